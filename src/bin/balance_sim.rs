@@ -0,0 +1,169 @@
+use mr_autoauto::{
+    requested_config_profiles, run_headless, CliArgs, GameConfig, HeadlessRun, RunEndReason,
+    RunSummary,
+};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+const USAGE: &str = "usage: balance_sim [--runs <n>] [--seed <first u64>] [--duration <seconds>] \
+[--config <dir>] [--profile <name|dir>]... [--vehicle <id>] [--out <file.csv>]";
+
+struct BalanceSimArgs {
+    runs: u32,
+    first_seed: u64,
+    duration_s: f32,
+    config_dir: PathBuf,
+    profiles: Vec<String>,
+    vehicle_id: Option<String>,
+    out: Option<PathBuf>,
+}
+
+impl BalanceSimArgs {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Self {
+            runs: 100,
+            first_seed: 1,
+            duration_s: 180.0,
+            config_dir: PathBuf::from("config"),
+            profiles: Vec::new(),
+            vehicle_id: None,
+            out: None,
+        };
+        let mut args = args.into_iter();
+        while let Some(flag) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("`{flag}` expects a value"))?;
+            match flag.as_str() {
+                "--runs" => parsed.runs = parse_value(&flag, &value)?,
+                "--seed" => parsed.first_seed = parse_value(&flag, &value)?,
+                "--duration" => parsed.duration_s = parse_value(&flag, &value)?,
+                "--config" => parsed.config_dir = PathBuf::from(value),
+                "--profile" => parsed.profiles.push(value),
+                "--vehicle" => parsed.vehicle_id = Some(value),
+                "--out" => parsed.out = Some(PathBuf::from(value)),
+                _ => return Err(format!("unknown argument `{flag}`")),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("`{flag}` got an invalid value `{value}`"))
+}
+
+fn main() {
+    let args = BalanceSimArgs::parse(std::env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{error}\n{USAGE}");
+        std::process::exit(2);
+    });
+    // Same selection as the game: `--profile` flags, else `MR_AUTOAUTO_PROFILE`.
+    let profiles = requested_config_profiles(Some(&CliArgs {
+        profiles: args.profiles.clone(),
+        ..CliArgs::default()
+    }));
+    let config =
+        GameConfig::load_with_profiles(&args.config_dir, &profiles).unwrap_or_else(|error| {
+            eprintln!(
+                "failed to load configuration from `{}`: {error}",
+                args.config_dir.display()
+            );
+            std::process::exit(1);
+        });
+    if let Some(vehicle_id) = args.vehicle_id.as_deref() {
+        if !config.vehicles_by_id.contains_key(vehicle_id) {
+            eprintln!("unknown vehicle `{vehicle_id}` in vehicles.toml\n{USAGE}");
+            std::process::exit(2);
+        }
+    }
+
+    let result = match &args.out {
+        Some(path) => {
+            File::create(path).and_then(|file| write_csv(&mut BufWriter::new(file), &args, &config))
+        }
+        None => write_csv(&mut io::stdout().lock(), &args, &config),
+    };
+    if let Err(error) = result {
+        eprintln!("failed writing CSV: {error}");
+        std::process::exit(1);
+    }
+}
+
+fn write_csv(out: &mut impl Write, args: &BalanceSimArgs, config: &GameConfig) -> io::Result<()> {
+    let enemy_type_ids: Vec<&str> = config
+        .enemy_types
+        .enemy_types
+        .iter()
+        .map(|enemy_type| enemy_type.id.as_str())
+        .collect();
+
+    write!(out, "seed,distance_m,score,outcome,death_cause,coins,kills")?;
+    for enemy_type_id in &enemy_type_ids {
+        write!(out, ",kills_{enemy_type_id}")?;
+    }
+    writeln!(out, ",upgrades")?;
+
+    for run_index in 0..args.runs {
+        let seed = args.first_seed.wrapping_add(run_index as u64);
+        let summary = run_headless(
+            config.clone(),
+            &HeadlessRun {
+                seed,
+                vehicle_id: args.vehicle_id.clone(),
+                duration_s: args.duration_s,
+                autopilot: true,
+                ..Default::default()
+            },
+        );
+        write_csv_row(out, &summary, &enemy_type_ids)?;
+        eprintln!(
+            "run {}/{} (seed {seed}): {:.0} m",
+            run_index + 1,
+            args.runs,
+            summary.distance_m
+        );
+    }
+    out.flush()
+}
+
+fn write_csv_row(
+    out: &mut impl Write,
+    summary: &RunSummary,
+    enemy_type_ids: &[&str],
+) -> io::Result<()> {
+    let (outcome, death_cause) = match summary.end_reason {
+        Some(RunEndReason::Destroyed) => {
+            let cause = summary
+                .last_damage_cause
+                .map(|cause| cause.label())
+                .unwrap_or("unknown");
+            ("died", cause)
+        }
+        Some(RunEndReason::OutOfFuel) => ("out_of_fuel", ""),
+        None => ("survived", ""),
+    };
+
+    write!(
+        out,
+        "{},{:.1},{},{outcome},{death_cause},{},{}",
+        summary.seed,
+        summary.distance_m,
+        summary.score,
+        summary.coin_pickup_count,
+        summary.kill_count
+    )?;
+    for enemy_type_id in enemy_type_ids {
+        let kills = summary
+            .kills_by_enemy_type
+            .get(*enemy_type_id)
+            .copied()
+            .unwrap_or(0);
+        write!(out, ",{kills}")?;
+    }
+    writeln!(out, ",{}", summary.upgrades_picked.join(";"))
+}
@@ -0,0 +1,65 @@
+use mr_autoauto::GameConfig;
+use std::path::PathBuf;
+
+const USAGE: &str =
+    "usage: check_config [--config <dir>] [--assets <dir>] [--profile <name|dir>]...";
+
+struct CheckConfigArgs {
+    config_dir: PathBuf,
+    assets_dir: PathBuf,
+    profiles: Vec<String>,
+}
+
+impl CheckConfigArgs {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Self {
+            config_dir: PathBuf::from("config"),
+            assets_dir: PathBuf::from("assets"),
+            profiles: Vec::new(),
+        };
+        let mut args = args.into_iter();
+        while let Some(flag) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("`{flag}` expects a value"))?;
+            match flag.as_str() {
+                "--config" => parsed.config_dir = PathBuf::from(value),
+                "--assets" => parsed.assets_dir = PathBuf::from(value),
+                "--profile" => parsed.profiles.push(value),
+                _ => return Err(format!("unknown argument `{flag}`")),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+fn main() {
+    let args = CheckConfigArgs::parse(std::env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{error}\n{USAGE}");
+        std::process::exit(2);
+    });
+    let config =
+        GameConfig::load_with_profiles(&args.config_dir, &args.profiles).unwrap_or_else(|error| {
+            eprintln!(
+                "failed to load configuration from `{}`: {error}",
+                args.config_dir.display()
+            );
+            std::process::exit(1);
+        });
+
+    let report = config.lint_report(&args.config_dir, &args.assets_dir);
+    if report.is_empty() {
+        println!("`{}` passed all config checks.", args.config_dir.display());
+        return;
+    }
+
+    eprintln!(
+        "`{}` has {} config lint problem(s):",
+        args.config_dir.display(),
+        report.issues.len()
+    );
+    for issue in &report.issues {
+        eprintln!("  {issue}");
+    }
+    std::process::exit(1);
+}
@@ -0,0 +1,42 @@
+use mr_autoauto::config_file_schemas;
+use std::fs;
+use std::path::PathBuf;
+
+const USAGE: &str = "usage: export_config_schema [--out <dir>]";
+
+fn parse_out_dir(args: impl IntoIterator<Item = String>) -> Result<PathBuf, String> {
+    let mut out_dir = PathBuf::from("config/schema");
+    let mut args = args.into_iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("`{flag}` expects a value"))?;
+        match flag.as_str() {
+            "--out" => out_dir = PathBuf::from(value),
+            _ => return Err(format!("unknown argument `{flag}`")),
+        }
+    }
+    Ok(out_dir)
+}
+
+fn main() {
+    let out_dir = parse_out_dir(std::env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{error}\n{USAGE}");
+        std::process::exit(2);
+    });
+    if let Err(error) = fs::create_dir_all(&out_dir) {
+        eprintln!("failed to create `{}`: {error}", out_dir.display());
+        std::process::exit(1);
+    }
+
+    for (file, schema) in config_file_schemas() {
+        let stem = file.trim_end_matches(".toml");
+        let path = out_dir.join(format!("{stem}.schema.json"));
+        let json = serde_json::to_string_pretty(&schema).expect("schemas serialize to JSON");
+        if let Err(error) = fs::write(&path, json + "\n") {
+            eprintln!("failed to write `{}`: {error}", path.display());
+            std::process::exit(1);
+        }
+        println!("{file} -> {}", path.display());
+    }
+}
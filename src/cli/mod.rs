@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use std::path::PathBuf;
+
+pub const USAGE: &str =
+    "usage: mr_autoauto [--seed <u64>] [--replay <file>] [--profile <name|dir>]... [--no-config-watch]";
+
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CliArgs {
+    pub seed: Option<u64>,
+    pub replay: Option<PathBuf>,
+    pub profiles: Vec<String>,
+    pub no_config_watch: bool,
+}
+
+impl CliArgs {
+    pub fn from_env() -> Result<Self, String> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let mut value_for = |flag: &str| {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("`{flag}` expects a value"))
+            };
+
+            match flag.as_str() {
+                "--seed" => parsed.seed = Some(parse_seed(&value_for("--seed")?)?),
+                "--replay" => parsed.replay = Some(PathBuf::from(value_for("--replay")?)),
+                "--profile" => parsed.profiles.push(value_for("--profile")?),
+                "--no-config-watch" => parsed.no_config_watch = true,
+                _ => return Err(format!("unknown argument `{flag}`")),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+fn parse_seed(raw: &str) -> Result<u64, String> {
+    let raw = raw.trim();
+    let parsed = match raw.strip_prefix("0x").or_else(|| raw.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(&hex.replace('_', ""), 16),
+        None => raw.replace('_', "").parse::<u64>(),
+    };
+    parsed.map_err(|_| format!("`--seed` expects an unsigned integer, got `{raw}`"))
+}
@@ -5,9 +5,11 @@ use crate::gameplay::enemies::{
     SegmentBossSpawnedEvent,
 };
 use crate::gameplay::vehicle::{
-    PlayerHealth, PlayerVehicle, VehicleStuntEvent, VehicleStuntMetrics, VehicleTelemetry,
+    PlayerHealth, PlayerNitro, PlayerVehicle, VehicleNitroBoostEvent, VehicleStuntEvent,
+    VehicleStuntMetrics, VehicleTelemetry,
 };
-use crate::states::GameState;
+use crate::rng::{RngStream, RunRng, StreamRng};
+use crate::states::ActiveRun;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::web::{audio_playback_allowed, AudioUnlockState};
 #[cfg(target_arch = "wasm32")]
@@ -51,11 +53,11 @@ impl Plugin for CommentaryStubPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<CommentaryStubState>()
             .add_systems(
-                OnEnter(GameState::InRun),
+                OnEnter(ActiveRun),
                 (reset_commentary_stub, spawn_commentary_subtitle_overlay),
             )
             .add_systems(
-                OnExit(GameState::InRun),
+                OnExit(ActiveRun),
                 (
                     cleanup_commentary_subtitle_overlay,
                     cleanup_commentary_narration_playback,
@@ -71,7 +73,7 @@ impl Plugin for CommentaryStubPlugin {
                     sync_commentary_subtitle_overlay,
                 )
                     .chain()
-                    .run_if(in_state(GameState::InRun))
+                    .run_if(in_state(ActiveRun))
                     .run_if(resource_exists::<GameConfig>),
             );
     }
@@ -135,6 +137,10 @@ pub enum GameEvent {
     Streak {
         count: u32,
     },
+    NitroReady,
+    NitroBoost {
+        speed_mps: f32,
+    },
     Manual {
         label: String,
     },
@@ -158,6 +164,8 @@ impl GameEvent {
             Self::BossSpawned { .. } => "BossSpawned",
             Self::BossDefeated { .. } => "BossDefeated",
             Self::Streak { .. } => "Streak",
+            Self::NitroReady => "NitroReady",
+            Self::NitroBoost { .. } => "NitroBoost",
             Self::Manual { .. } => "Manual",
         }
     }
@@ -215,11 +223,11 @@ pub struct CommentaryStubState {
     pending_audio_path: Option<String>,
     last_emit_time_seconds: f64,
     next_commentator_index: usize,
-    rng_state: u64,
     last_crash_count: u32,
     last_speed_tier: u8,
     near_death_active: bool,
     enemy_swarm_active: bool,
+    nitro_ready_active: bool,
     kill_streak_count: u32,
     last_kill_time_seconds: f64,
     last_speaker_id: String,
@@ -248,11 +256,11 @@ impl Default for CommentaryStubState {
             pending_audio_path: None,
             last_emit_time_seconds: 0.0,
             next_commentator_index: 0,
-            rng_state: 0x4D52_4155_544F_4155,
             last_crash_count: 0,
             last_speed_tier: 0,
             near_death_active: false,
             enemy_swarm_active: false,
+            nitro_ready_active: false,
             kill_streak_count: 0,
             last_kill_time_seconds: 0.0,
             last_speaker_id: String::new(),
@@ -289,11 +297,13 @@ struct SummaryAggregation {
     boss_kill_count: u32,
     boss_kill_segment: Option<String>,
     streak_count: u32,
+    nitro_ready: bool,
+    nitro_boost_count: u32,
+    fastest_nitro_boost_mps: f32,
     manual_labels: Vec<String>,
 }
 fn reset_commentary_stub(mut state: ResMut<CommentaryStubState>) {
     *state = CommentaryStubState::default();
-    state.rng_state ^= unix_timestamp_seconds();
 }
 
 fn spawn_commentary_subtitle_overlay(mut commands: Commands) {
@@ -357,11 +367,12 @@ fn collect_commentary_events(
     config: Res<GameConfig>,
     telemetry: Option<Res<VehicleTelemetry>>,
     stunt_metrics: Option<Res<VehicleStuntMetrics>>,
-    player_query: Query<&PlayerHealth, With<PlayerVehicle>>,
+    player_query: Query<(&PlayerHealth, Option<&PlayerNitro>), With<PlayerVehicle>>,
     camera_query: Query<(&GlobalTransform, &Projection), With<Camera2d>>,
     enemy_query: Query<&GlobalTransform, With<Enemy>>,
     mut stunt_events: MessageReader<VehicleStuntEvent>,
     mut kill_events: MessageReader<EnemyKilledEvent>,
+    mut nitro_boost_events: MessageReader<VehicleNitroBoostEvent>,
     mut boss_spawned_events: MessageReader<SegmentBossSpawnedEvent>,
     mut boss_defeated_events: MessageReader<SegmentBossDefeatedEvent>,
     mut player_damage_events: MessageReader<PlayerDamageEvent>,
@@ -485,6 +496,15 @@ fn collect_commentary_events(
         );
     }
 
+    for event in nitro_boost_events.read() {
+        push_event(
+            &mut state,
+            GameEvent::NitroBoost {
+                speed_mps: event.speed_mps,
+            },
+        );
+    }
+
     for event in boss_spawned_events.read() {
         push_event(
             &mut state,
@@ -561,7 +581,16 @@ fn collect_commentary_events(
         }
     }
 
-    if let Ok(player_health) = player_query.single() {
+    if let Ok((_, Some(nitro))) = player_query.single() {
+        if nitro.ready() && !state.nitro_ready_active {
+            state.nitro_ready_active = true;
+            push_event(&mut state, GameEvent::NitroReady);
+        } else if !nitro.ready() {
+            state.nitro_ready_active = false;
+        }
+    }
+
+    if let Ok((player_health, _)) = player_query.single() {
         let health_fraction = if player_health.max > 0.0 {
             (player_health.current / player_health.max).clamp(0.0, 1.0)
         } else {
@@ -849,6 +878,7 @@ fn normalize_wav_unknown_sizes(bytes: &mut [u8]) -> Result<(), String> {
 fn process_commentary_queue(
     time: Res<Time>,
     config: Res<GameConfig>,
+    mut rng: ResMut<RunRng>,
     mut state: ResMut<CommentaryStubState>,
 ) {
     if state.inflight_request.is_some() {
@@ -910,12 +940,14 @@ fn process_commentary_queue(
 
     let summary = build_summary_text(&emitted_events);
     let fallback_line = if summary.is_empty() {
-        fallback_line(&config, &mut state).unwrap_or_else(|| "player event.".to_string())
+        fallback_line(&config, rng.stream(RngStream::CosmeticFx))
+            .unwrap_or_else(|| "player event.".to_string())
     } else {
         summary.clone()
     };
 
-    let (chat_emotion, voice_emotion) = select_commentator_emotion(&profile, &mut state);
+    let (chat_emotion, voice_emotion) =
+        select_commentator_emotion(&profile, rng.stream(RngStream::CosmeticFx));
     state.pending_chat_emotion = chat_emotion.clone();
     state.pending_voice_emotion = voice_emotion.clone();
     state.pending_summary_preview = summary.clone();
@@ -1139,7 +1171,7 @@ fn select_next_commentator_profile(
 
 fn select_commentator_emotion(
     profile: &CommentatorProfile,
-    state: &mut CommentaryStubState,
+    rng: &mut StreamRng,
 ) -> (String, String) {
     let fallback = "Neutral".to_string();
     let emotion_pool = if profile.emotions.is_empty() {
@@ -1147,7 +1179,7 @@ fn select_commentator_emotion(
     } else {
         profile.emotions.clone()
     };
-    let index = next_rng_u32(&mut state.rng_state) as usize % emotion_pool.len();
+    let index = rng.index(emotion_pool.len());
     let raw = emotion_pool[index].trim();
     (normalize_chat_emotion(raw), normalize_voice_emotion(raw))
 }
@@ -1255,6 +1287,13 @@ fn build_summary_text(events: &[GameEvent]) -> String {
             GameEvent::Streak { count } => {
                 agg.streak_count = agg.streak_count.max(*count);
             }
+            GameEvent::NitroReady => {
+                agg.nitro_ready = true;
+            }
+            GameEvent::NitroBoost { speed_mps } => {
+                agg.nitro_boost_count = agg.nitro_boost_count.saturating_add(1);
+                agg.fastest_nitro_boost_mps = agg.fastest_nitro_boost_mps.max(*speed_mps);
+            }
             GameEvent::Manual { label } => {
                 agg.manual_labels.push(label.clone());
             }
@@ -1349,6 +1388,14 @@ fn build_summary_text(events: &[GameEvent]) -> String {
     if agg.streak_count >= 2 {
         parts.push(format!("player kill streak is {}", agg.streak_count));
     }
+    if agg.nitro_boost_count > 0 {
+        parts.push(format!(
+            "player fired the nitro boost at {:.1} m/s",
+            agg.fastest_nitro_boost_mps.max(0.0)
+        ));
+    } else if agg.nitro_ready {
+        parts.push("player's nitro meter is full".to_string());
+    }
     if !agg.manual_labels.is_empty() {
         parts.push(format!("debug events: {}", agg.manual_labels.join(", ")));
     }
@@ -1377,22 +1424,14 @@ WHAT HAPPENED (dry game facts):\n\
     )
 }
 
-fn fallback_line(config: &GameConfig, state: &mut CommentaryStubState) -> Option<String> {
+fn fallback_line(config: &GameConfig, rng: &mut StreamRng) -> Option<String> {
     if config.commentator.fallback.lines.is_empty() {
         return None;
     }
-    let index =
-        next_rng_u32(&mut state.rng_state) as usize % config.commentator.fallback.lines.len();
+    let index = rng.index(config.commentator.fallback.lines.len());
     Some(config.commentator.fallback.lines[index].clone())
 }
 
-fn next_rng_u32(state: &mut u64) -> u32 {
-    *state = state
-        .wrapping_mul(6364136223846793005)
-        .wrapping_add(1442695040888963407);
-    ((*state >> 32) & 0xFFFF_FFFF) as u32
-}
-
 fn count_visible_enemies_on_screen(
     camera_query: &Query<(&GlobalTransform, &Projection), With<Camera2d>>,
     enemy_query: &Query<&GlobalTransform, With<Enemy>>,
@@ -1694,19 +1733,6 @@ fn truncate(input: &str, max_chars: usize) -> String {
     truncated
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-fn unix_timestamp_seconds() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs()
-}
-
-#[cfg(target_arch = "wasm32")]
-fn unix_timestamp_seconds() -> u64 {
-    0
-}
-
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ChatRequestPayload<'a> {
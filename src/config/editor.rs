@@ -0,0 +1,277 @@
+use super::inheritance::{inheritable_rows_key, resolve_row_inheritance};
+use super::*;
+use toml::{Table, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EditableConfigTable {
+    #[default]
+    EnemyTypes,
+    Weapons,
+    Spawners,
+    Environments,
+    Pickups,
+    Scoring,
+    CommentatorThresholds,
+}
+
+impl EditableConfigTable {
+    pub const ALL: [Self; 7] = [
+        Self::EnemyTypes,
+        Self::Weapons,
+        Self::Spawners,
+        Self::Environments,
+        Self::Pickups,
+        Self::Scoring,
+        Self::CommentatorThresholds,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::EnemyTypes => "enemy_types",
+            Self::Weapons => "weapons",
+            Self::Spawners => "spawners",
+            Self::Environments => "environments",
+            Self::Pickups => "pickups",
+            Self::Scoring => "scoring",
+            Self::CommentatorThresholds => "commentator thresholds",
+        }
+    }
+
+    pub fn file(self) -> &'static str {
+        match self {
+            Self::EnemyTypes => "enemy_types.toml",
+            Self::Weapons => "weapons.toml",
+            Self::Spawners => "spawners.toml",
+            Self::Environments => "environments.toml",
+            Self::Pickups | Self::Scoring => "game.toml",
+            Self::CommentatorThresholds => "commentator.toml",
+        }
+    }
+
+    // Top-level key in `file()`: `[[rows]]` keyed by id when `has_rows()`,
+    // otherwise a single table.
+    pub fn toml_key(self) -> &'static str {
+        match self {
+            Self::CommentatorThresholds => "thresholds",
+            _ => self.label(),
+        }
+    }
+
+    pub fn has_rows(self) -> bool {
+        matches!(
+            self,
+            Self::EnemyTypes | Self::Weapons | Self::Spawners | Self::Environments
+        )
+    }
+
+    pub fn row_ids(self, config: &GameConfig) -> Vec<String> {
+        match self {
+            Self::EnemyTypes => ids(&config.enemy_types.enemy_types),
+            Self::Weapons => ids(&config.weapons.weapons),
+            Self::Spawners => ids(&config.spawners.spawners),
+            Self::Environments => ids(&config.environments.environments),
+            Self::Pickups | Self::Scoring | Self::CommentatorThresholds => Vec::new(),
+        }
+    }
+
+    // Returns the effective values (after defaults and `extends`) as a TOML table.
+    pub fn read_row(self, config: &GameConfig, id: Option<&str>) -> Result<Table, String> {
+        let value = match self {
+            Self::EnemyTypes => to_value(find_row(&config.enemy_types.enemy_types, id)?),
+            Self::Weapons => to_value(find_row(&config.weapons.weapons, id)?),
+            Self::Spawners => to_value(find_row(&config.spawners.spawners, id)?),
+            Self::Environments => to_value(find_row(&config.environments.environments, id)?),
+            Self::Pickups => to_value(&config.game.pickups),
+            Self::Scoring => to_value(&config.game.scoring),
+            Self::CommentatorThresholds => to_value(&config.commentator.thresholds),
+        }?;
+        match shorten_floats(value) {
+            Value::Table(row) => Ok(row),
+            _ => Err(format!("{} did not serialize to a table", self.label())),
+        }
+    }
+
+    // Deserializes `row` into the table's config type and swaps it in place,
+    // restoring the previous value if the config no longer passes validation.
+    pub fn apply_row(
+        self,
+        config: &mut GameConfig,
+        id: Option<&str>,
+        row: Table,
+    ) -> Result<(), String> {
+        let row = Value::Table(row);
+        match self {
+            Self::EnemyTypes => apply_rows(self, config, id, row, |config| {
+                (
+                    &mut config.enemy_types.enemy_types,
+                    &mut config.enemy_types_by_id,
+                )
+            }),
+            Self::Weapons => apply_rows(self, config, id, row, |config| {
+                (&mut config.weapons.weapons, &mut config.weapons_by_id)
+            }),
+            Self::Spawners => apply_rows(self, config, id, row, |config| {
+                (&mut config.spawners.spawners, &mut config.spawners_by_id)
+            }),
+            Self::Environments => apply_rows(self, config, id, row, |config| {
+                (
+                    &mut config.environments.environments,
+                    &mut config.environments_by_id,
+                )
+            }),
+            Self::Pickups => apply_value(config, row, |config| &mut config.game.pickups),
+            Self::Scoring => apply_value(config, row, |config| &mut config.game.scoring),
+            Self::CommentatorThresholds => {
+                apply_value(config, row, |config| &mut config.commentator.thresholds)
+            }
+        }
+    }
+}
+
+fn ids<T: HasId>(rows: &[T]) -> Vec<String> {
+    rows.iter().map(|row| row.id().to_string()).collect()
+}
+
+fn find_row<'a, T: HasId>(rows: &'a [T], id: Option<&str>) -> Result<&'a T, String> {
+    let id = id.ok_or_else(|| "select a row id first".to_string())?;
+    rows.iter()
+        .find(|row| row.id() == id)
+        .ok_or_else(|| format!("no row with id `{id}`"))
+}
+
+fn apply_rows<T, F>(
+    table: EditableConfigTable,
+    config: &mut GameConfig,
+    id: Option<&str>,
+    row: Value,
+    fields: F,
+) -> Result<(), String>
+where
+    T: HasId + Clone + Serialize + DeserializeOwned,
+    F: Fn(&mut GameConfig) -> (&mut Vec<T>, &mut HashMap<String, T>),
+{
+    let (rows, _) = fields(config);
+    let edited = edited_rows(table, rows, id, row)?;
+    let edited_by_id = edited
+        .iter()
+        .map(|row| (row.id().to_string(), row.clone()))
+        .collect();
+
+    let (rows, by_id) = fields(config);
+    let previous = (
+        std::mem::replace(rows, edited),
+        std::mem::replace(by_id, edited_by_id),
+    );
+    if let Err(error) = config.validate_references() {
+        let (rows, by_id) = fields(config);
+        (*rows, *by_id) = previous;
+        return Err(error.to_string());
+    }
+    Ok(())
+}
+
+fn apply_value<T, F>(config: &mut GameConfig, row: Value, field: F) -> Result<(), String>
+where
+    T: DeserializeOwned,
+    F: Fn(&mut GameConfig) -> &mut T,
+{
+    let previous = std::mem::replace(field(config), from_value(row)?);
+    if let Err(error) = config.validate_references() {
+        *field(config) = previous;
+        return Err(error.to_string());
+    }
+    Ok(())
+}
+
+// Returns every row of the table with `row` swapped in. Tables with `extends`
+// are re-resolved so children pick up the edited parent's values for keys they
+// do not override themselves.
+fn edited_rows<T: HasId + Clone + Serialize + DeserializeOwned>(
+    table: EditableConfigTable,
+    rows: &[T],
+    id: Option<&str>,
+    row: Value,
+) -> Result<Vec<T>, String> {
+    let index = rows
+        .iter()
+        .position(|existing| Some(existing.id()) == id)
+        .ok_or_else(|| format!("no row with id `{}`", id.unwrap_or_default()))?;
+    let edited: T = from_value(row.clone())?;
+    if edited.id() != rows[index].id() {
+        return Err(format!("`id` of `{}` cannot be changed", rows[index].id()));
+    }
+    if inheritable_rows_key(table.file()).is_none() {
+        let mut rows = rows.to_vec();
+        rows[index] = edited;
+        return Ok(rows);
+    }
+
+    let resolved = rows
+        .iter()
+        .map(|row| match to_value(row)? {
+            Value::Table(row) => Ok(row),
+            _ => Err(format!("{} did not serialize to a table", table.label())),
+        })
+        .collect::<Result<Vec<Table>, String>>()?;
+    let mut layered: Vec<Value> = resolved
+        .iter()
+        .map(|row| Value::Table(own_keys(row, &resolved)))
+        .collect();
+    layered[index] = row;
+
+    let mut root = Value::Table(Table::from_iter([(
+        table.toml_key().to_string(),
+        Value::Array(layered),
+    )]));
+    let mut report = ConfigReport::default();
+    resolve_row_inheritance(&mut root, table.file(), table.toml_key(), &mut report);
+    report.into_result().map_err(|error| error.to_string())?;
+    match root.get(table.toml_key()) {
+        Some(Value::Array(rows)) => rows.iter().cloned().map(from_value).collect(),
+        _ => Err(format!("{} rows went missing", table.label())),
+    }
+}
+
+// Strips a resolved row back to the keys it sets itself: anything equal to its
+// resolved parent's value is treated as inherited.
+fn own_keys(row: &Table, rows: &[Table]) -> Table {
+    let parent = row
+        .get("extends")
+        .and_then(Value::as_str)
+        .and_then(|parent_id| {
+            rows.iter()
+                .find(|candidate| candidate.get("id").and_then(Value::as_str) == Some(parent_id))
+        });
+    let Some(parent) = parent else {
+        return row.clone();
+    };
+    row.iter()
+        .filter(|(key, value)| {
+            matches!(key.as_str(), "id" | "extends") || parent.get(*key) != Some(*value)
+        })
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+fn to_value<T: Serialize>(row: &T) -> Result<Value, String> {
+    Value::try_from(row).map_err(|error| error.to_string())
+}
+
+fn from_value<T: DeserializeOwned>(row: Value) -> Result<T, String> {
+    row.try_into()
+        .map_err(|error: toml::de::Error| error.message().to_string())
+}
+
+fn shorten_floats(value: Value) -> Value {
+    match value {
+        Value::Float(number) => Value::Float(f32_to_short_f64(number as f32)),
+        Value::Array(items) => Value::Array(items.into_iter().map(shorten_floats).collect()),
+        Value::Table(table) => Value::Table(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, shorten_floats(value)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
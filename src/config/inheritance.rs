@@ -0,0 +1,106 @@
+use super::profiles::{merge_toml_layer, row_id};
+use super::*;
+use toml::Value;
+
+const INHERITABLE_ROWS: [(&str, &str); 3] = [
+    ("enemy_types.toml", "enemy_types"),
+    ("weapons.toml", "weapons"),
+    ("vehicles.toml", "vehicles"),
+];
+
+pub(super) fn inheritable_rows_key(file: &str) -> Option<&'static str> {
+    INHERITABLE_ROWS
+        .iter()
+        .find(|(rows_file, _)| *rows_file == file)
+        .map(|(_, rows_key)| *rows_key)
+}
+
+// Rewrites every `[[rows]]` entry that has `extends = "<id>"` as its parent chain
+// merged under it, using the same rules as profile layers. Returns whether any
+// row declared `extends`; unknown parents and cycles are pushed into `report`.
+pub(super) fn resolve_row_inheritance(
+    root: &mut Value,
+    file: &str,
+    rows_key: &str,
+    report: &mut ConfigReport,
+) -> bool {
+    let Some(rows) = root.get_mut(rows_key).and_then(Value::as_array_mut) else {
+        return false;
+    };
+    if !rows.iter().any(|row| row.get("extends").is_some()) {
+        return false;
+    }
+
+    let originals = rows.clone();
+    let mut errors = Vec::new();
+    for (index, row) in rows.iter_mut().enumerate() {
+        let mut chain = vec![index];
+        let error = loop {
+            let current = chain[chain.len() - 1];
+            let Some(extends) = originals[current].get("extends") else {
+                break None;
+            };
+            let Some(parent_id) = extends.as_str() else {
+                break Some((current, "must be a string id".to_string()));
+            };
+            let Some(parent) = originals
+                .iter()
+                .position(|candidate| row_id(candidate) == Some(parent_id))
+            else {
+                break Some((current, format!("references unknown id `{parent_id}`")));
+            };
+            if let Some(cycle_start) = chain.iter().position(|link| *link == parent) {
+                let cycle: Vec<&str> = chain[cycle_start..]
+                    .iter()
+                    .chain([&parent])
+                    .map(|link| row_id(&originals[*link]).unwrap_or("?"))
+                    .collect();
+                break Some((current, format!("forms a cycle ({})", cycle.join(" -> "))));
+            }
+            chain.push(parent);
+        };
+
+        if let Some(error) = error {
+            if !errors.contains(&error) {
+                errors.push(error);
+            }
+            continue;
+        }
+        let mut merged = originals[chain[chain.len() - 1]].clone();
+        for link in chain.iter().rev().skip(1) {
+            merge_toml_layer(&mut merged, originals[*link].clone());
+        }
+        *row = merged;
+    }
+
+    for (index, message) in errors {
+        report.push(
+            file,
+            format!("{rows_key}[{index}].extends"),
+            Some(index),
+            message,
+        );
+    }
+    true
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(super) fn read_embedded_toml<T: DeserializeOwned>(
+    path_label: &str,
+    raw: &str,
+) -> Result<T, ConfigError> {
+    let file = path_label.rsplit('/').next().unwrap_or(path_label);
+    let Some(rows_key) = inheritable_rows_key(file) else {
+        return read_toml_from_str(path_label, raw);
+    };
+    let mut value: Value = read_toml_from_str(path_label, raw)?;
+    let mut report = ConfigReport::default();
+    if !resolve_row_inheritance(&mut value, file, rows_key, &mut report) {
+        return read_toml_from_str(path_label, raw);
+    }
+    report.into_result()?;
+    value.try_into().map_err(|source| ConfigError::Parse {
+        path: PathBuf::from(path_label),
+        source: Box::new(source),
+    })
+}
@@ -0,0 +1,136 @@
+use super::*;
+use std::collections::HashSet;
+
+impl GameConfig {
+    pub fn lint_report(&self, config_dir: &Path, assets_dir: &Path) -> ConfigReport {
+        let mut report = ConfigReport::default();
+
+        let mut used_enemy_ids: HashSet<&str> = self
+            .spawners
+            .spawners
+            .iter()
+            .flat_map(|spawner| spawner.spawn_enemy_ids.iter().map(String::as_str))
+            .collect();
+        used_enemy_ids.insert(SEGMENT_BOSS_ENEMY_ID);
+        for (index, enemy) in self.enemy_types.enemy_types.iter().enumerate() {
+            if !used_enemy_ids.contains(enemy.id.as_str()) {
+                report.push(
+                    "enemy_types.toml",
+                    format!("enemy_types[{index}].id"),
+                    Some(index),
+                    format!("`{}` is never spawned by any spawners.toml entry", enemy.id),
+                );
+            }
+        }
+
+        let used_weapon_ids: HashSet<&str> = self
+            .vehicles
+            .vehicles
+            .iter()
+            .flat_map(|vehicle| {
+                std::iter::once(vehicle.default_weapon_id.as_str())
+                    .chain(vehicle.secondary_weapon_id.as_deref())
+            })
+            .chain(
+                self.enemy_types
+                    .enemy_types
+                    .iter()
+                    .map(|enemy| enemy.weapon_id.as_str()),
+            )
+            .collect();
+        for (index, weapon) in self.weapons.weapons.iter().enumerate() {
+            if !used_weapon_ids.contains(weapon.id.as_str()) {
+                report.push(
+                    "weapons.toml",
+                    format!("weapons[{index}].id"),
+                    Some(index),
+                    format!("`{}` is not used by any vehicle or enemy type", weapon.id),
+                );
+            }
+        }
+
+        let placed_background_ids: HashSet<&str> = self
+            .segments
+            .segment_sequence
+            .iter()
+            .map(|segment| segment.id.as_str())
+            .collect();
+        for (index, background) in self.backgrounds.backgrounds.iter().enumerate() {
+            if !placed_background_ids.contains(background.id.as_str()) {
+                report.push(
+                    "backgrounds.toml",
+                    format!("backgrounds[{index}].id"),
+                    Some(index),
+                    format!(
+                        "`{}` is never placed in segments.toml::segment_sequence",
+                        background.id
+                    ),
+                );
+            }
+        }
+
+        // A segment without `spawn_set` runs every spawner, so none are unused then.
+        let runs_all_spawners = self
+            .segments
+            .segment_sequence
+            .iter()
+            .any(|segment| segment.spawn_set.is_none());
+        let used_spawner_ids: HashSet<&str> = self
+            .segments
+            .segment_sequence
+            .iter()
+            .filter_map(|segment| segment.spawn_set.as_deref())
+            .collect();
+        for (index, spawner) in self.spawners.spawners.iter().enumerate() {
+            if !runs_all_spawners && !used_spawner_ids.contains(spawner.id.as_str()) {
+                report.push(
+                    "spawners.toml",
+                    format!("spawners[{index}].id"),
+                    Some(index),
+                    format!("`{}` is not the spawn_set of any segment", spawner.id),
+                );
+            }
+        }
+
+        let mut asset_paths = Vec::new();
+        for (index, sprite) in self.assets.sprites.iter().enumerate() {
+            asset_paths.push((format!("sprites[{index}].path"), index, &sprite.path));
+        }
+        for (index, model) in self.assets.models.iter().enumerate() {
+            asset_paths.push((
+                format!("models[{index}].scene_path"),
+                index,
+                &model.scene_path,
+            ));
+        }
+        for (index, splat) in self.assets.splats.iter().enumerate() {
+            asset_paths.push((format!("splats[{index}].path"), index, &splat.path));
+        }
+        for (index, audio) in self.assets.audio.iter().enumerate() {
+            asset_paths.push((format!("audio[{index}].path"), index, &audio.path));
+        }
+        for (key_path, index, asset_path) in asset_paths {
+            // Scene paths carry a `#Scene0` label that is not part of the file name.
+            let file_path = asset_path.split('#').next().unwrap_or_default();
+            if !assets_dir.join(file_path).is_file() {
+                report.push(
+                    "assets.toml",
+                    key_path,
+                    Some(index),
+                    format!(
+                        "`{asset_path}` does not exist under `{}`",
+                        assets_dir.display()
+                    ),
+                );
+            }
+        }
+
+        let profile_dirs: Vec<PathBuf> = self
+            .profiles
+            .iter()
+            .filter_map(|profile| resolve_profile_dir(config_dir, profile).ok())
+            .collect();
+        locate_layered_report(&mut report, config_dir, &profile_dirs);
+        report
+    }
+}
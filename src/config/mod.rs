@@ -1,28 +1,61 @@
 #![allow(dead_code)]
 
+use crate::cli::CliArgs;
 use bevy::prelude::*;
+use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+mod editor;
+mod inheritance;
+mod lints;
+mod profiles;
+mod report;
+mod schema;
+mod stat_paths;
+#[cfg(not(target_arch = "wasm32"))]
+mod watcher;
+
+pub use editor::EditableConfigTable;
+#[cfg(target_arch = "wasm32")]
+use inheritance::read_embedded_toml;
+use profiles::{locate_layered_report, read_layered_toml, resolve_profile_dir};
+pub use profiles::{profile_override_warning, requested_config_profiles};
+pub use report::*;
+pub use schema::config_file_schemas;
+use schema::extendable_row_schema;
+pub use stat_paths::*;
+
 const CONFIG_DIR: &str = "config";
+// Spawned by the segment boss gate rather than a spawner, so lints treat it as used.
+pub const SEGMENT_BOSS_ENEMY_ID: &str = "segment_boss_drone";
 
 pub struct ConfigPlugin;
 
 impl Plugin for ConfigPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, load_game_config)
+        app.add_message::<ConfigReloadedEvent>()
+            .add_systems(Startup, load_game_config)
             .add_systems(Update, reload_game_config_hotkey);
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(Startup, watcher::start_config_watcher)
+            .add_systems(
+                Update,
+                watcher::reload_changed_config_files
+                    .run_if(resource_exists::<watcher::ConfigWatcher>),
+            );
     }
 }
 
-fn load_game_config(mut commands: Commands) {
-    let config =
-        GameConfig::load_for_current_platform(Path::new(CONFIG_DIR)).unwrap_or_else(|error| {
+fn load_game_config(mut commands: Commands, cli: Option<Res<CliArgs>>) {
+    let profiles = requested_config_profiles(cli.as_deref());
+    let config = GameConfig::load_for_current_platform(Path::new(CONFIG_DIR), &profiles)
+        .unwrap_or_else(|error| {
             panic!("failed to load configuration from `{CONFIG_DIR}`: {error}");
         });
 
@@ -33,12 +66,15 @@ fn load_game_config(mut commands: Commands) {
         info!("Press F5 to hot-reload config files from `{CONFIG_DIR}`.");
     }
 
+    commands.insert_resource(RunStats::from_config(&config));
     commands.insert_resource(config);
 }
 
 fn reload_game_config_hotkey(
+    mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
     game_config: Option<ResMut<GameConfig>>,
+    mut reloaded_events: MessageWriter<ConfigReloadedEvent>,
 ) {
     if !keyboard.just_pressed(KeyCode::F5) {
         return;
@@ -56,20 +92,61 @@ fn reload_game_config_hotkey(
     }
 
     #[cfg(not(target_arch = "wasm32"))]
-    match GameConfig::load_for_current_platform(Path::new(CONFIG_DIR)) {
+    if reload_game_config(&mut commands, &mut current_config, "Hot-reloaded") {
+        reloaded_events.write(ConfigReloadedEvent);
+    }
+}
+
+fn reload_game_config(
+    commands: &mut Commands,
+    current_config: &mut GameConfig,
+    log_prefix: &str,
+) -> bool {
+    match GameConfig::load_for_current_platform(Path::new(CONFIG_DIR), &current_config.profiles) {
         Ok(new_config) => {
             *current_config = new_config;
-            log_config_summary("Hot-reloaded", &current_config);
+            log_config_summary(log_prefix, current_config);
+            commands.remove_resource::<ConfigReloadFailure>();
+            true
         }
         Err(error) => {
             error!("Config hot-reload failed; keeping previous config: {error}");
+            commands.insert_resource(ConfigReloadFailure::from_error(&error));
+            false
+        }
+    }
+}
+
+// Sent whenever the game itself writes or reloads the config files, so the file
+// watcher takes what is on disk as already loaded instead of reloading it again.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct ConfigReloadedEvent;
+
+#[derive(Resource, Debug, Clone)]
+pub struct ConfigReloadFailure {
+    pub summary: String,
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl ConfigReloadFailure {
+    fn from_error(error: &ConfigError) -> Self {
+        match error {
+            ConfigError::Invalid(report) => Self {
+                summary: format!("{} config validation error(s)", report.issues.len()),
+                issues: report.issues.clone(),
+            },
+            _ => Self {
+                summary: error.to_string(),
+                issues: Vec::new(),
+            },
         }
     }
 }
 
 fn log_config_summary(prefix: &str, config: &GameConfig) {
     info!(
-        "{prefix} config: {} segments, {} environments, {} enemies, {} weapons.",
+        "{prefix} config (profile: {}): {} segments, {} environments, {} enemies, {} weapons.",
+        config.profile_label(),
         config.segments.segment_sequence.len(),
         config.environments_by_id.len(),
         config.enemy_types_by_id.len(),
@@ -101,50 +178,141 @@ pub struct GameConfig {
     pub model_assets_by_id: HashMap<String, ModelAssetConfig>,
     pub splat_assets_by_id: HashMap<String, SplatAssetConfig>,
     pub audio_assets_by_id: HashMap<String, AudioAssetConfig>,
+    pub profiles: Vec<String>,
 }
 
 impl GameConfig {
-    pub fn load_for_current_platform(config_dir: &Path) -> Result<Self, ConfigError> {
+    pub fn load_for_current_platform(
+        config_dir: &Path,
+        profiles: &[String],
+    ) -> Result<Self, ConfigError> {
         #[cfg(target_arch = "wasm32")]
         {
             let _ = config_dir;
+            if !profiles.is_empty() {
+                warn!("Config profiles are ignored on web builds (embedded config snapshot).");
+            }
             Self::load_embedded()
         }
 
         #[cfg(not(target_arch = "wasm32"))]
         {
-            Self::load_from_dir(config_dir)
+            Self::load_with_profiles(config_dir, profiles)
         }
     }
 
     pub fn load_from_dir(config_dir: &Path) -> Result<Self, ConfigError> {
-        let game: GameFile = read_toml(&config_dir.join("game.toml"))?;
-        let assets: AssetsFile = read_toml(&config_dir.join("assets.toml"))?;
-        let segments: SegmentsFile = read_toml(&config_dir.join("segments.toml"))?;
-        let backgrounds: BackgroundsFile = read_toml(&config_dir.join("backgrounds.toml"))?;
-        let environments: EnvironmentsFile = read_toml(&config_dir.join("environments.toml"))?;
-        let enemy_types: EnemyTypesFile = read_toml(&config_dir.join("enemy_types.toml"))?;
-        let spawners: SpawnersFile = read_toml(&config_dir.join("spawners.toml"))?;
-        let weapons: WeaponsFile = read_toml(&config_dir.join("weapons.toml"))?;
-        let vehicles: VehiclesFile = read_toml(&config_dir.join("vehicles.toml"))?;
-        let upgrades: UpgradesFile = read_toml(&config_dir.join("upgrades.toml"))?;
-        let commentator: CommentatorFile = read_toml(&config_dir.join("commentator.toml"))?;
+        Self::load_with_profiles(config_dir, &[])
+    }
+
+    pub fn load_with_profiles(config_dir: &Path, profiles: &[String]) -> Result<Self, ConfigError> {
+        let profile_dirs = profiles
+            .iter()
+            .map(|profile| resolve_profile_dir(config_dir, profile))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut report = ConfigReport::default();
+        let game: Option<GameFile> =
+            report.absorb(read_layered_toml(config_dir, &profile_dirs, "game.toml"));
+        let assets: Option<AssetsFile> =
+            report.absorb(read_layered_toml(config_dir, &profile_dirs, "assets.toml"));
+        let segments: Option<SegmentsFile> = report.absorb(read_layered_toml(
+            config_dir,
+            &profile_dirs,
+            "segments.toml",
+        ));
+        let backgrounds: Option<BackgroundsFile> = report.absorb(read_layered_toml(
+            config_dir,
+            &profile_dirs,
+            "backgrounds.toml",
+        ));
+        let environments: Option<EnvironmentsFile> = report.absorb(read_layered_toml(
+            config_dir,
+            &profile_dirs,
+            "environments.toml",
+        ));
+        let enemy_types: Option<EnemyTypesFile> = report.absorb(read_layered_toml(
+            config_dir,
+            &profile_dirs,
+            "enemy_types.toml",
+        ));
+        let spawners: Option<SpawnersFile> = report.absorb(read_layered_toml(
+            config_dir,
+            &profile_dirs,
+            "spawners.toml",
+        ));
+        let weapons: Option<WeaponsFile> =
+            report.absorb(read_layered_toml(config_dir, &profile_dirs, "weapons.toml"));
+        let vehicles: Option<VehiclesFile> = report.absorb(read_layered_toml(
+            config_dir,
+            &profile_dirs,
+            "vehicles.toml",
+        ));
+        let upgrades: Option<UpgradesFile> = report.absorb(read_layered_toml(
+            config_dir,
+            &profile_dirs,
+            "upgrades.toml",
+        ));
+        let commentator: Option<CommentatorFile> = report.absorb(read_layered_toml(
+            config_dir,
+            &profile_dirs,
+            "commentator.toml",
+        ));
+        let (
+            Some(game),
+            Some(assets),
+            Some(segments),
+            Some(backgrounds),
+            Some(environments),
+            Some(enemy_types),
+            Some(spawners),
+            Some(weapons),
+            Some(vehicles),
+            Some(upgrades),
+            Some(commentator),
+        ) = (
+            game,
+            assets,
+            segments,
+            backgrounds,
+            environments,
+            enemy_types,
+            spawners,
+            weapons,
+            vehicles,
+            upgrades,
+            commentator,
+        )
+        else {
+            return Err(ConfigError::Invalid(report));
+        };
 
         let config = Self {
-            sprite_assets_by_id: to_index("assets.toml::sprites", &assets.sprites)?,
-            model_assets_by_id: to_index("assets.toml::models", &assets.models)?,
-            splat_assets_by_id: to_index("assets.toml::splats", &assets.splats)?,
-            audio_assets_by_id: to_index("assets.toml::audio", &assets.audio)?,
-            backgrounds_by_id: to_index("backgrounds.toml::backgrounds", &backgrounds.backgrounds)?,
+            sprite_assets_by_id: to_index("assets.toml", "sprites", &assets.sprites, &mut report),
+            model_assets_by_id: to_index("assets.toml", "models", &assets.models, &mut report),
+            splat_assets_by_id: to_index("assets.toml", "splats", &assets.splats, &mut report),
+            audio_assets_by_id: to_index("assets.toml", "audio", &assets.audio, &mut report),
+            backgrounds_by_id: to_index(
+                "backgrounds.toml",
+                "backgrounds",
+                &backgrounds.backgrounds,
+                &mut report,
+            ),
             environments_by_id: to_index(
-                "environments.toml::environments",
+                "environments.toml",
+                "environments",
                 &environments.environments,
-            )?,
-            enemy_types_by_id: to_index("enemy_types.toml::enemy_types", &enemy_types.enemy_types)?,
-            spawners_by_id: to_index("spawners.toml::spawners", &spawners.spawners)?,
-            weapons_by_id: to_index("weapons.toml::weapons", &weapons.weapons)?,
-            vehicles_by_id: to_index("vehicles.toml::vehicles", &vehicles.vehicles)?,
-            upgrades_by_id: to_index("upgrades.toml::upgrades", &upgrades.upgrades)?,
+                &mut report,
+            ),
+            enemy_types_by_id: to_index(
+                "enemy_types.toml",
+                "enemy_types",
+                &enemy_types.enemy_types,
+                &mut report,
+            ),
+            spawners_by_id: to_index("spawners.toml", "spawners", &spawners.spawners, &mut report),
+            weapons_by_id: to_index("weapons.toml", "weapons", &weapons.weapons, &mut report),
+            vehicles_by_id: to_index("vehicles.toml", "vehicles", &vehicles.vehicles, &mut report),
+            upgrades_by_id: to_index("upgrades.toml", "upgrades", &upgrades.upgrades, &mut report),
             game,
             assets,
             segments,
@@ -156,72 +324,119 @@ impl GameConfig {
             vehicles,
             upgrades,
             commentator,
+            profiles: profiles.to_vec(),
         };
 
-        config.validate_references()?;
+        config.collect_validation_issues(&mut report);
+        locate_layered_report(&mut report, config_dir, &profile_dirs);
+        report.into_result()?;
         Ok(config)
     }
 
     #[cfg(target_arch = "wasm32")]
     pub fn load_embedded() -> Result<Self, ConfigError> {
-        let game: GameFile =
-            read_toml_from_str("config/game.toml", include_str!("../../config/game.toml"))?;
-        let assets: AssetsFile = read_toml_from_str(
+        let mut report = ConfigReport::default();
+        let game: Option<GameFile> = report.absorb(read_toml_from_str(
+            "config/game.toml",
+            include_str!("../../config/game.toml"),
+        ));
+        let assets: Option<AssetsFile> = report.absorb(read_toml_from_str(
             "config/assets.toml",
             include_str!("../../config/assets.toml"),
-        )?;
-        let segments: SegmentsFile = read_toml_from_str(
+        ));
+        let segments: Option<SegmentsFile> = report.absorb(read_toml_from_str(
             "config/segments.toml",
             include_str!("../../config/segments.toml"),
-        )?;
-        let backgrounds: BackgroundsFile = read_toml_from_str(
+        ));
+        let backgrounds: Option<BackgroundsFile> = report.absorb(read_toml_from_str(
             "config/backgrounds.toml",
             include_str!("../../config/backgrounds.toml"),
-        )?;
-        let environments: EnvironmentsFile = read_toml_from_str(
+        ));
+        let environments: Option<EnvironmentsFile> = report.absorb(read_toml_from_str(
             "config/environments.toml",
             include_str!("../../config/environments.toml"),
-        )?;
-        let enemy_types: EnemyTypesFile = read_toml_from_str(
+        ));
+        let enemy_types: Option<EnemyTypesFile> = report.absorb(read_embedded_toml(
             "config/enemy_types.toml",
             include_str!("../../config/enemy_types.toml"),
-        )?;
-        let spawners: SpawnersFile = read_toml_from_str(
+        ));
+        let spawners: Option<SpawnersFile> = report.absorb(read_toml_from_str(
             "config/spawners.toml",
             include_str!("../../config/spawners.toml"),
-        )?;
-        let weapons: WeaponsFile = read_toml_from_str(
+        ));
+        let weapons: Option<WeaponsFile> = report.absorb(read_embedded_toml(
             "config/weapons.toml",
             include_str!("../../config/weapons.toml"),
-        )?;
-        let vehicles: VehiclesFile = read_toml_from_str(
+        ));
+        let vehicles: Option<VehiclesFile> = report.absorb(read_embedded_toml(
             "config/vehicles.toml",
             include_str!("../../config/vehicles.toml"),
-        )?;
-        let upgrades: UpgradesFile = read_toml_from_str(
+        ));
+        let upgrades: Option<UpgradesFile> = report.absorb(read_toml_from_str(
             "config/upgrades.toml",
             include_str!("../../config/upgrades.toml"),
-        )?;
-        let commentator: CommentatorFile = read_toml_from_str(
+        ));
+        let commentator: Option<CommentatorFile> = report.absorb(read_toml_from_str(
             "config/commentator.toml",
             include_str!("../../config/commentator.toml"),
-        )?;
+        ));
+
+        let (
+            Some(game),
+            Some(assets),
+            Some(segments),
+            Some(backgrounds),
+            Some(environments),
+            Some(enemy_types),
+            Some(spawners),
+            Some(weapons),
+            Some(vehicles),
+            Some(upgrades),
+            Some(commentator),
+        ) = (
+            game,
+            assets,
+            segments,
+            backgrounds,
+            environments,
+            enemy_types,
+            spawners,
+            weapons,
+            vehicles,
+            upgrades,
+            commentator,
+        )
+        else {
+            return Err(ConfigError::Invalid(report));
+        };
 
         let config = Self {
-            sprite_assets_by_id: to_index("assets.toml::sprites", &assets.sprites)?,
-            model_assets_by_id: to_index("assets.toml::models", &assets.models)?,
-            splat_assets_by_id: to_index("assets.toml::splats", &assets.splats)?,
-            audio_assets_by_id: to_index("assets.toml::audio", &assets.audio)?,
-            backgrounds_by_id: to_index("backgrounds.toml::backgrounds", &backgrounds.backgrounds)?,
+            sprite_assets_by_id: to_index("assets.toml", "sprites", &assets.sprites, &mut report),
+            model_assets_by_id: to_index("assets.toml", "models", &assets.models, &mut report),
+            splat_assets_by_id: to_index("assets.toml", "splats", &assets.splats, &mut report),
+            audio_assets_by_id: to_index("assets.toml", "audio", &assets.audio, &mut report),
+            backgrounds_by_id: to_index(
+                "backgrounds.toml",
+                "backgrounds",
+                &backgrounds.backgrounds,
+                &mut report,
+            ),
             environments_by_id: to_index(
-                "environments.toml::environments",
+                "environments.toml",
+                "environments",
                 &environments.environments,
-            )?,
-            enemy_types_by_id: to_index("enemy_types.toml::enemy_types", &enemy_types.enemy_types)?,
-            spawners_by_id: to_index("spawners.toml::spawners", &spawners.spawners)?,
-            weapons_by_id: to_index("weapons.toml::weapons", &weapons.weapons)?,
-            vehicles_by_id: to_index("vehicles.toml::vehicles", &vehicles.vehicles)?,
-            upgrades_by_id: to_index("upgrades.toml::upgrades", &upgrades.upgrades)?,
+                &mut report,
+            ),
+            enemy_types_by_id: to_index(
+                "enemy_types.toml",
+                "enemy_types",
+                &enemy_types.enemy_types,
+                &mut report,
+            ),
+            spawners_by_id: to_index("spawners.toml", "spawners", &spawners.spawners, &mut report),
+            weapons_by_id: to_index("weapons.toml", "weapons", &weapons.weapons, &mut report),
+            vehicles_by_id: to_index("vehicles.toml", "vehicles", &vehicles.vehicles, &mut report),
+            upgrades_by_id: to_index("upgrades.toml", "upgrades", &upgrades.upgrades, &mut report),
             game,
             assets,
             segments,
@@ -233,12 +448,22 @@ impl GameConfig {
             vehicles,
             upgrades,
             commentator,
+            profiles: Vec::new(),
         };
 
-        config.validate_references()?;
+        config.collect_validation_issues(&mut report);
+        report.into_result()?;
         Ok(config)
     }
 
+    pub fn profile_label(&self) -> String {
+        if self.profiles.is_empty() {
+            "base".to_string()
+        } else {
+            self.profiles.join("+")
+        }
+    }
+
     pub fn is_web_mode_active(&self) -> bool {
         #[cfg(target_arch = "wasm32")]
         {
@@ -251,35 +476,67 @@ impl GameConfig {
     }
 
     fn validate_references(&self) -> Result<(), ConfigError> {
+        let mut report = ConfigReport::default();
+        self.collect_validation_issues(&mut report);
+        report.into_result()
+    }
+
+    fn collect_validation_issues(&self, report: &mut ConfigReport) {
         if !self
             .environments_by_id
             .contains_key(&self.game.app.starting_environment)
         {
-            return Err(ConfigError::Validation(format!(
-                "game.toml::app.starting_environment references unknown environment id `{}`",
-                self.game.app.starting_environment
-            )));
+            report.push(
+                "game.toml",
+                "app.starting_environment",
+                None,
+                format!(
+                    "references unknown environment id `{}`",
+                    self.game.app.starting_environment
+                ),
+            );
         }
 
         if !self
             .vehicles_by_id
             .contains_key(&self.game.app.default_vehicle)
         {
-            return Err(ConfigError::Validation(format!(
-                "game.toml::app.default_vehicle references unknown vehicle id `{}`",
-                self.game.app.default_vehicle
-            )));
+            report.push(
+                "game.toml",
+                "app.default_vehicle",
+                None,
+                format!(
+                    "references unknown vehicle id `{}`",
+                    self.game.app.default_vehicle
+                ),
+            );
+        }
+
+        let fixed_timestep_hz = self.game.app.fixed_timestep_hz;
+        if !(10.0..=240.0).contains(&fixed_timestep_hz) {
+            report.push(
+                "game.toml",
+                "app.fixed_timestep_hz",
+                None,
+                format!("must be within 10..=240, got {fixed_timestep_hz}"),
+            );
         }
 
         if self.game.web.max_player_projectiles == 0 {
-            return Err(ConfigError::Validation(
-                "game.toml::web.max_player_projectiles must be >= 1".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "web.max_player_projectiles",
+                None,
+                "must be >= 1",
+            );
         }
         if self.game.web.max_enemy_projectiles == 0 {
-            return Err(ConfigError::Validation(
-                "game.toml::web.max_enemy_projectiles must be >= 1".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "web.max_enemy_projectiles",
+                None,
+                "must be >= 1",
+            );
         }
         let proxy_base = self.game.web.neocortex_proxy_base_url.trim();
         if !(proxy_base.is_empty()
@@ -287,48 +544,79 @@ impl GameConfig {
             || proxy_base.starts_with("https://")
             || proxy_base.starts_with('/'))
         {
-            return Err(ConfigError::Validation(
-                "game.toml::web.neocortex_proxy_base_url must start with `http://`, `https://`, or `/` when provided".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "web.neocortex_proxy_base_url",
+                None,
+                "must start with `http://`, `https://`, or `/` when provided",
+            );
         }
 
         for (index, segment) in self.segments.segment_sequence.iter().enumerate() {
             if !self.backgrounds_by_id.contains_key(&segment.id) {
-                return Err(ConfigError::Validation(format!(
-                    "segments.toml::segment_sequence[{index}].id `{}` is missing in backgrounds.toml::backgrounds",
-                    segment.id
-                )));
+                report.push(
+                    "segments.toml",
+                    format!("segment_sequence[{index}].id"),
+                    Some(index),
+                    format!(
+                        "`{}` is missing in backgrounds.toml::backgrounds",
+                        segment.id
+                    ),
+                );
             }
 
             if !self.environments_by_id.contains_key(&segment.environment) {
-                return Err(ConfigError::Validation(format!(
-                    "segments.toml::segment_sequence[{index}].environment references unknown environment id `{}`",
-                    segment.environment
-                )));
+                report.push(
+                    "segments.toml",
+                    format!("segment_sequence[{index}].environment"),
+                    Some(index),
+                    format!(
+                        "references unknown environment id `{}`",
+                        segment.environment
+                    ),
+                );
             }
 
             if let Some(spawn_set) = segment.spawn_set.as_deref() {
                 if !self.spawners_by_id.contains_key(spawn_set) {
-                    return Err(ConfigError::Validation(format!(
-                        "segments.toml::segment_sequence[{index}].spawn_set references unknown spawner id `{spawn_set}`"
-                    )));
+                    report.push(
+                        "segments.toml",
+                        format!("segment_sequence[{index}].spawn_set"),
+                        Some(index),
+                        format!("references unknown spawner id `{spawn_set}`"),
+                    );
                 }
             }
+
+            if !segment.difficulty_multiplier.is_finite() || segment.difficulty_multiplier <= 0.0 {
+                report.push(
+                    "segments.toml",
+                    format!("segment_sequence[{index}].difficulty_multiplier"),
+                    Some(index),
+                    "must be > 0",
+                );
+            }
         }
 
         for (index, background) in self.backgrounds.backgrounds.iter().enumerate() {
             if !background.parallax.is_finite() {
-                return Err(ConfigError::Validation(format!(
-                    "backgrounds.toml::backgrounds[{index}].parallax must be finite"
-                )));
+                report.push(
+                    "backgrounds.toml",
+                    format!("backgrounds[{index}].parallax"),
+                    Some(index),
+                    "must be finite",
+                );
             }
             if !background.offset_x_m.is_finite()
                 || !background.offset_y_m.is_finite()
                 || !background.offset_z_m.is_finite()
             {
-                return Err(ConfigError::Validation(format!(
-                    "backgrounds.toml::backgrounds[{index}] offsets must be finite"
-                )));
+                report.push(
+                    "backgrounds.toml",
+                    format!("backgrounds[{index}]"),
+                    Some(index),
+                    "offsets must be finite",
+                );
             }
             if !background.scale_x.is_finite()
                 || !background.scale_y.is_finite()
@@ -337,14 +625,20 @@ impl GameConfig {
                 || background.scale_y.abs() <= f32::EPSILON
                 || background.scale_z.abs() <= f32::EPSILON
             {
-                return Err(ConfigError::Validation(format!(
-                    "backgrounds.toml::backgrounds[{index}] scales must be finite and non-zero (negative values are allowed for axis flips)"
-                )));
+                report.push(
+                    "backgrounds.toml",
+                    format!("backgrounds[{index}]"),
+                    Some(index),
+                    "scales must be finite and non-zero (negative values are allowed for axis flips)",
+                );
             }
             if !background.loop_length_m.is_finite() || background.loop_length_m < 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "backgrounds.toml::backgrounds[{index}].loop_length_m must be >= 0"
-                )));
+                report.push(
+                    "backgrounds.toml",
+                    format!("backgrounds[{index}].loop_length_m"),
+                    Some(index),
+                    "must be >= 0",
+                );
             }
             for (label, value, requires_non_negative) in [
                 ("wave_a_amplitude", background.wave_a_amplitude, false),
@@ -356,292 +650,595 @@ impl GameConfig {
             ] {
                 if let Some(value) = value {
                     if !value.is_finite() {
-                        return Err(ConfigError::Validation(format!(
-                            "backgrounds.toml::backgrounds[{index}].{label} must be finite when provided"
-                        )));
+                        report.push(
+                            "backgrounds.toml",
+                            format!("backgrounds[{index}].{label}"),
+                            Some(index),
+                            "must be finite when provided",
+                        );
                     }
                     if requires_non_negative && value < 0.0 {
-                        return Err(ConfigError::Validation(format!(
-                            "backgrounds.toml::backgrounds[{index}].{label} must be >= 0 when provided"
-                        )));
+                        report.push(
+                            "backgrounds.toml",
+                            format!("backgrounds[{index}].{label}"),
+                            Some(index),
+                            "must be >= 0 when provided",
+                        );
                     }
                 }
             }
             if let Some(splat_asset_id) = background.splat_asset_id.as_deref() {
                 if !self.splat_assets_by_id.contains_key(splat_asset_id) {
-                    return Err(ConfigError::Validation(format!(
-                        "backgrounds.toml::backgrounds[{index}].splat_asset_id references unknown splat id `{splat_asset_id}`"
-                    )));
+                    report.push(
+                        "backgrounds.toml",
+                        format!("backgrounds[{index}].splat_asset_id"),
+                        Some(index),
+                        format!("references unknown splat id `{splat_asset_id}`"),
+                    );
                 }
             }
         }
 
         for (index, enemy) in self.enemy_types.enemy_types.iter().enumerate() {
             if !self.weapons_by_id.contains_key(&enemy.weapon_id) {
-                return Err(ConfigError::Validation(format!(
-                    "enemy_types.toml::enemy_types[{index}].weapon_id references unknown weapon id `{}`",
-                    enemy.weapon_id
-                )));
+                report.push(
+                    "enemy_types.toml",
+                    format!("enemy_types[{index}].weapon_id"),
+                    Some(index),
+                    format!("references unknown weapon id `{}`", enemy.weapon_id),
+                );
             }
             if enemy.health <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "enemy_types.toml::enemy_types[{index}].health must be > 0"
-                )));
+                report.push(
+                    "enemy_types.toml",
+                    format!("enemy_types[{index}].health"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if enemy.speed <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "enemy_types.toml::enemy_types[{index}].speed must be > 0"
-                )));
+                report.push(
+                    "enemy_types.toml",
+                    format!("enemy_types[{index}].speed"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if enemy.hitbox_radius <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "enemy_types.toml::enemy_types[{index}].hitbox_radius must be > 0"
-                )));
+                report.push(
+                    "enemy_types.toml",
+                    format!("enemy_types[{index}].hitbox_radius"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
-            if !matches!(
-                enemy.behavior.as_str(),
-                "walker" | "flier" | "turret" | "charger" | "bomber" | "boss"
-            ) {
-                return Err(ConfigError::Validation(format!(
-                    "enemy_types.toml::enemy_types[{index}].behavior `{}` is unsupported (expected walker/flier/turret/charger/bomber/boss)",
-                    enemy.behavior
-                )));
-            }
-            if enemy.behavior == "flier"
+            if enemy.behavior == EnemyBehaviorKind::Flier
                 && (enemy.hover_amplitude <= 0.0 || enemy.hover_frequency <= 0.0)
             {
-                return Err(ConfigError::Validation(format!(
-                    "enemy_types.toml::enemy_types[{index}] flier behavior requires hover_amplitude > 0 and hover_frequency > 0"
-                )));
+                report.push(
+                    "enemy_types.toml",
+                    format!("enemy_types[{index}]"),
+                    Some(index),
+                    "flier behavior requires hover_amplitude > 0 and hover_frequency > 0",
+                );
             }
-            if enemy.behavior == "charger" && enemy.charge_speed_multiplier <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "enemy_types.toml::enemy_types[{index}] charger behavior requires charge_speed_multiplier > 0"
-                )));
+            if enemy.behavior == EnemyBehaviorKind::Charger && enemy.charge_speed_multiplier <= 0.0
+            {
+                report.push(
+                    "enemy_types.toml",
+                    format!("enemy_types[{index}]"),
+                    Some(index),
+                    "charger behavior requires charge_speed_multiplier > 0",
+                );
             }
         }
 
         for (index, spawner) in self.spawners.spawners.iter().enumerate() {
+            if spawner.spawn_enemy_ids.is_empty() {
+                report.push(
+                    "spawners.toml",
+                    format!("spawners[{index}].spawn_enemy_ids"),
+                    Some(index),
+                    "must not be empty",
+                );
+            }
+            if spawner.max_alive == 0 {
+                report.push(
+                    "spawners.toml",
+                    format!("spawners[{index}].max_alive"),
+                    Some(index),
+                    "must be >= 1",
+                );
+            }
+            if spawner.start_distance < 0.0 {
+                report.push(
+                    "spawners.toml",
+                    format!("spawners[{index}].start_distance"),
+                    Some(index),
+                    "must be >= 0",
+                );
+            }
+            if spawner.interval_seconds < 0.0 {
+                report.push(
+                    "spawners.toml",
+                    format!("spawners[{index}].interval_seconds"),
+                    Some(index),
+                    "must be >= 0",
+                );
+            }
+            if spawner.spawn_ahead_m <= 0.0 || spawner.spawn_spacing_m <= 0.0 {
+                report.push(
+                    "spawners.toml",
+                    format!("spawners[{index}]"),
+                    Some(index),
+                    "spawn_ahead_m and spawn_spacing_m must be > 0",
+                );
+            }
             for (enemy_index, enemy_id) in spawner.spawn_enemy_ids.iter().enumerate() {
                 if !self.enemy_types_by_id.contains_key(enemy_id) {
-                    return Err(ConfigError::Validation(format!(
-                        "spawners.toml::spawners[{index}].spawn_enemy_ids[{enemy_index}] references unknown enemy id `{enemy_id}`"
-                    )));
+                    report.push(
+                        "spawners.toml",
+                        format!("spawners[{index}].spawn_enemy_ids[{enemy_index}]"),
+                        Some(index),
+                        format!("references unknown enemy id `{enemy_id}`"),
+                    );
                 }
             }
         }
 
         for (index, weapon) in self.weapons.weapons.iter().enumerate() {
-            if !matches!(weapon.projectile_type.as_str(), "bullet" | "missile") {
-                return Err(ConfigError::Validation(format!(
-                    "weapons.toml::weapons[{index}].projectile_type `{}` is unsupported (expected bullet/missile)",
-                    weapon.projectile_type
-                )));
-            }
             if weapon.bullet_speed <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "weapons.toml::weapons[{index}].bullet_speed must be > 0"
-                )));
+                report.push(
+                    "weapons.toml",
+                    format!("weapons[{index}].bullet_speed"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if weapon.fire_rate <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "weapons.toml::weapons[{index}].fire_rate must be > 0"
-                )));
+                report.push(
+                    "weapons.toml",
+                    format!("weapons[{index}].fire_rate"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if weapon.spread_degrees < 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "weapons.toml::weapons[{index}].spread_degrees must be >= 0"
-                )));
+                report.push(
+                    "weapons.toml",
+                    format!("weapons[{index}].spread_degrees"),
+                    Some(index),
+                    "must be >= 0",
+                );
             }
             if weapon.damage <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "weapons.toml::weapons[{index}].damage must be > 0"
-                )));
+                report.push(
+                    "weapons.toml",
+                    format!("weapons[{index}].damage"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if weapon.burst_count == 0 {
-                return Err(ConfigError::Validation(format!(
-                    "weapons.toml::weapons[{index}].burst_count must be >= 1"
-                )));
+                report.push(
+                    "weapons.toml",
+                    format!("weapons[{index}].burst_count"),
+                    Some(index),
+                    "must be >= 1",
+                );
             }
             if weapon.burst_interval_seconds < 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "weapons.toml::weapons[{index}].burst_interval_seconds must be >= 0"
-                )));
+                report.push(
+                    "weapons.toml",
+                    format!("weapons[{index}].burst_interval_seconds"),
+                    Some(index),
+                    "must be >= 0",
+                );
             }
             if weapon.projectile_drag < 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "weapons.toml::weapons[{index}].projectile_drag must be >= 0"
-                )));
+                report.push(
+                    "weapons.toml",
+                    format!("weapons[{index}].projectile_drag"),
+                    Some(index),
+                    "must be >= 0",
+                );
             }
             if weapon.projectile_lifetime_seconds <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "weapons.toml::weapons[{index}].projectile_lifetime_seconds must be > 0"
-                )));
+                report.push(
+                    "weapons.toml",
+                    format!("weapons[{index}].projectile_lifetime_seconds"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if weapon.missile_gravity_scale < 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "weapons.toml::weapons[{index}].missile_gravity_scale must be >= 0"
-                )));
+                report.push(
+                    "weapons.toml",
+                    format!("weapons[{index}].missile_gravity_scale"),
+                    Some(index),
+                    "must be >= 0",
+                );
             }
             if weapon.homing_turn_rate_degrees < 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "weapons.toml::weapons[{index}].homing_turn_rate_degrees must be >= 0"
-                )));
+                report.push(
+                    "weapons.toml",
+                    format!("weapons[{index}].homing_turn_rate_degrees"),
+                    Some(index),
+                    "must be >= 0",
+                );
             }
         }
 
         for (index, vehicle) in self.vehicles.vehicles.iter().enumerate() {
+            if let Some(model_id) = vehicle.model_id.as_deref() {
+                if !self.model_assets_by_id.contains_key(model_id) {
+                    report.push(
+                        "vehicles.toml",
+                        format!("vehicles[{index}].model_id"),
+                        Some(index),
+                        format!("references unknown model id `{model_id}` in assets.toml::models"),
+                    );
+                }
+            }
+            for (slot, part) in vehicle.parts.iter().flat_map(VehiclePartsConfig::slots) {
+                if !self.model_assets_by_id.contains_key(&part.model_id) {
+                    report.push(
+                        "vehicles.toml",
+                        format!("vehicles[{index}].parts.{slot}.model_id"),
+                        Some(index),
+                        format!(
+                            "references unknown model id `{}` in assets.toml::models",
+                            part.model_id
+                        ),
+                    );
+                }
+                if part.scale <= 0.0 {
+                    report.push(
+                        "vehicles.toml",
+                        format!("vehicles[{index}].parts.{slot}.scale"),
+                        Some(index),
+                        "must be > 0",
+                    );
+                }
+                if part
+                    .offset
+                    .iter()
+                    .chain(&part.rotation_degrees)
+                    .any(|value| !value.is_finite())
+                {
+                    report.push(
+                        "vehicles.toml",
+                        format!("vehicles[{index}].parts.{slot}"),
+                        Some(index),
+                        "offset and rotation_degrees must be finite",
+                    );
+                }
+            }
             if !self.weapons_by_id.contains_key(&vehicle.default_weapon_id) {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].default_weapon_id references unknown weapon id `{}`",
-                    vehicle.default_weapon_id
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].default_weapon_id"),
+                    Some(index),
+                    format!(
+                        "references unknown weapon id `{}`",
+                        vehicle.default_weapon_id
+                    ),
+                );
             }
             if let Some(secondary_weapon_id) = vehicle.secondary_weapon_id.as_deref() {
-                let Some(secondary_weapon) = self.weapons_by_id.get(secondary_weapon_id) else {
-                    return Err(ConfigError::Validation(format!(
-                        "vehicles.toml::vehicles[{index}].secondary_weapon_id references unknown weapon id `{secondary_weapon_id}`"
-                    )));
-                };
-                if secondary_weapon.projectile_type != "missile" {
-                    return Err(ConfigError::Validation(format!(
-                        "vehicles.toml::vehicles[{index}].secondary_weapon_id must point to a missile weapon"
-                    )));
+                match self.weapons_by_id.get(secondary_weapon_id) {
+                    None => report.push(
+                        "vehicles.toml",
+                        format!("vehicles[{index}].secondary_weapon_id"),
+                        Some(index),
+                        format!("references unknown weapon id `{secondary_weapon_id}`"),
+                    ),
+                    Some(secondary_weapon)
+                        if secondary_weapon.projectile_type != ProjectileKind::Missile =>
+                    {
+                        report.push(
+                            "vehicles.toml",
+                            format!("vehicles[{index}].secondary_weapon_id"),
+                            Some(index),
+                            "must point to a missile weapon",
+                        )
+                    }
+                    Some(_) => {}
                 }
                 if vehicle.missile_fire_interval_seconds <= 0.0 {
-                    return Err(ConfigError::Validation(format!(
-                        "vehicles.toml::vehicles[{index}].missile_fire_interval_seconds must be > 0 when secondary_weapon_id is set"
-                    )));
+                    report.push(
+                        "vehicles.toml",
+                        format!("vehicles[{index}].missile_fire_interval_seconds"),
+                        Some(index),
+                        "must be > 0 when secondary_weapon_id is set",
+                    );
                 }
             }
             if vehicle.max_forward_speed <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].max_forward_speed must be > 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].max_forward_speed"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if vehicle.max_reverse_speed <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].max_reverse_speed must be > 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].max_reverse_speed"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if vehicle.max_fall_speed <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].max_fall_speed must be > 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].max_fall_speed"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if vehicle.air_max_rotation_speed <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].air_max_rotation_speed must be > 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].air_max_rotation_speed"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if vehicle.linear_speed_scale <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].linear_speed_scale must be > 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].linear_speed_scale"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if vehicle.ground_coast_damping < 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].ground_coast_damping must be >= 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].ground_coast_damping"),
+                    Some(index),
+                    "must be >= 0",
+                );
             }
             if vehicle.air_base_damping < 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].air_base_damping must be >= 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].air_base_damping"),
+                    Some(index),
+                    "must be >= 0",
+                );
             }
             if vehicle.air_env_drag_factor < 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].air_env_drag_factor must be >= 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].air_env_drag_factor"),
+                    Some(index),
+                    "must be >= 0",
+                );
             }
             if vehicle.linear_inertia <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].linear_inertia must be > 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].linear_inertia"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if vehicle.rotational_inertia <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].rotational_inertia must be > 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].rotational_inertia"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if vehicle.gravity_scale <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].gravity_scale must be > 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].gravity_scale"),
+                    Some(index),
+                    "must be > 0",
+                );
+            }
+            if vehicle.chassis_length_m <= 0.0 {
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].chassis_length_m"),
+                    Some(index),
+                    "must be > 0",
+                );
+            }
+            if vehicle.chassis_height_m <= 0.0 {
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].chassis_height_m"),
+                    Some(index),
+                    "must be > 0",
+                );
+            }
+            if vehicle.chassis_mass_kg <= 0.0 {
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].chassis_mass_kg"),
+                    Some(index),
+                    "must be > 0",
+                );
+            }
+            if vehicle.wheel_radius_m <= 0.0 {
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].wheel_radius_m"),
+                    Some(index),
+                    "must be > 0",
+                );
+            }
+            if vehicle.front_hardpoint_x_m <= vehicle.rear_hardpoint_x_m {
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].front_hardpoint_x_m"),
+                    Some(index),
+                    "must be > rear_hardpoint_x_m",
+                );
+            }
+            if let Some(fuel) = &vehicle.fuel {
+                if !fuel.tank_capacity.is_finite() || fuel.tank_capacity <= 0.0 {
+                    report.push(
+                        "vehicles.toml",
+                        format!("vehicles[{index}].fuel.tank_capacity"),
+                        Some(index),
+                        "must be > 0",
+                    );
+                }
+                for (field, value) in [
+                    ("idle_burn_per_second", fuel.idle_burn_per_second),
+                    ("throttle_burn_per_second", fuel.throttle_burn_per_second),
+                    ("out_of_fuel_stall_seconds", fuel.out_of_fuel_stall_seconds),
+                ] {
+                    if !value.is_finite() || value < 0.0 {
+                        report.push(
+                            "vehicles.toml",
+                            format!("vehicles[{index}].fuel.{field}"),
+                            Some(index),
+                            "must be >= 0",
+                        );
+                    }
+                }
+            }
+            if let Some(nitro) = &vehicle.nitro {
+                for (field, value) in [
+                    ("meter_capacity", nitro.meter_capacity),
+                    ("boost_duration_s", nitro.boost_duration_s),
+                ] {
+                    if !value.is_finite() || value <= 0.0 {
+                        report.push(
+                            "vehicles.toml",
+                            format!("vehicles[{index}].nitro.{field}"),
+                            Some(index),
+                            "must be > 0",
+                        );
+                    }
+                }
+                for (field, value) in [
+                    ("stunt_charge", nitro.stunt_charge),
+                    ("kill_charge", nitro.kill_charge),
+                    ("thrust_acceleration", nitro.thrust_acceleration),
+                    ("max_speed_bonus_mps", nitro.max_speed_bonus_mps),
+                ] {
+                    if !value.is_finite() || value < 0.0 {
+                        report.push(
+                            "vehicles.toml",
+                            format!("vehicles[{index}].nitro.{field}"),
+                            Some(index),
+                            "must be >= 0",
+                        );
+                    }
+                }
             }
             if vehicle.suspension_rest_length_m <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].suspension_rest_length_m must be > 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].suspension_rest_length_m"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if vehicle.suspension_stiffness <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].suspension_stiffness must be > 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].suspension_stiffness"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if vehicle.suspension_damping < 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].suspension_damping must be >= 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].suspension_damping"),
+                    Some(index),
+                    "must be >= 0",
+                );
             }
             if vehicle.suspension_max_compression_m <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].suspension_max_compression_m must be > 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].suspension_max_compression_m"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if vehicle.suspension_max_extension_m < 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].suspension_max_extension_m must be >= 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].suspension_max_extension_m"),
+                    Some(index),
+                    "must be >= 0",
+                );
             }
             if vehicle.tire_longitudinal_grip <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].tire_longitudinal_grip must be > 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].tire_longitudinal_grip"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if !(0.0..=1.0).contains(&vehicle.tire_slip_grip_floor) {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].tire_slip_grip_floor must be in [0, 1]"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].tire_slip_grip_floor"),
+                    Some(index),
+                    "must be in [0, 1]",
+                );
             }
             if !(0.0..=1.0).contains(&vehicle.front_drive_ratio) {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].front_drive_ratio must be in [0, 1]"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].front_drive_ratio"),
+                    Some(index),
+                    "must be in [0, 1]",
+                );
             }
             if vehicle.rear_drive_traction_assist_distance_m < 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].rear_drive_traction_assist_distance_m must be >= 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].rear_drive_traction_assist_distance_m"),
+                    Some(index),
+                    "must be >= 0",
+                );
             }
             if !(0.0..=1.0).contains(&vehicle.rear_drive_traction_assist_min_factor) {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].rear_drive_traction_assist_min_factor must be in [0, 1]"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].rear_drive_traction_assist_min_factor"),
+                    Some(index),
+                    "must be in [0, 1]",
+                );
             }
             if vehicle.turret_range_m <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].turret_range_m must be > 0"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].turret_range_m"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if !(0.0 < vehicle.turret_cone_degrees && vehicle.turret_cone_degrees <= 180.0) {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].turret_cone_degrees must be in (0, 180]"
-                )));
-            }
-            if !matches!(
-                vehicle.turret_target_priority.as_str(),
-                "nearest" | "strongest"
-            ) {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}].turret_target_priority `{}` is unsupported (expected nearest/strongest)",
-                    vehicle.turret_target_priority
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}].turret_cone_degrees"),
+                    Some(index),
+                    "must be in (0, 180]",
+                );
             }
             if vehicle.camera_look_ahead_max <= vehicle.camera_look_ahead_min {
-                return Err(ConfigError::Validation(format!(
-                    "vehicles.toml::vehicles[{index}] camera look-ahead range is invalid (max must be > min)"
-                )));
+                report.push(
+                    "vehicles.toml",
+                    format!("vehicles[{index}]"),
+                    Some(index),
+                    "camera look-ahead range is invalid (max must be > min)",
+                );
             }
         }
 
@@ -652,91 +1249,120 @@ impl GameConfig {
             || self.game.terrain.wave_b_frequency < 0.0
             || self.game.terrain.wave_c_frequency < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::terrain wave frequencies must be >= 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "terrain",
+                None,
+                "wave frequencies must be >= 0",
+            );
         }
         if !self.game.terrain.ground_lowering_m.is_finite()
             || self.game.terrain.ground_lowering_m < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::terrain.ground_lowering_m must be >= 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "terrain.ground_lowering_m",
+                None,
+                "must be >= 0",
+            );
         }
         if !self.game.scoring.points_per_meter.is_finite()
             || self.game.scoring.points_per_meter < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::scoring.points_per_meter must be >= 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "scoring.points_per_meter",
+                None,
+                "must be >= 0",
+            );
         }
         if !self.game.scoring.airtime_points_per_second.is_finite()
             || self.game.scoring.airtime_points_per_second < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::scoring.airtime_points_per_second must be >= 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "scoring.airtime_points_per_second",
+                None,
+                "must be >= 0",
+            );
         }
         if !self.game.scoring.wheelie_points_per_second.is_finite()
             || self.game.scoring.wheelie_points_per_second < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::scoring.wheelie_points_per_second must be >= 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "scoring.wheelie_points_per_second",
+                None,
+                "must be >= 0",
+            );
         }
         if !self.game.pickups.despawn_seconds.is_finite()
             || self.game.pickups.despawn_seconds <= 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.despawn_seconds must be > 0".to_string(),
-            ));
+            report.push("game.toml", "pickups.despawn_seconds", None, "must be > 0");
         }
         if !self.game.pickups.despawn_behind_player_m.is_finite()
             || self.game.pickups.despawn_behind_player_m < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.despawn_behind_player_m must be >= 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups.despawn_behind_player_m",
+                None,
+                "must be >= 0",
+            );
         }
         if !self.game.pickups.gravity_mps2.is_finite() || self.game.pickups.gravity_mps2 < 0.0 {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.gravity_mps2 must be >= 0".to_string(),
-            ));
+            report.push("game.toml", "pickups.gravity_mps2", None, "must be >= 0");
         }
         if !self.game.pickups.bounce_damping.is_finite()
             || !(0.0..=1.0).contains(&self.game.pickups.bounce_damping)
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.bounce_damping must be in [0, 1]".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups.bounce_damping",
+                None,
+                "must be in [0, 1]",
+            );
         }
         if !self.game.pickups.ground_stop_speed_mps.is_finite()
             || self.game.pickups.ground_stop_speed_mps < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.ground_stop_speed_mps must be >= 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups.ground_stop_speed_mps",
+                None,
+                "must be >= 0",
+            );
         }
         if !self.game.pickups.ground_slide_damping.is_finite()
             || !(0.0..=1.0).contains(&self.game.pickups.ground_slide_damping)
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.ground_slide_damping must be in [0, 1]".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups.ground_slide_damping",
+                None,
+                "must be in [0, 1]",
+            );
         }
         if !self.game.pickups.collection_radius_m.is_finite()
             || self.game.pickups.collection_radius_m <= 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.collection_radius_m must be > 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups.collection_radius_m",
+                None,
+                "must be > 0",
+            );
         }
         if !self.game.pickups.drop_horizontal_spread_mps.is_finite()
             || self.game.pickups.drop_horizontal_spread_mps < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.drop_horizontal_spread_mps must be >= 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups.drop_horizontal_spread_mps",
+                None,
+                "must be >= 0",
+            );
         }
         if !self.game.pickups.drop_vertical_speed_min_mps.is_finite()
             || !self.game.pickups.drop_vertical_speed_max_mps.is_finite()
@@ -744,74 +1370,99 @@ impl GameConfig {
             || self.game.pickups.drop_vertical_speed_max_mps
                 < self.game.pickups.drop_vertical_speed_min_mps
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups drop vertical speed range is invalid".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups",
+                None,
+                "drop vertical speed range is invalid",
+            );
         }
         if !self.game.pickups.health_drop_chance.is_finite()
             || !(0.0..=1.0).contains(&self.game.pickups.health_drop_chance)
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.health_drop_chance must be in [0, 1]".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups.health_drop_chance",
+                None,
+                "must be in [0, 1]",
+            );
         }
         if !self.game.pickups.health_drop_heal_amount.is_finite()
             || self.game.pickups.health_drop_heal_amount < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.health_drop_heal_amount must be >= 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups.health_drop_heal_amount",
+                None,
+                "must be >= 0",
+            );
         }
         if !self.game.pickups.coin_score_scale.is_finite()
             || self.game.pickups.coin_score_scale < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.coin_score_scale must be >= 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups.coin_score_scale",
+                None,
+                "must be >= 0",
+            );
         }
         if !self.game.pickups.coin_radius_m.is_finite() || self.game.pickups.coin_radius_m <= 0.0 {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.coin_radius_m must be > 0".to_string(),
-            ));
+            report.push("game.toml", "pickups.coin_radius_m", None, "must be > 0");
         }
         if !self.game.pickups.health_box_size_m.is_finite()
             || self.game.pickups.health_box_size_m <= 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.health_box_size_m must be > 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups.health_box_size_m",
+                None,
+                "must be > 0",
+            );
         }
         if !self.game.pickups.coin_pickup_radius_m.is_finite()
             || self.game.pickups.coin_pickup_radius_m <= 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.coin_pickup_radius_m must be > 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups.coin_pickup_radius_m",
+                None,
+                "must be > 0",
+            );
         }
         if !self.game.pickups.health_pickup_radius_m.is_finite()
             || self.game.pickups.health_pickup_radius_m <= 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups.health_pickup_radius_m must be > 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups.health_pickup_radius_m",
+                None,
+                "must be > 0",
+            );
         }
         if !self.game.pickups.coin_spin_speed_min_rad_s.is_finite()
             || !self.game.pickups.coin_spin_speed_max_rad_s.is_finite()
             || self.game.pickups.coin_spin_speed_min_rad_s
                 > self.game.pickups.coin_spin_speed_max_rad_s
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups coin spin speed range is invalid".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups",
+                None,
+                "coin spin speed range is invalid",
+            );
         }
         if !self.game.pickups.health_spin_speed_min_rad_s.is_finite()
             || !self.game.pickups.health_spin_speed_max_rad_s.is_finite()
             || self.game.pickups.health_spin_speed_min_rad_s
                 > self.game.pickups.health_spin_speed_max_rad_s
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups health spin speed range is invalid".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "pickups",
+                None,
+                "health spin speed range is invalid",
+            );
         }
         if !self.game.pickups.coin_jitter_x_m.is_finite()
             || !self.game.pickups.coin_jitter_y_m.is_finite()
@@ -822,14 +1473,58 @@ impl GameConfig {
             || self.game.pickups.health_jitter_x_m < 0.0
             || self.game.pickups.health_jitter_y_m < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::pickups jitter values must be >= 0".to_string(),
-            ));
+            report.push("game.toml", "pickups", None, "jitter values must be >= 0");
+        }
+        if !self.game.pickups.fuel_drop_chance.is_finite()
+            || !(0.0..=1.0).contains(&self.game.pickups.fuel_drop_chance)
+        {
+            report.push(
+                "game.toml",
+                "pickups.fuel_drop_chance",
+                None,
+                "must be in [0, 1]",
+            );
+        }
+        if !self.game.pickups.fuel_refill_fraction.is_finite()
+            || self.game.pickups.fuel_refill_fraction <= 0.0
+            || self.game.pickups.fuel_refill_fraction > 1.0
+        {
+            report.push(
+                "game.toml",
+                "pickups.fuel_refill_fraction",
+                None,
+                "must be in (0, 1]",
+            );
+        }
+        if !self.game.pickups.fuel_can_size_m.is_finite()
+            || self.game.pickups.fuel_can_size_m <= 0.0
+        {
+            report.push("game.toml", "pickups.fuel_can_size_m", None, "must be > 0");
+        }
+        if !self.game.pickups.fuel_pickup_radius_m.is_finite()
+            || self.game.pickups.fuel_pickup_radius_m <= 0.0
+        {
+            report.push(
+                "game.toml",
+                "pickups.fuel_pickup_radius_m",
+                None,
+                "must be > 0",
+            );
+        }
+        if !self.game.pickups.fuel_track_spacing_m.is_finite()
+            || !self.game.pickups.fuel_track_spawn_ahead_m.is_finite()
+            || self.game.pickups.fuel_track_spacing_m < 0.0
+            || self.game.pickups.fuel_track_spawn_ahead_m < 0.0
+        {
+            report.push(
+                "game.toml",
+                "pickups",
+                None,
+                "fuel_track_spacing_m and fuel_track_spawn_ahead_m must be >= 0",
+            );
         }
         if !self.game.sfx.master_volume.is_finite() || self.game.sfx.master_volume < 0.0 {
-            return Err(ConfigError::Validation(
-                "game.toml::sfx.master_volume must be >= 0".to_string(),
-            ));
+            report.push("game.toml", "sfx.master_volume", None, "must be >= 0");
         }
         if !self.game.sfx.pitch_random_min.is_finite()
             || !self.game.sfx.pitch_random_max.is_finite()
@@ -837,49 +1532,43 @@ impl GameConfig {
             || self.game.sfx.pitch_random_max <= 0.0
             || self.game.sfx.pitch_random_min > self.game.sfx.pitch_random_max
         {
-            return Err(ConfigError::Validation(
-                "game.toml::sfx pitch random range is invalid".to_string(),
-            ));
+            report.push("game.toml", "sfx", None, "pitch random range is invalid");
         }
         if !self.game.sfx.engine_volume.is_finite() || self.game.sfx.engine_volume < 0.0 {
-            return Err(ConfigError::Validation(
-                "game.toml::sfx.engine_volume must be >= 0".to_string(),
-            ));
+            report.push("game.toml", "sfx.engine_volume", None, "must be >= 0");
         }
         if !self.game.sfx.engine_base_speed.is_finite() || self.game.sfx.engine_base_speed <= 0.0 {
-            return Err(ConfigError::Validation(
-                "game.toml::sfx.engine_base_speed must be > 0".to_string(),
-            ));
+            report.push("game.toml", "sfx.engine_base_speed", None, "must be > 0");
         }
         if !self.game.sfx.engine_accel_speed_boost.is_finite()
             || self.game.sfx.engine_accel_speed_boost < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::sfx.engine_accel_speed_boost must be >= 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "sfx.engine_accel_speed_boost",
+                None,
+                "must be >= 0",
+            );
         }
         if !self.game.sfx.engine_velocity_speed_boost.is_finite()
             || self.game.sfx.engine_velocity_speed_boost < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::sfx.engine_velocity_speed_boost must be >= 0".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "sfx.engine_velocity_speed_boost",
+                None,
+                "must be >= 0",
+            );
         }
         if !self.game.sfx.engine_idle_gain.is_finite() || self.game.sfx.engine_idle_gain < 0.0 {
-            return Err(ConfigError::Validation(
-                "game.toml::sfx.engine_idle_gain must be >= 0".to_string(),
-            ));
+            report.push("game.toml", "sfx.engine_idle_gain", None, "must be >= 0");
         }
         if !self.game.sfx.engine_load_gain.is_finite() || self.game.sfx.engine_load_gain < 0.0 {
-            return Err(ConfigError::Validation(
-                "game.toml::sfx.engine_load_gain must be >= 0".to_string(),
-            ));
+            report.push("game.toml", "sfx.engine_load_gain", None, "must be >= 0");
         }
         if !self.game.sfx.engine_pitch_jitter.is_finite() || self.game.sfx.engine_pitch_jitter < 0.0
         {
-            return Err(ConfigError::Validation(
-                "game.toml::sfx.engine_pitch_jitter must be >= 0".to_string(),
-            ));
+            report.push("game.toml", "sfx.engine_pitch_jitter", None, "must be >= 0");
         }
         for (label, value) in [
             ("music_volume", self.game.sfx.music_volume),
@@ -889,66 +1578,185 @@ impl GameConfig {
             ("missile_launch_volume", self.game.sfx.missile_launch_volume),
             ("missile_hit_volume", self.game.sfx.missile_hit_volume),
             ("explode_volume", self.game.sfx.explode_volume),
+            ("nitro_boost_volume", self.game.sfx.nitro_boost_volume),
         ] {
             if !value.is_finite() || value < 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "game.toml::sfx.{label} must be >= 0"
-                )));
+                report.push("game.toml", format!("sfx.{label}"), None, "must be >= 0");
             }
         }
         if self.game.run_upgrades.coins_per_offer == 0 {
-            return Err(ConfigError::Validation(
-                "game.toml::run_upgrades.coins_per_offer must be >= 1".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "run_upgrades.coins_per_offer",
+                None,
+                "must be >= 1",
+            );
         }
-        if self.game.run_upgrades.choices_per_offer == 0 {
-            return Err(ConfigError::Validation(
-                "game.toml::run_upgrades.choices_per_offer must be >= 1".to_string(),
-            ));
+        if !(1..=3).contains(&self.game.run_upgrades.choices_per_offer) {
+            report.push(
+                "game.toml",
+                "run_upgrades.choices_per_offer",
+                None,
+                "must be between 1 and 3",
+            );
+        }
+        for (rarity, weight) in &self.game.run_upgrades.rarity_weights {
+            if rarity.trim().is_empty() {
+                report.push(
+                    "game.toml",
+                    "run_upgrades.rarity_weights",
+                    None,
+                    "cannot contain an empty rarity",
+                );
+            }
+            if !weight.is_finite() || *weight < 0.0 {
+                report.push(
+                    "game.toml",
+                    format!("run_upgrades.rarity_weights.{rarity}"),
+                    None,
+                    "must be >= 0",
+                );
+            }
+        }
+        if !self
+            .game
+            .run_upgrades
+            .rarity_weights
+            .values()
+            .any(|weight| *weight > 0.0)
+        {
+            report.push(
+                "game.toml",
+                "run_upgrades.rarity_weights",
+                None,
+                "must include at least one weight > 0",
+            );
         }
         if self.game.run_upgrades.options.is_empty() {
-            return Err(ConfigError::Validation(
-                "game.toml::run_upgrades.options must include at least one option".to_string(),
-            ));
+            report.push(
+                "game.toml",
+                "run_upgrades.options",
+                None,
+                "must include at least one option",
+            );
         }
         let mut seen_upgrade_ids = std::collections::HashSet::new();
         for (index, option) in self.game.run_upgrades.options.iter().enumerate() {
             if option.id.trim().is_empty() {
-                return Err(ConfigError::Validation(format!(
-                    "game.toml::run_upgrades.options[{index}].id cannot be empty"
-                )));
+                report.push(
+                    "game.toml",
+                    format!("run_upgrades.options[{index}].id"),
+                    Some(index),
+                    "cannot be empty",
+                );
             }
             if !seen_upgrade_ids.insert(option.id.clone()) {
-                return Err(ConfigError::Validation(format!(
-                    "game.toml::run_upgrades.options contains duplicate id `{}`",
-                    option.id
-                )));
+                report.push(
+                    "game.toml",
+                    "run_upgrades.options",
+                    None,
+                    format!("contains duplicate id `{}`", option.id),
+                );
             }
             if option.label.trim().is_empty() {
-                return Err(ConfigError::Validation(format!(
-                    "game.toml::run_upgrades.options[{index}].label cannot be empty"
-                )));
+                report.push(
+                    "game.toml",
+                    format!("run_upgrades.options[{index}].label"),
+                    Some(index),
+                    "cannot be empty",
+                );
             }
             if !option.value.is_finite() || option.value <= 0.0 {
-                return Err(ConfigError::Validation(format!(
-                    "game.toml::run_upgrades.options[{index}].value must be > 0"
-                )));
+                report.push(
+                    "game.toml",
+                    format!("run_upgrades.options[{index}].value"),
+                    Some(index),
+                    "must be > 0",
+                );
             }
             if option.max_stacks == 0 {
-                return Err(ConfigError::Validation(format!(
-                    "game.toml::run_upgrades.options[{index}].max_stacks must be >= 1"
-                )));
+                report.push(
+                    "game.toml",
+                    format!("run_upgrades.options[{index}].max_stacks"),
+                    Some(index),
+                    "must be >= 1",
+                );
+            }
+            if !self
+                .game
+                .run_upgrades
+                .rarity_weights
+                .contains_key(&option.rarity)
+            {
+                report.push(
+                    "game.toml",
+                    format!("run_upgrades.options[{index}].rarity"),
+                    Some(index),
+                    format!(
+                        "`{}` is not listed in run_upgrades.rarity_weights",
+                        option.rarity
+                    ),
+                );
+            }
+        }
+        if self.game.difficulty.curve.is_empty() {
+            report.push(
+                "game.toml",
+                "difficulty.curve",
+                None,
+                "must include at least one point",
+            );
+        }
+        let mut previous_curve_distance = f32::NEG_INFINITY;
+        for (index, point) in self.game.difficulty.curve.iter().enumerate() {
+            if !point.distance_m.is_finite() || point.distance_m < previous_curve_distance {
+                report.push(
+                    "game.toml",
+                    format!("difficulty.curve[{index}].distance_m"),
+                    Some(index),
+                    "must be finite and ascending",
+                );
+            }
+            if !point.scalar.is_finite() || point.scalar <= 0.0 {
+                report.push(
+                    "game.toml",
+                    format!("difficulty.curve[{index}].scalar"),
+                    Some(index),
+                    "must be > 0",
+                );
+            }
+            previous_curve_distance = point.distance_m;
+        }
+        for (label, weight) in [
+            ("health_weight", self.game.difficulty.health_weight),
+            ("damage_weight", self.game.difficulty.damage_weight),
+            ("fire_rate_weight", self.game.difficulty.fire_rate_weight),
+            ("spawn_rate_weight", self.game.difficulty.spawn_rate_weight),
+        ] {
+            if !weight.is_finite() || weight < 0.0 {
+                report.push(
+                    "game.toml",
+                    format!("difficulty.{label}"),
+                    None,
+                    "must be >= 0",
+                );
             }
         }
         if self.commentator.commentary.min_seconds_between_lines < 0.0 {
-            return Err(ConfigError::Validation(
-                "commentator.toml::commentary.min_seconds_between_lines must be >= 0".to_string(),
-            ));
+            report.push(
+                "commentator.toml",
+                "commentary.min_seconds_between_lines",
+                None,
+                "must be >= 0",
+            );
         }
         if self.commentator.commentary.max_events_per_batch == 0 {
-            return Err(ConfigError::Validation(
-                "commentator.toml::commentary.max_events_per_batch must be >= 1".to_string(),
-            ));
+            report.push(
+                "commentator.toml",
+                "commentary.max_events_per_batch",
+                None,
+                "must be >= 1",
+            );
         }
         if !self
             .commentator
@@ -957,9 +1765,12 @@ impl GameConfig {
             .is_finite()
             || self.commentator.commentary.api_retry_backoff_seconds < 0.0
         {
-            return Err(ConfigError::Validation(
-                "commentator.toml::commentary.api_retry_backoff_seconds must be >= 0".to_string(),
-            ));
+            report.push(
+                "commentator.toml",
+                "commentary.api_retry_backoff_seconds",
+                None,
+                "must be >= 0",
+            );
         }
         if !self
             .commentator
@@ -972,117 +1783,265 @@ impl GameConfig {
                 .api_stale_request_timeout_seconds
                 <= 0.0
         {
-            return Err(ConfigError::Validation(
-                "commentator.toml::commentary.api_stale_request_timeout_seconds must be > 0"
-                    .to_string(),
-            ));
+            report.push(
+                "commentator.toml",
+                "commentary.api_stale_request_timeout_seconds",
+                None,
+                "must be > 0",
+            );
         }
         if !self.commentator.commentary.narration_volume.is_finite()
             || self.commentator.commentary.narration_volume < 0.0
         {
-            return Err(ConfigError::Validation(
-                "commentator.toml::commentary.narration_volume must be >= 0".to_string(),
-            ));
+            report.push(
+                "commentator.toml",
+                "commentary.narration_volume",
+                None,
+                "must be >= 0",
+            );
         }
         if self.commentator.commentators.len() < 2 {
-            return Err(ConfigError::Validation(
-                "commentator.toml must define at least two `[[commentators]]` profiles".to_string(),
-            ));
+            report.push(
+                "commentator.toml",
+                "",
+                None,
+                "must define at least two `[[commentators]]` profiles",
+            );
         }
         let mut commentator_ids = std::collections::HashSet::new();
         for (index, commentator) in self.commentator.commentators.iter().enumerate() {
             if commentator.id.trim().is_empty() {
-                return Err(ConfigError::Validation(format!(
-                    "commentator.toml::commentators[{index}].id cannot be empty"
-                )));
+                report.push(
+                    "commentator.toml",
+                    format!("commentators[{index}].id"),
+                    Some(index),
+                    "cannot be empty",
+                );
             }
             if commentator.name.trim().is_empty() {
-                return Err(ConfigError::Validation(format!(
-                    "commentator.toml::commentators[{index}].name cannot be empty"
-                )));
+                report.push(
+                    "commentator.toml",
+                    format!("commentators[{index}].name"),
+                    Some(index),
+                    "cannot be empty",
+                );
             }
             if commentator.character_id.trim().is_empty() {
-                return Err(ConfigError::Validation(format!(
-                    "commentator.toml::commentators[{index}].character_id cannot be empty"
-                )));
+                report.push(
+                    "commentator.toml",
+                    format!("commentators[{index}].character_id"),
+                    Some(index),
+                    "cannot be empty",
+                );
             }
             if !commentator_ids.insert(commentator.id.clone()) {
-                return Err(ConfigError::Validation(format!(
-                    "commentator.toml::commentators contains duplicate id `{}`",
-                    commentator.id
-                )));
+                report.push(
+                    "commentator.toml",
+                    "commentators",
+                    None,
+                    format!("contains duplicate id `{}`", commentator.id),
+                );
             }
             if commentator.style_instruction.trim().is_empty() {
-                return Err(ConfigError::Validation(format!(
-                    "commentator.toml::commentators[{index}].style_instruction cannot be empty"
-                )));
+                report.push(
+                    "commentator.toml",
+                    format!("commentators[{index}].style_instruction"),
+                    Some(index),
+                    "cannot be empty",
+                );
             }
             if !matches!(
                 commentator.style_length.as_str(),
                 "short" | "medium" | "long"
             ) {
-                return Err(ConfigError::Validation(format!(
-                    "commentator.toml::commentators[{index}].style_length `{}` is unsupported (expected short/medium/long)",
-                    commentator.style_length
-                )));
+                report.push(
+                    "commentator.toml",
+                    format!("commentators[{index}].style_length"),
+                    Some(index),
+                    format!(
+                        "`{}` is unsupported (expected short/medium/long)",
+                        commentator.style_length
+                    ),
+                );
             }
             if commentator.emotions.is_empty() {
-                return Err(ConfigError::Validation(format!(
-                    "commentator.toml::commentators[{index}].emotions must contain at least one emotion"
-                )));
+                report.push(
+                    "commentator.toml",
+                    format!("commentators[{index}].emotions"),
+                    Some(index),
+                    "must contain at least one emotion",
+                );
             }
             for (emotion_index, emotion) in commentator.emotions.iter().enumerate() {
                 if emotion.trim().is_empty() {
-                    return Err(ConfigError::Validation(format!(
-                        "commentator.toml::commentators[{index}].emotions[{emotion_index}] cannot be empty"
-                    )));
+                    report.push(
+                        "commentator.toml",
+                        format!("commentators[{index}].emotions[{emotion_index}]"),
+                        Some(index),
+                        "cannot be empty",
+                    );
                 }
             }
         }
 
         for (index, sprite) in self.assets.sprites.iter().enumerate() {
             if sprite.path.trim().is_empty() {
-                return Err(ConfigError::Validation(format!(
-                    "assets.toml::sprites[{index}].path cannot be empty"
-                )));
+                report.push(
+                    "assets.toml",
+                    format!("sprites[{index}].path"),
+                    Some(index),
+                    "cannot be empty",
+                );
             }
         }
 
+        // Part models are single pieces placed on their own anchors, so only
+        // whole-vehicle scenes (same `model_id` / `vehicle_<id>` / `<id>` lookup
+        // as the vehicle scene) need wheel nodes.
+        let whole_vehicle_model_ids: std::collections::HashSet<String> = self
+            .vehicles
+            .vehicles
+            .iter()
+            .flat_map(|vehicle| match vehicle.model_id.as_deref() {
+                Some(model_id) => vec![model_id.to_string()],
+                None => vec![format!("vehicle_{}", vehicle.id), vehicle.id.clone()],
+            })
+            .collect();
+        let part_model_ids: std::collections::HashSet<&str> = self
+            .vehicles
+            .vehicles
+            .iter()
+            .filter_map(|vehicle| vehicle.parts.as_ref())
+            .flat_map(|parts| parts.slots().map(|(_, part)| part.model_id.as_str()))
+            .collect();
         for (index, model) in self.assets.models.iter().enumerate() {
             if model.scene_path.trim().is_empty() {
-                return Err(ConfigError::Validation(format!(
-                    "assets.toml::models[{index}].scene_path cannot be empty"
-                )));
+                report.push(
+                    "assets.toml",
+                    format!("models[{index}].scene_path"),
+                    Some(index),
+                    "cannot be empty",
+                );
             }
             if model.root_node.trim().is_empty() {
-                return Err(ConfigError::Validation(format!(
-                    "assets.toml::models[{index}].root_node cannot be empty"
-                )));
+                report.push(
+                    "assets.toml",
+                    format!("models[{index}].root_node"),
+                    Some(index),
+                    "cannot be empty",
+                );
             }
-            if model.wheel_nodes.is_empty() {
-                return Err(ConfigError::Validation(format!(
-                    "assets.toml::models[{index}].wheel_nodes must contain at least one node name"
-                )));
+            let parts_only = part_model_ids.contains(model.id.as_str())
+                && !whole_vehicle_model_ids.contains(&model.id);
+            if model.wheel_nodes.is_empty() && !parts_only {
+                report.push(
+                    "assets.toml",
+                    format!("models[{index}].wheel_nodes"),
+                    Some(index),
+                    "must contain at least one node name",
+                );
             }
         }
 
         for (index, splat) in self.assets.splats.iter().enumerate() {
             if splat.path.trim().is_empty() {
-                return Err(ConfigError::Validation(format!(
-                    "assets.toml::splats[{index}].path cannot be empty"
-                )));
+                report.push(
+                    "assets.toml",
+                    format!("splats[{index}].path"),
+                    Some(index),
+                    "cannot be empty",
+                );
             }
         }
 
         for (index, audio) in self.assets.audio.iter().enumerate() {
             if audio.path.trim().is_empty() {
-                return Err(ConfigError::Validation(format!(
-                    "assets.toml::audio[{index}].path cannot be empty"
-                )));
+                report.push(
+                    "assets.toml",
+                    format!("audio[{index}].path"),
+                    Some(index),
+                    "cannot be empty",
+                );
             }
         }
 
-        Ok(())
+        for (index, upgrade) in self.upgrades.upgrades.iter().enumerate() {
+            if let Err(error) =
+                StatPath::parse(&upgrade.target).and_then(|path| self.stat_value(&path))
+            {
+                report.push(
+                    "upgrades.toml",
+                    format!("upgrades[{index}].target"),
+                    Some(index),
+                    format!("is invalid: {error}"),
+                );
+            }
+            if !upgrade.add.is_finite() {
+                report.push(
+                    "upgrades.toml",
+                    format!("upgrades[{index}].add"),
+                    Some(index),
+                    "must be finite",
+                );
+            }
+            if !upgrade.multiply.is_finite() || upgrade.multiply <= 0.0 {
+                report.push(
+                    "upgrades.toml",
+                    format!("upgrades[{index}].multiply"),
+                    Some(index),
+                    "must be > 0",
+                );
+            }
+            if upgrade.add == 0.0 && upgrade.multiply == 1.0 {
+                report.push(
+                    "upgrades.toml",
+                    format!("upgrades[{index}]"),
+                    Some(index),
+                    "must set `add` or `multiply`",
+                );
+            }
+            if upgrade.max_stacks == 0 {
+                report.push(
+                    "upgrades.toml",
+                    format!("upgrades[{index}].max_stacks"),
+                    Some(index),
+                    "must be >= 1",
+                );
+            }
+            if !self
+                .game
+                .run_upgrades
+                .rarity_weights
+                .contains_key(&upgrade.rarity)
+            {
+                report.push(
+                    "upgrades.toml",
+                    format!("upgrades[{index}].rarity"),
+                    Some(index),
+                    format!(
+                        "`{}` is not listed in game.toml::run_upgrades.rarity_weights",
+                        upgrade.rarity
+                    ),
+                );
+            }
+            if self
+                .game
+                .run_upgrades
+                .options
+                .iter()
+                .any(|option| option.id == upgrade.id)
+            {
+                report.push(
+                    "upgrades.toml",
+                    format!("upgrades[{index}].id"),
+                    Some(index),
+                    format!(
+                        "`{}` collides with a game.toml::run_upgrades option",
+                        upgrade.id
+                    ),
+                );
+            }
+        }
     }
 
     pub fn active_segment_id_for_distance(&self, distance_m: f32) -> Option<&str> {
@@ -1103,6 +2062,13 @@ impl GameConfig {
             })
     }
 
+    pub fn difficulty_scalar_at_distance(&self, distance_m: f32) -> f32 {
+        let segment_multiplier = self
+            .resolve_segment_at_distance(distance_m)
+            .map_or(1.0, |(_, segment, _, _)| segment.difficulty_multiplier);
+        self.game.difficulty.curve_scalar_at_distance(distance_m) * segment_multiplier
+    }
+
     pub fn segment_start_x_for_index(&self, segment_index: usize) -> Option<f32> {
         if segment_index >= self.segments.segment_sequence.len() {
             return None;
@@ -1257,7 +2223,7 @@ pub enum ConfigError {
         path: PathBuf,
         source: Box<toml::de::Error>,
     },
-    Validation(String),
+    Invalid(ConfigReport),
 }
 
 impl Display for ConfigError {
@@ -1269,7 +2235,7 @@ impl Display for ConfigError {
             Self::Parse { path, source } => {
                 write!(f, "failed to parse `{}`: {source}", path.display())
             }
-            Self::Validation(message) => write!(f, "{message}"),
+            Self::Invalid(report) => write!(f, "{report}"),
         }
     }
 }
@@ -1279,7 +2245,7 @@ impl Error for ConfigError {
         match self {
             Self::Io { source, .. } => Some(source),
             Self::Parse { source, .. } => Some(source),
-            Self::Validation(_) => None,
+            Self::Invalid(_) => None,
         }
     }
 }
@@ -1299,35 +2265,56 @@ fn read_toml_from_str<T: DeserializeOwned>(path_label: &str, raw: &str) -> Resul
     })
 }
 
-fn to_index<T>(label: &str, rows: &[T]) -> Result<HashMap<String, T>, ConfigError>
+fn to_index<T>(
+    file: &str,
+    rows_key: &str,
+    rows: &[T],
+    report: &mut ConfigReport,
+) -> HashMap<String, T>
 where
     T: HasId + Clone,
 {
     let mut map = HashMap::new();
 
-    for row in rows {
+    for (index, row) in rows.iter().enumerate() {
         let id = row.id();
         if id.trim().is_empty() {
-            return Err(ConfigError::Validation(format!(
-                "{label} contains an empty id"
-            )));
+            report.push(
+                file,
+                format!("{rows_key}[{index}].id"),
+                Some(index),
+                "cannot be empty",
+            );
+            continue;
         }
 
-        if map.insert(id.to_string(), row.clone()).is_some() {
-            return Err(ConfigError::Validation(format!(
-                "{label} contains duplicate id `{id}`"
-            )));
+        if map.contains_key(id) {
+            report.push(
+                file,
+                format!("{rows_key}[{index}].id"),
+                Some(index),
+                format!("`{id}` is a duplicate"),
+            );
+            continue;
         }
+        map.insert(id.to_string(), row.clone());
     }
 
-    Ok(map)
+    map
 }
 
 trait HasId {
     fn id(&self) -> &str;
 }
 
-#[derive(Debug, Clone, Deserialize)]
+// Config floats are f32; widening one straight to f64 prints
+// `0.9700000286102295`. Going through the f32's shortest decimal form keeps
+// `0.97` wherever config values are serialized or written back to TOML.
+pub fn f32_to_short_f64(value: f32) -> f64 {
+    value.to_string().parse().unwrap_or(value as f64)
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct GameFile {
     pub app: AppConfig,
     pub terrain: TerrainConfig,
@@ -1338,12 +2325,14 @@ pub struct GameFile {
     #[serde(default)]
     pub run_upgrades: RunUpgradeConfig,
     #[serde(default)]
+    pub difficulty: DifficultyConfig,
+    #[serde(default)]
     pub sfx: SfxConfig,
     #[serde(default)]
     pub web: WebConfig,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct AppConfig {
     pub fixed_timestep_hz: f32,
     pub starting_environment: String,
@@ -1351,7 +2340,7 @@ pub struct AppConfig {
     pub debug_overlay: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct WebConfig {
     #[serde(default = "default_web_enabled")]
     pub enabled: bool,
@@ -1425,7 +2414,7 @@ fn default_web_allow_client_api_key() -> bool {
     false
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct TerrainConfig {
     pub base_height: f32,
     pub ramp_slope: f32,
@@ -1453,7 +2442,7 @@ fn default_terrain_ground_lowering_m() -> f32 {
     0.0
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ScoringConfig {
     #[serde(default = "default_points_per_meter")]
     pub points_per_meter: f32,
@@ -1499,7 +2488,7 @@ fn default_no_damage_bonus() -> u32 {
     600
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PickupConfig {
     #[serde(default = "default_pickup_despawn_seconds")]
     pub despawn_seconds: f32,
@@ -1553,6 +2542,20 @@ pub struct PickupConfig {
     pub health_jitter_x_m: f32,
     #[serde(default = "default_pickup_health_jitter_y_m")]
     pub health_jitter_y_m: f32,
+    #[serde(default = "default_pickup_fuel_drop_chance")]
+    pub fuel_drop_chance: f32,
+    #[serde(default = "default_pickup_fuel_refill_fraction")]
+    pub fuel_refill_fraction: f32,
+    #[serde(default = "default_pickup_fuel_can_size_m")]
+    pub fuel_can_size_m: f32,
+    #[serde(default = "default_pickup_fuel_pickup_radius_m")]
+    pub fuel_pickup_radius_m: f32,
+    // Fuel cans are also placed along the track every `fuel_track_spacing_m`
+    // (0 disables), spawned this far ahead of the player.
+    #[serde(default = "default_pickup_fuel_track_spacing_m")]
+    pub fuel_track_spacing_m: f32,
+    #[serde(default = "default_pickup_fuel_track_spawn_ahead_m")]
+    pub fuel_track_spawn_ahead_m: f32,
 }
 
 impl Default for PickupConfig {
@@ -1584,6 +2587,12 @@ impl Default for PickupConfig {
             coin_jitter_y_m: default_pickup_coin_jitter_y_m(),
             health_jitter_x_m: default_pickup_health_jitter_x_m(),
             health_jitter_y_m: default_pickup_health_jitter_y_m(),
+            fuel_drop_chance: default_pickup_fuel_drop_chance(),
+            fuel_refill_fraction: default_pickup_fuel_refill_fraction(),
+            fuel_can_size_m: default_pickup_fuel_can_size_m(),
+            fuel_pickup_radius_m: default_pickup_fuel_pickup_radius_m(),
+            fuel_track_spacing_m: default_pickup_fuel_track_spacing_m(),
+            fuel_track_spawn_ahead_m: default_pickup_fuel_track_spawn_ahead_m(),
         }
     }
 }
@@ -1692,12 +2701,42 @@ fn default_pickup_health_jitter_y_m() -> f32 {
     0.14
 }
 
-#[derive(Debug, Clone, Deserialize)]
+fn default_pickup_fuel_drop_chance() -> f32 {
+    0.10
+}
+
+fn default_pickup_fuel_refill_fraction() -> f32 {
+    0.5
+}
+
+fn default_pickup_fuel_can_size_m() -> f32 {
+    0.66
+}
+
+fn default_pickup_fuel_pickup_radius_m() -> f32 {
+    0.55
+}
+
+fn default_pickup_fuel_track_spacing_m() -> f32 {
+    250.0
+}
+
+fn default_pickup_fuel_track_spawn_ahead_m() -> f32 {
+    60.0
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct RunUpgradeConfig {
     #[serde(default = "default_run_upgrade_coins_per_offer")]
     pub coins_per_offer: u32,
     #[serde(default = "default_run_upgrade_choices_per_offer")]
     pub choices_per_offer: usize,
+    #[serde(default = "default_run_upgrade_reroll_cost_coins")]
+    pub reroll_cost_coins: u32,
+    #[serde(default = "default_run_upgrade_skip_cost_coins")]
+    pub skip_cost_coins: u32,
+    #[serde(default = "default_run_upgrade_rarity_weights")]
+    pub rarity_weights: HashMap<String, f32>,
     #[serde(default = "default_run_upgrade_options")]
     pub options: Vec<RunUpgradeOptionConfig>,
 }
@@ -1707,12 +2746,21 @@ impl Default for RunUpgradeConfig {
         Self {
             coins_per_offer: default_run_upgrade_coins_per_offer(),
             choices_per_offer: default_run_upgrade_choices_per_offer(),
+            reroll_cost_coins: default_run_upgrade_reroll_cost_coins(),
+            skip_cost_coins: default_run_upgrade_skip_cost_coins(),
+            rarity_weights: default_run_upgrade_rarity_weights(),
             options: default_run_upgrade_options(),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+impl RunUpgradeConfig {
+    pub fn rarity_weight(&self, rarity: &str) -> f32 {
+        self.rarity_weights.get(rarity).copied().unwrap_or(0.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum RunUpgradeEffectKind {
     HealthFlat,
@@ -1724,7 +2772,7 @@ pub enum RunUpgradeEffectKind {
     TurretRangePercent,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct RunUpgradeOptionConfig {
     pub id: String,
     pub label: String,
@@ -1732,6 +2780,8 @@ pub struct RunUpgradeOptionConfig {
     pub value: f32,
     #[serde(default = "default_run_upgrade_max_stacks")]
     pub max_stacks: u32,
+    #[serde(default = "default_upgrade_rarity")]
+    pub rarity: String,
 }
 
 fn default_run_upgrade_coins_per_offer() -> u32 {
@@ -1742,10 +2792,133 @@ fn default_run_upgrade_choices_per_offer() -> usize {
     2
 }
 
+fn default_run_upgrade_reroll_cost_coins() -> u32 {
+    3
+}
+
+fn default_run_upgrade_skip_cost_coins() -> u32 {
+    1
+}
+
+fn default_run_upgrade_rarity_weights() -> HashMap<String, f32> {
+    HashMap::from([
+        ("common".to_string(), 70.0),
+        ("rare".to_string(), 25.0),
+        ("epic".to_string(), 5.0),
+    ])
+}
+
+fn default_upgrade_rarity() -> String {
+    "common".to_string()
+}
+
 fn default_run_upgrade_max_stacks() -> u32 {
     50
 }
 
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DifficultyConfig {
+    #[serde(default = "default_difficulty_curve")]
+    pub curve: Vec<DifficultyCurvePoint>,
+    #[serde(default = "default_difficulty_health_weight")]
+    pub health_weight: f32,
+    #[serde(default = "default_difficulty_damage_weight")]
+    pub damage_weight: f32,
+    #[serde(default = "default_difficulty_fire_rate_weight")]
+    pub fire_rate_weight: f32,
+    #[serde(default = "default_difficulty_spawn_rate_weight")]
+    pub spawn_rate_weight: f32,
+}
+
+impl Default for DifficultyConfig {
+    fn default() -> Self {
+        Self {
+            curve: default_difficulty_curve(),
+            health_weight: default_difficulty_health_weight(),
+            damage_weight: default_difficulty_damage_weight(),
+            fire_rate_weight: default_difficulty_fire_rate_weight(),
+            spawn_rate_weight: default_difficulty_spawn_rate_weight(),
+        }
+    }
+}
+
+impl DifficultyConfig {
+    pub fn curve_scalar_at_distance(&self, distance_m: f32) -> f32 {
+        let Some(first) = self.curve.first() else {
+            return 1.0;
+        };
+        if distance_m <= first.distance_m {
+            return first.scalar;
+        }
+
+        for pair in self.curve.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            if distance_m <= to.distance_m {
+                let span = (to.distance_m - from.distance_m).max(f32::EPSILON);
+                let t = ((distance_m - from.distance_m) / span).clamp(0.0, 1.0);
+                return from.scalar + (to.scalar - from.scalar) * t;
+            }
+        }
+
+        self.curve.last().map_or(1.0, |point| point.scalar)
+    }
+
+    pub fn health_multiplier(&self, scalar: f32) -> f32 {
+        weighted_difficulty_multiplier(scalar, self.health_weight)
+    }
+
+    pub fn damage_multiplier(&self, scalar: f32) -> f32 {
+        weighted_difficulty_multiplier(scalar, self.damage_weight)
+    }
+
+    pub fn fire_rate_multiplier(&self, scalar: f32) -> f32 {
+        weighted_difficulty_multiplier(scalar, self.fire_rate_weight)
+    }
+
+    pub fn spawn_rate_multiplier(&self, scalar: f32) -> f32 {
+        weighted_difficulty_multiplier(scalar, self.spawn_rate_weight)
+    }
+}
+
+fn weighted_difficulty_multiplier(scalar: f32, weight: f32) -> f32 {
+    (1.0 + (scalar - 1.0) * weight).max(0.05)
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+pub struct DifficultyCurvePoint {
+    pub distance_m: f32,
+    pub scalar: f32,
+}
+
+fn default_difficulty_curve() -> Vec<DifficultyCurvePoint> {
+    vec![
+        DifficultyCurvePoint {
+            distance_m: 0.0,
+            scalar: 1.0,
+        },
+        DifficultyCurvePoint {
+            distance_m: 2000.0,
+            scalar: 1.5,
+        },
+    ]
+}
+
+fn default_difficulty_health_weight() -> f32 {
+    1.0
+}
+
+fn default_difficulty_damage_weight() -> f32 {
+    0.5
+}
+
+fn default_difficulty_fire_rate_weight() -> f32 {
+    0.5
+}
+
+fn default_difficulty_spawn_rate_weight() -> f32 {
+    0.75
+}
+
 fn default_run_upgrade_options() -> Vec<RunUpgradeOptionConfig> {
     vec![
         RunUpgradeOptionConfig {
@@ -1754,6 +2927,7 @@ fn default_run_upgrade_options() -> Vec<RunUpgradeOptionConfig> {
             effect: RunUpgradeEffectKind::HealthFlat,
             value: 10.0,
             max_stacks: 50,
+            rarity: default_upgrade_rarity(),
         },
         RunUpgradeOptionConfig {
             id: "gun_fire_rate_plus_10_percent".to_string(),
@@ -1761,6 +2935,7 @@ fn default_run_upgrade_options() -> Vec<RunUpgradeOptionConfig> {
             effect: RunUpgradeEffectKind::WeaponFireRatePercent,
             value: 0.10,
             max_stacks: 50,
+            rarity: default_upgrade_rarity(),
         },
         RunUpgradeOptionConfig {
             id: "missile_fire_rate_plus_10_percent".to_string(),
@@ -1768,6 +2943,7 @@ fn default_run_upgrade_options() -> Vec<RunUpgradeOptionConfig> {
             effect: RunUpgradeEffectKind::MissileFireRatePercent,
             value: 0.10,
             max_stacks: 50,
+            rarity: default_upgrade_rarity(),
         },
         RunUpgradeOptionConfig {
             id: "vehicle_power_plus_10_percent".to_string(),
@@ -1775,6 +2951,7 @@ fn default_run_upgrade_options() -> Vec<RunUpgradeOptionConfig> {
             effect: RunUpgradeEffectKind::VehiclePowerPercent,
             value: 0.10,
             max_stacks: 50,
+            rarity: default_upgrade_rarity(),
         },
         RunUpgradeOptionConfig {
             id: "turret_cone_plus_5_degrees".to_string(),
@@ -1782,6 +2959,7 @@ fn default_run_upgrade_options() -> Vec<RunUpgradeOptionConfig> {
             effect: RunUpgradeEffectKind::TurretConeDegreesFlat,
             value: 5.0,
             max_stacks: 30,
+            rarity: default_upgrade_rarity(),
         },
         RunUpgradeOptionConfig {
             id: "missile_turn_rate_plus_10_percent".to_string(),
@@ -1789,6 +2967,7 @@ fn default_run_upgrade_options() -> Vec<RunUpgradeOptionConfig> {
             effect: RunUpgradeEffectKind::MissileTurnRatePercent,
             value: 0.10,
             max_stacks: 50,
+            rarity: default_upgrade_rarity(),
         },
         RunUpgradeOptionConfig {
             id: "turret_range_plus_10_percent".to_string(),
@@ -1796,11 +2975,12 @@ fn default_run_upgrade_options() -> Vec<RunUpgradeOptionConfig> {
             effect: RunUpgradeEffectKind::TurretRangePercent,
             value: 0.10,
             max_stacks: 50,
+            rarity: default_upgrade_rarity(),
         },
     ]
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct SfxConfig {
     #[serde(default = "default_sfx_enabled")]
     pub enabled: bool,
@@ -1838,6 +3018,8 @@ pub struct SfxConfig {
     pub missile_hit_volume: f32,
     #[serde(default = "default_sfx_explode_volume")]
     pub explode_volume: f32,
+    #[serde(default = "default_sfx_nitro_boost_volume")]
+    pub nitro_boost_volume: f32,
 }
 
 impl Default for SfxConfig {
@@ -1861,6 +3043,7 @@ impl Default for SfxConfig {
             missile_launch_volume: default_sfx_missile_launch_volume(),
             missile_hit_volume: default_sfx_missile_hit_volume(),
             explode_volume: default_sfx_explode_volume(),
+            nitro_boost_volume: default_sfx_nitro_boost_volume(),
         }
     }
 }
@@ -1937,28 +3120,44 @@ fn default_sfx_explode_volume() -> f32 {
     0.40
 }
 
-#[derive(Debug, Clone, Deserialize)]
+fn default_sfx_nitro_boost_volume() -> f32 {
+    0.50
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct SegmentsFile {
     pub segment_sequence: Vec<SegmentSequenceConfig>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct SegmentSequenceConfig {
     pub id: String,
     pub length: f32,
     pub environment: String,
     pub spawn_set: Option<String>,
+    #[serde(default = "default_segment_difficulty_multiplier")]
+    pub difficulty_multiplier: f32,
+}
+
+fn default_segment_difficulty_multiplier() -> f32 {
+    1.0
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct BackgroundsFile {
     pub backgrounds: Vec<BackgroundConfig>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BackgroundPlaceholder {
+    Box,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct BackgroundConfig {
     pub id: String,
-    pub placeholder: String,
+    pub placeholder: BackgroundPlaceholder,
     pub color: [f32; 3],
     pub parallax: f32,
     #[serde(default)]
@@ -2025,12 +3224,12 @@ impl HasId for BackgroundConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct EnvironmentsFile {
     pub environments: Vec<EnvironmentConfig>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EnvironmentConfig {
     pub id: String,
     pub gravity: f32,
@@ -2047,15 +3246,29 @@ impl HasId for EnvironmentConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct EnemyTypesFile {
     pub enemy_types: Vec<EnemyTypeConfig>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EnemyBehaviorKind {
+    Walker,
+    Flier,
+    Turret,
+    Charger,
+    Bomber,
+    Boss,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(transform = extendable_row_schema)]
 pub struct EnemyTypeConfig {
     pub id: String,
-    pub behavior: String,
+    #[serde(default)]
+    pub extends: Option<String>,
+    pub behavior: EnemyBehaviorKind,
     pub health: f32,
     pub speed: f32,
     pub contact_damage: f32,
@@ -2081,19 +3294,23 @@ impl HasId for EnemyTypeConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct SpawnersFile {
     pub spawners: Vec<SpawnerConfig>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SpawnerConfig {
     pub id: String,
-    pub mode: String,
+    pub mode: SpawnerMode,
     pub spawn_enemy_ids: Vec<String>,
     pub start_distance: f32,
     pub interval_seconds: f32,
     pub max_alive: u32,
+    #[serde(default = "default_spawner_spawn_ahead_m")]
+    pub spawn_ahead_m: f32,
+    #[serde(default = "default_spawner_spawn_spacing_m")]
+    pub spawn_spacing_m: f32,
 }
 
 impl HasId for SpawnerConfig {
@@ -2102,15 +3319,46 @@ impl HasId for SpawnerConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SpawnerMode {
+    /// One enemy every `spawn_spacing_m` of player travel, no faster than `interval_seconds`.
+    Distance,
+    /// One enemy every `interval_seconds`.
+    Timed,
+    /// The whole `spawn_enemy_ids` roster at once, re-armed when the previous wave is cleared.
+    Wave,
+    /// One enemy every `interval_seconds`, only while the segment boss is alive.
+    BossGate,
+}
+
+fn default_spawner_spawn_ahead_m() -> f32 {
+    41.6
+}
+
+fn default_spawner_spawn_spacing_m() -> f32 {
+    26.0
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct WeaponsFile {
     pub weapons: Vec<WeaponConfig>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectileKind {
+    Bullet,
+    Missile,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(transform = extendable_row_schema)]
 pub struct WeaponConfig {
     pub id: String,
-    pub projectile_type: String,
+    #[serde(default)]
+    pub extends: Option<String>,
+    pub projectile_type: ProjectileKind,
     pub bullet_speed: f32,
     pub fire_rate: f32,
     pub spread_degrees: f32,
@@ -2155,14 +3403,34 @@ impl HasId for WeaponConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct VehiclesFile {
     pub vehicles: Vec<VehicleConfig>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TurretTargetPriority {
+    Nearest,
+    Strongest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(transform = extendable_row_schema)]
 pub struct VehicleConfig {
     pub id: String,
+    #[serde(default)]
+    pub extends: Option<String>,
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub model_id: Option<String>,
+    #[serde(default)]
+    pub parts: Option<VehiclePartsConfig>,
+    #[serde(default)]
+    pub fuel: Option<VehicleFuelConfig>,
+    #[serde(default)]
+    pub nitro: Option<VehicleNitroConfig>,
     pub health: f32,
     pub acceleration: f32,
     pub brake_strength: f32,
@@ -2179,6 +3447,24 @@ pub struct VehicleConfig {
     pub linear_inertia: f32,
     pub rotational_inertia: f32,
     pub gravity_scale: f32,
+    #[serde(default = "default_chassis_length_m")]
+    pub chassis_length_m: f32,
+    #[serde(default = "default_chassis_height_m")]
+    pub chassis_height_m: f32,
+    #[serde(default = "default_chassis_mass_kg")]
+    pub chassis_mass_kg: f32,
+    #[serde(default = "default_chassis_center_of_mass_y_m")]
+    pub chassis_center_of_mass_y_m: f32,
+    #[serde(default = "default_wheel_radius_m")]
+    pub wheel_radius_m: f32,
+    #[serde(default = "default_front_hardpoint_x_m")]
+    pub front_hardpoint_x_m: f32,
+    #[serde(default = "default_front_hardpoint_y_m")]
+    pub front_hardpoint_y_m: f32,
+    #[serde(default = "default_rear_hardpoint_x_m")]
+    pub rear_hardpoint_x_m: f32,
+    #[serde(default = "default_rear_hardpoint_y_m")]
+    pub rear_hardpoint_y_m: f32,
     #[serde(default = "default_suspension_rest_length_m")]
     pub suspension_rest_length_m: f32,
     #[serde(default = "default_suspension_stiffness")]
@@ -2204,7 +3490,7 @@ pub struct VehicleConfig {
     #[serde(default = "default_turret_cone_degrees")]
     pub turret_cone_degrees: f32,
     #[serde(default = "default_turret_target_priority")]
-    pub turret_target_priority: String,
+    pub turret_target_priority: TurretTargetPriority,
     #[serde(default)]
     pub secondary_weapon_id: Option<String>,
     #[serde(default = "default_missile_fire_interval_seconds")]
@@ -2223,6 +3509,42 @@ fn default_air_max_rotation_speed() -> f32 {
     5.5
 }
 
+fn default_chassis_length_m() -> f32 {
+    3.45
+}
+
+fn default_chassis_height_m() -> f32 {
+    1.08
+}
+
+fn default_chassis_mass_kg() -> f32 {
+    6.0
+}
+
+fn default_chassis_center_of_mass_y_m() -> f32 {
+    -0.54
+}
+
+fn default_wheel_radius_m() -> f32 {
+    0.552
+}
+
+fn default_front_hardpoint_x_m() -> f32 {
+    1.2808
+}
+
+fn default_front_hardpoint_y_m() -> f32 {
+    0.0708
+}
+
+fn default_rear_hardpoint_x_m() -> f32 {
+    -1.3008
+}
+
+fn default_rear_hardpoint_y_m() -> f32 {
+    0.1208
+}
+
 fn default_suspension_rest_length_m() -> f32 {
     0.78
 }
@@ -2267,30 +3589,143 @@ fn default_turret_cone_degrees() -> f32 {
     60.0
 }
 
-fn default_turret_target_priority() -> String {
-    "nearest".to_string()
+fn default_turret_target_priority() -> TurretTargetPriority {
+    TurretTargetPriority::Nearest
 }
 
 fn default_missile_fire_interval_seconds() -> f32 {
     2.0
 }
 
+impl VehicleConfig {
+    pub fn display_label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.id)
+    }
+}
+
 impl HasId for VehicleConfig {
     fn id(&self) -> &str {
         &self.id
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VehiclePartsConfig {
+    pub chassis: VehiclePartConfig,
+    #[serde(default)]
+    pub turret: Option<VehiclePartConfig>,
+    pub front_wheel: VehiclePartConfig,
+    pub rear_wheel: VehiclePartConfig,
+}
+
+impl VehiclePartsConfig {
+    pub fn slots(&self) -> impl Iterator<Item = (&'static str, &VehiclePartConfig)> {
+        [
+            ("chassis", Some(&self.chassis)),
+            ("turret", self.turret.as_ref()),
+            ("front_wheel", Some(&self.front_wheel)),
+            ("rear_wheel", Some(&self.rear_wheel)),
+        ]
+        .into_iter()
+        .filter_map(|(slot, part)| part.map(|part| (slot, part)))
+    }
+}
+
+// `offset` is relative to the part's anchor: the chassis origin, the turret
+// pivot or the wheel hub.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VehiclePartConfig {
+    pub model_id: String,
+    #[serde(default)]
+    pub offset: [f32; 3],
+    #[serde(default)]
+    pub rotation_degrees: [f32; 3],
+    #[serde(default = "default_vehicle_part_scale")]
+    pub scale: f32,
+}
+
+fn default_vehicle_part_scale() -> f32 {
+    1.0
+}
+
+// Burn per second is `idle_burn_per_second + throttle_burn_per_second` while
+// accelerating. Once the tank is empty the engine stops driving, and the run ends
+// after the vehicle has stalled for `out_of_fuel_stall_seconds`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VehicleFuelConfig {
+    pub tank_capacity: f32,
+    #[serde(default = "default_fuel_idle_burn_per_second")]
+    pub idle_burn_per_second: f32,
+    #[serde(default = "default_fuel_throttle_burn_per_second")]
+    pub throttle_burn_per_second: f32,
+    #[serde(default = "default_fuel_out_of_fuel_stall_seconds")]
+    pub out_of_fuel_stall_seconds: f32,
+}
+
+fn default_fuel_idle_burn_per_second() -> f32 {
+    0.5
+}
+
+fn default_fuel_throttle_burn_per_second() -> f32 {
+    2.5
+}
+
+fn default_fuel_out_of_fuel_stall_seconds() -> f32 {
+    2.0
+}
+
+// The meter fills by `stunt_charge` per stunt and `kill_charge` per kill. Holding
+// accelerate and brake together with a full meter empties it into a boost that
+// pushes with `thrust_acceleration` for `boost_duration_s`, allowing up to
+// `max_speed_bonus_mps` over `max_forward_speed`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VehicleNitroConfig {
+    pub meter_capacity: f32,
+    #[serde(default = "default_nitro_stunt_charge")]
+    pub stunt_charge: f32,
+    #[serde(default = "default_nitro_kill_charge")]
+    pub kill_charge: f32,
+    #[serde(default = "default_nitro_boost_duration_s")]
+    pub boost_duration_s: f32,
+    #[serde(default = "default_nitro_thrust_acceleration")]
+    pub thrust_acceleration: f32,
+    #[serde(default = "default_nitro_max_speed_bonus_mps")]
+    pub max_speed_bonus_mps: f32,
+}
+
+fn default_nitro_stunt_charge() -> f32 {
+    25.0
+}
+
+fn default_nitro_kill_charge() -> f32 {
+    10.0
+}
+
+fn default_nitro_boost_duration_s() -> f32 {
+    1.5
+}
+
+fn default_nitro_thrust_acceleration() -> f32 {
+    16.0
+}
+
+fn default_nitro_max_speed_bonus_mps() -> f32 {
+    8.0
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct UpgradesFile {
     pub upgrades: Vec<UpgradeConfig>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct UpgradeConfig {
     pub id: String,
     pub target: String,
+    #[serde(default)]
     pub add: f32,
+    #[serde(default = "default_upgrade_multiply")]
+    pub multiply: f32,
     pub max_stacks: u32,
     pub rarity: String,
     pub label: String,
@@ -2302,7 +3737,31 @@ impl HasId for UpgradeConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl UpgradeConfig {
+    pub fn stat_modifiers(&self) -> Result<Vec<StatModifier>, String> {
+        let path = StatPath::parse(&self.target)?;
+        let mut modifiers = Vec::with_capacity(2);
+        if self.add != 0.0 {
+            modifiers.push(StatModifier {
+                path: path.clone(),
+                op: StatModifierOp::Add(self.add),
+            });
+        }
+        if self.multiply != 1.0 {
+            modifiers.push(StatModifier {
+                path,
+                op: StatModifierOp::Multiply(self.multiply),
+            });
+        }
+        Ok(modifiers)
+    }
+}
+
+fn default_upgrade_multiply() -> f32 {
+    1.0
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct CommentatorFile {
     pub commentary: CommentaryConfig,
     pub thresholds: CommentaryThresholds,
@@ -2311,7 +3770,7 @@ pub struct CommentatorFile {
     pub fallback: FallbackLines,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct CommentaryConfig {
     pub min_seconds_between_lines: f32,
     #[serde(default = "default_max_events_per_batch")]
@@ -2328,7 +3787,7 @@ pub struct CommentaryConfig {
     pub narration_volume: f32,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct CommentatorProfile {
     pub id: String,
     #[serde(default = "default_commentator_name")]
@@ -2349,7 +3808,7 @@ pub struct CommentatorProfile {
     pub subtitle_color: [f32; 3],
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CommentaryThresholds {
     pub airtime_big_jump: f32,
     #[serde(default)]
@@ -2363,7 +3822,7 @@ pub struct CommentaryThresholds {
     pub near_death_health_fraction: f32,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct FallbackLines {
     pub lines: Vec<String>,
 }
@@ -2466,7 +3925,7 @@ fn default_commentator_profiles() -> Vec<CommentatorProfile> {
     ]
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, JsonSchema, Default)]
 pub struct AssetsFile {
     #[serde(default)]
     pub sprites: Vec<SpriteAssetConfig>,
@@ -2478,7 +3937,7 @@ pub struct AssetsFile {
     pub audio: Vec<AudioAssetConfig>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct SpriteAssetConfig {
     pub id: String,
     pub path: String,
@@ -2490,11 +3949,12 @@ impl HasId for SpriteAssetConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ModelAssetConfig {
     pub id: String,
     pub scene_path: String,
     pub root_node: String,
+    #[serde(default)]
     pub wheel_nodes: Vec<String>,
     pub turret_node: Option<String>,
 }
@@ -2505,7 +3965,7 @@ impl HasId for ModelAssetConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct SplatAssetConfig {
     pub id: String,
     pub path: String,
@@ -2517,7 +3977,7 @@ impl HasId for SplatAssetConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct AudioAssetConfig {
     pub id: String,
     pub path: String,
@@ -2557,6 +4017,7 @@ mod tests {
                 scoring: ScoringConfig::default(),
                 pickups: PickupConfig::default(),
                 run_upgrades: RunUpgradeConfig::default(),
+                difficulty: DifficultyConfig::default(),
                 sfx: SfxConfig::default(),
                 web: WebConfig::default(),
             },
@@ -2567,12 +4028,13 @@ mod tests {
                     length: 100.0,
                     environment: "normal".to_string(),
                     spawn_set: Some("starter_wave".to_string()),
+                    difficulty_multiplier: 1.0,
                 }],
             },
             backgrounds: BackgroundsFile {
                 backgrounds: vec![BackgroundConfig {
                     id: "segment_a".to_string(),
-                    placeholder: "box".to_string(),
+                    placeholder: BackgroundPlaceholder::Box,
                     color: [0.1, 0.1, 0.1],
                     parallax: 0.4,
                     splat_asset_id: None,
@@ -2605,7 +4067,8 @@ mod tests {
             enemy_types: EnemyTypesFile {
                 enemy_types: vec![EnemyTypeConfig {
                     id: "grunt".to_string(),
-                    behavior: "walker".to_string(),
+                    extends: None,
+                    behavior: EnemyBehaviorKind::Walker,
                     health: 10.0,
                     speed: 1.0,
                     contact_damage: 2.0,
@@ -2620,18 +4083,21 @@ mod tests {
             spawners: SpawnersFile {
                 spawners: vec![SpawnerConfig {
                     id: "starter_wave".to_string(),
-                    mode: "distance".to_string(),
+                    mode: SpawnerMode::Distance,
                     spawn_enemy_ids: vec!["grunt".to_string()],
                     start_distance: 5.0,
                     interval_seconds: 2.0,
                     max_alive: 4,
+                    spawn_ahead_m: 41.6,
+                    spawn_spacing_m: 26.0,
                 }],
             },
             weapons: WeaponsFile {
                 weapons: vec![
                     WeaponConfig {
                         id: "enemy_weapon".to_string(),
-                        projectile_type: "bullet".to_string(),
+                        extends: None,
+                        projectile_type: ProjectileKind::Bullet,
                         bullet_speed: 10.0,
                         fire_rate: 1.0,
                         spread_degrees: 0.0,
@@ -2647,7 +4113,8 @@ mod tests {
                     },
                     WeaponConfig {
                         id: "player_weapon".to_string(),
-                        projectile_type: "bullet".to_string(),
+                        extends: None,
+                        projectile_type: ProjectileKind::Bullet,
                         bullet_speed: 12.0,
                         fire_rate: 2.0,
                         spread_degrees: 0.0,
@@ -2666,6 +4133,12 @@ mod tests {
             vehicles: VehiclesFile {
                 vehicles: vec![VehicleConfig {
                     id: "starter_car".to_string(),
+                    extends: None,
+                    label: None,
+                    model_id: None,
+                    parts: None,
+                    fuel: None,
+                    nitro: None,
                     health: 100.0,
                     acceleration: 10.0,
                     brake_strength: 5.0,
@@ -2681,6 +4154,15 @@ mod tests {
                     linear_inertia: 1.0,
                     rotational_inertia: 1.0,
                     gravity_scale: 1.0,
+                    chassis_length_m: 3.45,
+                    chassis_height_m: 1.08,
+                    chassis_mass_kg: 6.0,
+                    chassis_center_of_mass_y_m: -0.54,
+                    wheel_radius_m: 0.552,
+                    front_hardpoint_x_m: 1.2808,
+                    front_hardpoint_y_m: 0.0708,
+                    rear_hardpoint_x_m: -1.3008,
+                    rear_hardpoint_y_m: 0.1208,
                     suspension_rest_length_m: 0.78,
                     suspension_stiffness: 38.0,
                     suspension_damping: 8.0,
@@ -2693,7 +4175,7 @@ mod tests {
                     rear_drive_traction_assist_min_factor: 0.55,
                     turret_range_m: 28.0,
                     turret_cone_degrees: 60.0,
-                    turret_target_priority: "nearest".to_string(),
+                    turret_target_priority: TurretTargetPriority::Nearest,
                     secondary_weapon_id: None,
                     missile_fire_interval_seconds: 2.0,
                     camera_look_ahead_factor: 1.1,
@@ -2707,6 +4189,7 @@ mod tests {
                     id: "u1".to_string(),
                     target: "weapon.player_weapon.damage".to_string(),
                     add: 1.0,
+                    multiply: 1.0,
                     max_stacks: 2,
                     rarity: "common".to_string(),
                     label: "Damage+".to_string(),
@@ -2778,7 +4261,7 @@ mod tests {
                 "segment_a".to_string(),
                 BackgroundConfig {
                     id: "segment_a".to_string(),
-                    placeholder: "box".to_string(),
+                    placeholder: BackgroundPlaceholder::Box,
                     color: [0.1, 0.1, 0.1],
                     parallax: 0.4,
                     splat_asset_id: None,
@@ -2813,7 +4296,8 @@ mod tests {
                 "grunt".to_string(),
                 EnemyTypeConfig {
                     id: "grunt".to_string(),
-                    behavior: "walker".to_string(),
+                    extends: None,
+                    behavior: EnemyBehaviorKind::Walker,
                     health: 10.0,
                     speed: 1.0,
                     contact_damage: 2.0,
@@ -2829,11 +4313,13 @@ mod tests {
                 "starter_wave".to_string(),
                 SpawnerConfig {
                     id: "starter_wave".to_string(),
-                    mode: "distance".to_string(),
+                    mode: SpawnerMode::Distance,
                     spawn_enemy_ids: vec!["grunt".to_string()],
                     start_distance: 5.0,
                     interval_seconds: 2.0,
                     max_alive: 4,
+                    spawn_ahead_m: 41.6,
+                    spawn_spacing_m: 26.0,
                 },
             )]),
             weapons_by_id: HashMap::from([
@@ -2841,7 +4327,8 @@ mod tests {
                     "enemy_weapon".to_string(),
                     WeaponConfig {
                         id: "enemy_weapon".to_string(),
-                        projectile_type: "bullet".to_string(),
+                        extends: None,
+                        projectile_type: ProjectileKind::Bullet,
                         bullet_speed: 10.0,
                         fire_rate: 1.0,
                         spread_degrees: 0.0,
@@ -2860,7 +4347,8 @@ mod tests {
                     "player_weapon".to_string(),
                     WeaponConfig {
                         id: "player_weapon".to_string(),
-                        projectile_type: "bullet".to_string(),
+                        extends: None,
+                        projectile_type: ProjectileKind::Bullet,
                         bullet_speed: 12.0,
                         fire_rate: 2.0,
                         spread_degrees: 0.0,
@@ -2880,6 +4368,12 @@ mod tests {
                 "starter_car".to_string(),
                 VehicleConfig {
                     id: "starter_car".to_string(),
+                    extends: None,
+                    label: None,
+                    model_id: None,
+                    parts: None,
+                    fuel: None,
+                    nitro: None,
                     health: 100.0,
                     acceleration: 10.0,
                     brake_strength: 5.0,
@@ -2895,6 +4389,15 @@ mod tests {
                     linear_inertia: 1.0,
                     rotational_inertia: 1.0,
                     gravity_scale: 1.0,
+                    chassis_length_m: 3.45,
+                    chassis_height_m: 1.08,
+                    chassis_mass_kg: 6.0,
+                    chassis_center_of_mass_y_m: -0.54,
+                    wheel_radius_m: 0.552,
+                    front_hardpoint_x_m: 1.2808,
+                    front_hardpoint_y_m: 0.0708,
+                    rear_hardpoint_x_m: -1.3008,
+                    rear_hardpoint_y_m: 0.1208,
                     suspension_rest_length_m: 0.78,
                     suspension_stiffness: 38.0,
                     suspension_damping: 8.0,
@@ -2907,7 +4410,7 @@ mod tests {
                     rear_drive_traction_assist_min_factor: 0.55,
                     turret_range_m: 28.0,
                     turret_cone_degrees: 60.0,
-                    turret_target_priority: "nearest".to_string(),
+                    turret_target_priority: TurretTargetPriority::Nearest,
                     secondary_weapon_id: None,
                     missile_fire_interval_seconds: 2.0,
                     camera_look_ahead_factor: 1.1,
@@ -2922,6 +4425,7 @@ mod tests {
                     id: "u1".to_string(),
                     target: "weapon.player_weapon.damage".to_string(),
                     add: 1.0,
+                    multiply: 1.0,
                     max_stacks: 2,
                     rarity: "common".to_string(),
                     label: "Damage+".to_string(),
@@ -2931,6 +4435,7 @@ mod tests {
             model_assets_by_id: HashMap::new(),
             splat_assets_by_id: HashMap::new(),
             audio_assets_by_id: HashMap::new(),
+            profiles: Vec::new(),
         };
 
         let error = config
@@ -2941,4 +4446,535 @@ mod tests {
         assert!(message.contains("starting_environment"));
         assert!(message.contains("missing_env"));
     }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn layered_report_locates_keys_in_the_profile_that_sets_them() {
+        let dir = std::env::temp_dir().join(format!("mr_autoauto_layers_{}", std::process::id()));
+        let profile_dir = dir.join("profiles").join("hard");
+        fs::create_dir_all(&profile_dir).expect("temp profile dir should be created");
+        fs::write(
+            dir.join("weapons.toml"),
+            "[[weapons]]\nid = \"a\"\ndamage = 1.0\n\n[[weapons]]\nid = \"b\"\ndamage = 2.0\n",
+        )
+        .expect("base layer should write");
+        fs::write(
+            profile_dir.join("weapons.toml"),
+            "[[weapons]]\nid = \"c\"\nfire_rate = -1.0\n\n[[weapons]]\nid = \"b\"\ndamage = -2.0\n",
+        )
+        .expect("profile layer should write");
+
+        let mut report = ConfigReport::default();
+        report.push("weapons.toml", "weapons[0].damage", Some(0), "must be > 0");
+        report.push("weapons.toml", "weapons[1].damage", Some(1), "must be > 0");
+        report.push(
+            "weapons.toml",
+            "weapons[2].fire_rate",
+            Some(2),
+            "must be > 0",
+        );
+        locate_layered_report(&mut report, &dir, &[profile_dir]);
+        let _ = fs::remove_dir_all(&dir);
+
+        let located: Vec<(&str, &str, Option<usize>)> = report
+            .issues
+            .iter()
+            .map(|issue| (issue.file.as_str(), issue.key_path.as_str(), issue.line))
+            .collect();
+        assert_eq!(
+            located,
+            vec![
+                ("weapons.toml", "weapons[0].damage", Some(3)),
+                ("profiles/hard/weapons.toml", "weapons[1].damage", Some(7)),
+                (
+                    "profiles/hard/weapons.toml",
+                    "weapons[0].fire_rate",
+                    Some(3)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn profile_layer_merges_tables_by_key_and_rows_by_id() {
+        let mut base: toml::Value = toml::from_str(
+            "[difficulty]\nhealth_weight = 1.0\ndamage_weight = 0.5\n\n[[enemy_types]]\nid = \"grunt\"\nhealth = 10.0\nspeed = 4.0\n",
+        )
+        .expect("base TOML should parse");
+        let layer: toml::Value = toml::from_str(
+            "[difficulty]\nhealth_weight = 2.0\n\n[[enemy_types]]\nid = \"grunt\"\nhealth = 25.0\n\n[[enemy_types]]\nid = \"elite\"\nhealth = 80.0\n",
+        )
+        .expect("layer TOML should parse");
+
+        profiles::merge_toml_layer(&mut base, layer);
+
+        assert_eq!(base["difficulty"]["health_weight"].as_float(), Some(2.0));
+        assert_eq!(base["difficulty"]["damage_weight"].as_float(), Some(0.5));
+        let enemy_types = base["enemy_types"]
+            .as_array()
+            .expect("rows should stay an array");
+        assert_eq!(enemy_types.len(), 2);
+        assert_eq!(enemy_types[0]["health"].as_float(), Some(25.0));
+        assert_eq!(enemy_types[0]["speed"].as_float(), Some(4.0));
+        assert_eq!(enemy_types[1]["id"].as_str(), Some("elite"));
+    }
+
+    #[test]
+    fn row_inheritance_fills_parent_fields_and_rejects_cycles() {
+        let mut weapons: toml::Value = toml::from_str(
+            "[[weapons]]\nid = \"base\"\ndamage = 4.0\nfire_rate = 1.0\n\n[[weapons]]\nid = \"heavy\"\nextends = \"base\"\ndamage = 9.0\n\n[[weapons]]\nid = \"loop_a\"\nextends = \"loop_b\"\n\n[[weapons]]\nid = \"loop_b\"\nextends = \"loop_a\"\n",
+        )
+        .expect("weapons TOML should parse");
+        let mut report = ConfigReport::default();
+
+        assert!(inheritance::resolve_row_inheritance(
+            &mut weapons,
+            "weapons.toml",
+            "weapons",
+            &mut report
+        ));
+
+        let heavy = &weapons["weapons"][1];
+        assert_eq!(heavy["damage"].as_float(), Some(9.0));
+        assert_eq!(heavy["fire_rate"].as_float(), Some(1.0));
+        assert_eq!(report.issues.len(), 2);
+        assert!(report
+            .issues
+            .iter()
+            .all(|issue| issue.message.starts_with("forms a cycle")));
+    }
+
+    #[test]
+    fn validation_report_locates_issues_in_toml_source() {
+        let mut report = ConfigReport::default();
+        report.push(
+            "vehicles.toml",
+            "vehicles[1].turret_range_m",
+            Some(1),
+            "must be > 0",
+        );
+        report.push(
+            "commentator.toml",
+            "",
+            None,
+            "must define at least two `[[commentators]]` profiles",
+        );
+        report.locate(|file| {
+            (file == "vehicles.toml").then(|| {
+                "[[vehicles]]\nid = \"a\"\n\n[[vehicles]]\nid = \"b\"\n  turret_range_m = -1.0\n"
+                    .to_string()
+            })
+        });
+
+        let vehicle_issue = &report.issues[0];
+        assert_eq!(vehicle_issue.file, "vehicles.toml");
+        assert_eq!(vehicle_issue.key_path, "vehicles[1].turret_range_m");
+        assert_eq!(vehicle_issue.index, Some(1));
+        assert_eq!(vehicle_issue.line, Some(6));
+        assert_eq!(vehicle_issue.column, Some(3));
+        assert_eq!(vehicle_issue.message, "must be > 0");
+
+        let commentator_issue = &report.issues[1];
+        assert_eq!(commentator_issue.file, "commentator.toml");
+        assert_eq!(commentator_issue.line, None);
+        assert!(ConfigError::Invalid(report)
+            .to_string()
+            .starts_with("2 config validation error(s):"));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn load_reports_errors_from_every_broken_file_with_lines() {
+        let dir = std::env::temp_dir().join(format!("mr_autoauto_report_{}", std::process::id()));
+        fs::create_dir_all(&dir).expect("temp config dir should be created");
+        for entry in fs::read_dir("config").expect("repo config dir should list") {
+            let path = entry.expect("config entry should read").path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "toml")
+            {
+                fs::copy(&path, dir.join(path.file_name().unwrap()))
+                    .expect("config file should copy");
+            }
+        }
+        // Returns the 1-based line the broken text lands on in the copied file.
+        let break_file = |file: &'static str, from: &str, to: &str| {
+            let path = dir.join(file);
+            let raw = fs::read_to_string(&path).expect("copied config should read");
+            let offset = raw
+                .find(from)
+                .unwrap_or_else(|| panic!("{file} should contain `{from}`"));
+            fs::write(&path, raw.replacen(from, to, 1)).expect("broken config should write");
+            (file, raw[..offset].matches('\n').count() + 1)
+        };
+        let broken_lines = [
+            break_file("game.toml", "[terrain]", "[terrain"),
+            break_file(
+                "spawners.toml",
+                "mode = \"distance\"",
+                "mode = \"distnace\"",
+            ),
+            break_file("backgrounds.toml", "parallax = 0.5", "parallax = \"far\""),
+        ];
+
+        let result = GameConfig::load_from_dir(&dir);
+        let _ = fs::remove_dir_all(&dir);
+        let Err(ConfigError::Invalid(report)) = result else {
+            panic!("broken config should fail with a report");
+        };
+
+        assert_eq!(report.issues.len(), 3, "{report}");
+        for (file, line) in broken_lines {
+            let issue = report
+                .issues
+                .iter()
+                .find(|issue| issue.file.ends_with(file))
+                .unwrap_or_else(|| panic!("{file} should be reported: {report}"));
+            assert_eq!(issue.line, Some(line), "{issue}");
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn config_diff_reports_changed_rows_by_id() {
+        use super::watcher::diff_config_sources;
+
+        let old = "[sfx]\nmaster_volume = 1.0\n\n[[vehicles]]\nid = \"a\"\nhealth = 10.0\n\n[[vehicles]]\nid = \"b\"\nhealth = 20.0\n";
+        let new = "# tuned\n[sfx]\nmaster_volume = 1.0\n\n[[vehicles]]\nid = \"a\"\nhealth = 12.0\n\n[[vehicles]]\nid = \"c\"\nhealth = 20.0\n";
+
+        let diff = diff_config_sources("vehicles.toml", old, new).expect("rows changed");
+        assert_eq!(diff.changed, vec!["vehicles.a".to_string()]);
+        assert_eq!(diff.added, vec!["vehicles.c".to_string()]);
+        assert_eq!(diff.removed, vec!["vehicles.b".to_string()]);
+        assert_eq!(
+            diff.to_string(),
+            "vehicles.toml changed [vehicles.a] added [vehicles.c] removed [vehicles.b]"
+        );
+        assert_eq!(diff_config_sources("vehicles.toml", old, old), None);
+    }
+
+    #[test]
+    fn editable_table_applies_rows_live_and_rejects_bad_values() {
+        let mut config =
+            GameConfig::load_from_dir(Path::new("config")).expect("repo config should load");
+        let table = EditableConfigTable::Weapons;
+        let mut row = table
+            .read_row(&config, Some("enemy_boss_spread"))
+            .expect("weapon row should serialize");
+        assert_eq!(row["extends"].as_str(), Some("enemy_pea_shooter"));
+
+        row.insert("damage".to_string(), toml::Value::Float(42.0));
+        table
+            .apply_row(&mut config, Some("enemy_boss_spread"), row.clone())
+            .expect("edited row should apply");
+        assert_eq!(config.weapons_by_id["enemy_boss_spread"].damage, 42.0);
+
+        row.insert("damage".to_string(), toml::Value::from("lots"));
+        assert!(table
+            .apply_row(&mut config, Some("enemy_boss_spread"), row)
+            .is_err());
+        assert_eq!(config.weapons_by_id["enemy_boss_spread"].damage, 42.0);
+
+        let mut parent = table
+            .read_row(&config, Some("enemy_pea_shooter"))
+            .expect("parent row should serialize");
+        parent.insert("fire_rate".to_string(), toml::Value::Float(3.0));
+        parent.insert(
+            "burst_interval_seconds".to_string(),
+            toml::Value::Float(0.25),
+        );
+        table
+            .apply_row(&mut config, Some("enemy_pea_shooter"), parent)
+            .expect("edited parent should apply");
+        let child = &config.weapons_by_id["enemy_boss_spread"];
+        assert_eq!(child.burst_interval_seconds, 0.25);
+        assert_eq!(child.fire_rate, 0.7);
+        assert_eq!(child.damage, 42.0);
+    }
+
+    #[test]
+    fn lint_report_flags_unused_rows_and_missing_assets() {
+        let mut config =
+            GameConfig::load_from_dir(Path::new("config")).expect("repo config should load");
+        let mut unused_enemy = config.enemy_types.enemy_types[0].clone();
+        unused_enemy.id = "unused_grunt".to_string();
+        config.enemy_types.enemy_types.push(unused_enemy);
+        let mut unused_weapon = config.weapons.weapons[0].clone();
+        unused_weapon.id = "unused_gun".to_string();
+        unused_weapon.extends = None;
+        config.weapons.weapons.push(unused_weapon);
+        let mut unused_spawner = config.spawners.spawners[0].clone();
+        unused_spawner.id = "unused_wave".to_string();
+        config.spawners.spawners.push(unused_spawner);
+        config.assets.sprites[0].path = "sprites/missing_for_lint_test.png".to_string();
+
+        let report = config.lint_report(Path::new("config"), Path::new("assets"));
+        let issue_for = |file: &str, key_path: String| {
+            report
+                .issues
+                .iter()
+                .find(|issue| issue.file == file && issue.key_path == key_path)
+                .unwrap_or_else(|| panic!("expected a lint for {file}::{key_path}: {report}"))
+        };
+
+        let enemy_index = config.enemy_types.enemy_types.len() - 1;
+        let enemy = issue_for("enemy_types.toml", format!("enemy_types[{enemy_index}].id"));
+        assert!(enemy.message.contains("never spawned"), "{enemy}");
+        let weapon_index = config.weapons.weapons.len() - 1;
+        let weapon = issue_for("weapons.toml", format!("weapons[{weapon_index}].id"));
+        assert!(weapon.message.contains("not used"), "{weapon}");
+        let asset = issue_for("assets.toml", "sprites[0].path".to_string());
+        assert!(asset.message.contains("does not exist"), "{asset}");
+        assert_eq!(asset.line, Some(3), "{asset}");
+        let boss_index = config
+            .enemy_types
+            .enemy_types
+            .iter()
+            .position(|enemy| enemy.id == SEGMENT_BOSS_ENEMY_ID)
+            .expect("repo config should define the segment boss");
+        assert!(!report
+            .issues
+            .iter()
+            .any(|issue| issue.key_path == format!("enemy_types[{boss_index}].id")));
+        let spawner_index = config.spawners.spawners.len() - 1;
+        let spawner = issue_for("spawners.toml", format!("spawners[{spawner_index}].id"));
+        assert!(spawner.message.contains("not the spawn_set"), "{spawner}");
+
+        // A segment without `spawn_set` runs every spawner.
+        config.segments.segment_sequence[0].spawn_set = None;
+        let report = config.lint_report(Path::new("config"), Path::new("assets"));
+        assert!(
+            !report
+                .issues
+                .iter()
+                .any(|issue| issue.file == "spawners.toml"),
+            "{report}"
+        );
+    }
+
+    #[test]
+    fn stacked_stat_modifiers_clamp_to_field_bounds() {
+        let config =
+            GameConfig::load_from_dir(Path::new("config")).expect("repo config should load");
+        let mut stats = RunStats::from_config(&config);
+        let vehicle_id = stats.player_vehicle_id.clone();
+        let cone = StatModifier {
+            path: StatPath::new(
+                StatTargetKind::Vehicle,
+                Some(&vehicle_id),
+                "turret_cone_degrees",
+            ),
+            op: StatModifierOp::Add(30.0),
+        };
+        for _ in 0..30 {
+            stats
+                .apply_stat_modifier(&cone)
+                .expect("cone modifier applies");
+        }
+        assert_eq!(stats.player_vehicle().unwrap().turret_cone_degrees, 180.0);
+
+        let acceleration = StatModifier {
+            path: StatPath::new(StatTargetKind::Vehicle, Some(&vehicle_id), "acceleration"),
+            op: StatModifierOp::Multiply(0.0),
+        };
+        assert_eq!(stats.apply_stat_modifier(&acceleration), Ok(0.01));
+    }
+
+    #[test]
+    fn default_vehicle_geometry_matches_the_old_player_constants() {
+        let geometry = [
+            ("chassis_length_m", 3.45),
+            ("chassis_height_m", 1.08),
+            ("chassis_mass_kg", 6.0),
+            ("chassis_center_of_mass_y_m", -0.54),
+            ("wheel_radius_m", 0.552),
+            ("front_hardpoint_x_m", 1.2808),
+            ("front_hardpoint_y_m", 0.0708),
+            ("rear_hardpoint_x_m", -1.3008),
+            ("rear_hardpoint_y_m", 0.1208),
+        ];
+        let raw = fs::read_to_string("config/vehicles.toml").expect("vehicles.toml should read");
+        let root: toml::Value = toml::from_str(&raw).expect("vehicles.toml should parse");
+        let mut row = root["vehicles"][0]
+            .as_table()
+            .expect("first vehicle should be a table")
+            .clone();
+        for (key, _) in geometry {
+            row.remove(key);
+        }
+        let vehicle: VehicleConfig = toml::Value::Table(row)
+            .try_into()
+            .expect("vehicle row without geometry should deserialize");
+
+        let defaults = [
+            vehicle.chassis_length_m,
+            vehicle.chassis_height_m,
+            vehicle.chassis_mass_kg,
+            vehicle.chassis_center_of_mass_y_m,
+            vehicle.wheel_radius_m,
+            vehicle.front_hardpoint_x_m,
+            vehicle.front_hardpoint_y_m,
+            vehicle.rear_hardpoint_x_m,
+            vehicle.rear_hardpoint_y_m,
+        ];
+        for ((key, old_value), value) in geometry.into_iter().zip(defaults) {
+            assert_eq!(value, old_value, "{key}");
+        }
+    }
+
+    #[test]
+    fn vehicle_nitro_inherits_through_extends() {
+        let config =
+            GameConfig::load_from_dir(Path::new("config")).expect("repo config should load");
+        let buggy_nitro = config.vehicles_by_id["light_buggy"]
+            .nitro
+            .as_ref()
+            .expect("light_buggy should inherit a nitro table");
+        assert_eq!(buggy_nitro.meter_capacity, 80.0);
+        assert_eq!(
+            buggy_nitro.stunt_charge,
+            config.vehicles_by_id["starter_car"]
+                .nitro
+                .as_ref()
+                .expect("starter_car should have a nitro table")
+                .stunt_charge
+        );
+    }
+
+    #[test]
+    fn invalid_vehicle_sections_fail_validation() {
+        struct Case {
+            label: &'static str,
+            edit: fn(&mut GameConfig),
+            expected: &'static [&'static str],
+            unexpected: &'static [&'static str],
+        }
+        fn part(model_id: &str, scale: f32) -> VehiclePartConfig {
+            VehiclePartConfig {
+                model_id: model_id.to_string(),
+                offset: [0.0; 3],
+                rotation_degrees: [0.0; 3],
+                scale,
+            }
+        }
+
+        let cases = [
+            Case {
+                label: "parts",
+                edit: |config| {
+                    // A turret-only model has no wheels and must not be flagged for it.
+                    config.assets.models.push(ModelAssetConfig {
+                        id: "buggy_turret".to_string(),
+                        scene_path: "models/buggy_turret.glb#Scene0".to_string(),
+                        root_node: "Turret".to_string(),
+                        wheel_nodes: Vec::new(),
+                        turret_node: None,
+                    });
+                    config.model_assets_by_id = config
+                        .assets
+                        .models
+                        .iter()
+                        .map(|model| (model.id.clone(), model.clone()))
+                        .collect();
+                    config.vehicles.vehicles[0].parts = Some(VehiclePartsConfig {
+                        chassis: part("vehicle_starter_car", 1.0),
+                        turret: Some(part("buggy_turret", 1.0)),
+                        front_wheel: part("missing_wheel", 1.0),
+                        rear_wheel: part("vehicle_starter_car", 0.0),
+                    });
+                },
+                expected: &[
+                    "vehicles[0].parts.front_wheel.model_id references unknown model id `missing_wheel`",
+                    "vehicles[0].parts.rear_wheel.scale must be > 0",
+                ],
+                unexpected: &["parts.chassis", "wheel_nodes"],
+            },
+            Case {
+                label: "fuel",
+                edit: |config| {
+                    config.vehicles.vehicles[0].fuel = Some(VehicleFuelConfig {
+                        tank_capacity: 0.0,
+                        idle_burn_per_second: -1.0,
+                        throttle_burn_per_second: 2.0,
+                        out_of_fuel_stall_seconds: 2.0,
+                    });
+                },
+                expected: &[
+                    "vehicles[0].fuel.tank_capacity must be > 0",
+                    "vehicles[0].fuel.idle_burn_per_second must be >= 0",
+                ],
+                unexpected: &["throttle_burn_per_second"],
+            },
+            Case {
+                label: "nitro",
+                edit: |config| {
+                    let nitro = config.vehicles.vehicles[0]
+                        .nitro
+                        .as_mut()
+                        .expect("starter_car should have a nitro table");
+                    nitro.boost_duration_s = 0.0;
+                    nitro.thrust_acceleration = -4.0;
+                },
+                expected: &[
+                    "vehicles[0].nitro.boost_duration_s must be > 0",
+                    "vehicles[0].nitro.thrust_acceleration must be >= 0",
+                ],
+                unexpected: &["nitro.meter_capacity"],
+            },
+        ];
+
+        let repo_config =
+            GameConfig::load_from_dir(Path::new("config")).expect("repo config should load");
+        for case in cases {
+            let mut config = repo_config.clone();
+            (case.edit)(&mut config);
+            let Err(error) = config.validate_references() else {
+                panic!("bad {} should fail validation", case.label);
+            };
+            let message = error.to_string();
+            for expected in case.expected {
+                assert!(message.contains(expected), "{}: {message}", case.label);
+            }
+            for unexpected in case.unexpected {
+                assert!(!message.contains(unexpected), "{}: {message}", case.label);
+            }
+        }
+    }
+
+    #[test]
+    fn exported_config_schemas_are_up_to_date() {
+        for (file, schema) in config_file_schemas() {
+            let path = Path::new("config/schema")
+                .join(format!("{}.schema.json", file.trim_end_matches(".toml")));
+            let committed: Option<serde_json::Value> = fs::read_to_string(&path)
+                .ok()
+                .and_then(|raw| serde_json::from_str(&raw).ok());
+            assert!(
+                committed.as_ref() == Some(schema.as_value()),
+                "`{}` is stale; run `cargo run --bin export_config_schema`",
+                path.display()
+            );
+        }
+    }
+
+    #[test]
+    fn misspelled_enum_values_fail_at_load_listing_valid_options() {
+        let error = toml::from_str::<EnemyTypesFile>(
+            "[[enemy_types]]\nid = \"grunt\"\nbehavior = \"chargr\"\nhealth = 10.0\nspeed = 4.0\ncontact_damage = 5.0\nweapon_id = \"gun\"\nhitbox_radius = 1.0\n",
+        )
+        .expect_err("unknown behavior should be rejected");
+        let message = error.to_string();
+
+        assert!(message.contains("unknown variant `chargr`"), "{message}");
+        assert!(message.contains("`charger`"), "{message}");
+        assert!(message.contains("line 3"), "{message}");
+
+        let error = toml::from_str::<SpawnersFile>(
+            "[[spawners]]\nid = \"early\"\nmode = \"distnace\"\nspawn_enemy_ids = [\"grunt\"]\nstart_distance = 0.0\ninterval_seconds = 1.0\nmax_alive = 2\n",
+        )
+        .expect_err("unknown spawner mode should be rejected");
+        let message = error.to_string();
+        assert!(message.contains("unknown variant `distnace`"), "{message}");
+        assert!(message.contains("`boss_gate`"), "{message}");
+    }
 }
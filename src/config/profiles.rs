@@ -0,0 +1,274 @@
+use super::inheritance::{inheritable_rows_key, resolve_row_inheritance};
+use super::*;
+use crate::cli::CliArgs;
+use toml::Value;
+
+pub const CONFIG_PROFILE_ENV: &str = "MR_AUTOAUTO_PROFILE";
+const CONFIG_PROFILES_DIR: &str = "profiles";
+
+pub fn requested_config_profiles(cli: Option<&CliArgs>) -> Vec<String> {
+    if let Some(cli) = cli.filter(|cli| !cli.profiles.is_empty()) {
+        return cli.profiles.clone();
+    }
+    std::env::var(CONFIG_PROFILE_ENV)
+        .map(|raw| {
+            raw.split(',')
+                .map(str::trim)
+                .filter(|profile| !profile.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub(super) fn resolve_profile_dir(
+    config_dir: &Path,
+    profile: &str,
+) -> Result<PathBuf, ConfigError> {
+    let as_path = Path::new(profile);
+    let dir = if as_path.components().count() > 1 && as_path.is_dir() {
+        as_path.to_path_buf()
+    } else {
+        config_dir.join(CONFIG_PROFILES_DIR).join(profile)
+    };
+    if !dir.is_dir() {
+        return Err(ConfigError::Io {
+            path: dir,
+            source: std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("config profile `{profile}` has no override directory"),
+            ),
+        });
+    }
+    Ok(dir)
+}
+
+// Tuning panels and the config editor save into the base files; keys an active
+// profile layer also sets keep the profile's value on the next load.
+pub fn profile_override_warning(
+    config_dir: &Path,
+    profiles: &[String],
+    file: &str,
+    table_key: &str,
+    id: Option<&str>,
+    keys: &[&str],
+) -> Option<String> {
+    let mut overrides = Vec::new();
+    for profile in profiles {
+        let Ok(dir) = resolve_profile_dir(config_dir, profile) else {
+            continue;
+        };
+        let Some(layer) = fs::read_to_string(dir.join(file))
+            .ok()
+            .and_then(|raw| toml::from_str::<Value>(&raw).ok())
+        else {
+            continue;
+        };
+        let scope = match id {
+            Some(id) => layer
+                .get(table_key)
+                .and_then(Value::as_array)
+                .and_then(|rows| rows.iter().find(|row| row_id(row) == Some(id))),
+            None => layer.get(table_key),
+        };
+        let Some(scope) = scope.and_then(Value::as_table) else {
+            continue;
+        };
+        let shadowed: Vec<String> = keys
+            .iter()
+            .filter(|key| scope.contains_key(**key))
+            .map(|key| format!("`{key}`"))
+            .collect();
+        if !shadowed.is_empty() {
+            overrides.push(format!(
+                "profile `{profile}` overrides {}",
+                shadowed.join(", ")
+            ));
+        }
+    }
+    (!overrides.is_empty()).then(|| {
+        format!(
+            "Warning: {} in {file}, so the saved base value is not what loads.",
+            overrides.join("; ")
+        )
+    })
+}
+
+pub(super) fn read_layered_toml<T: DeserializeOwned>(
+    config_dir: &Path,
+    profile_dirs: &[PathBuf],
+    file: &str,
+) -> Result<T, ConfigError> {
+    let base_path = config_dir.join(file);
+    let mut merged: Value = read_toml(&base_path)?;
+    let mut rewritten = false;
+    for dir in profile_dirs {
+        let path = dir.join(file);
+        if path.is_file() {
+            merge_toml_layer(&mut merged, read_toml(&path)?);
+            rewritten = true;
+        }
+    }
+    if let Some(rows_key) = inheritable_rows_key(file) {
+        let mut report = ConfigReport::default();
+        rewritten |= resolve_row_inheritance(&mut merged, file, rows_key, &mut report);
+        locate_layered_report(&mut report, config_dir, profile_dirs);
+        report.into_result()?;
+    }
+
+    if !rewritten {
+        // Deserializing straight from the file keeps line/column info in type errors.
+        return read_toml(&base_path);
+    }
+    merged.try_into().map_err(|source| ConfigError::Parse {
+        path: base_path,
+        source: Box::new(source),
+    })
+}
+
+// Issue key paths index into the merged config; each is pointed at the top-most
+// layer that actually sets the key (re-indexing rows by id) before locating it,
+// so the reported file and line are the ones to edit.
+pub(super) fn locate_layered_report(
+    report: &mut ConfigReport,
+    config_dir: &Path,
+    profile_dirs: &[PathBuf],
+) {
+    let mut layers_by_file: HashMap<String, Vec<(String, Value)>> = HashMap::new();
+    for issue in &mut report.issues {
+        let layers = layers_by_file
+            .entry(issue.file.clone())
+            .or_insert_with(|| read_layers(config_dir, profile_dirs, &issue.file));
+        if let Some((file, key_path, index)) = layer_for_key(layers, &issue.key_path) {
+            issue.file = file;
+            issue.key_path = key_path;
+            issue.index = index;
+        }
+    }
+    report.locate(|file| fs::read_to_string(config_dir.join(file)).ok());
+}
+
+fn read_layers(config_dir: &Path, profile_dirs: &[PathBuf], file: &str) -> Vec<(String, Value)> {
+    std::iter::once(config_dir.join(file))
+        .chain(profile_dirs.iter().map(|dir| dir.join(file)))
+        .filter_map(|path| {
+            let layer: Value = toml::from_str(&fs::read_to_string(&path).ok()?).ok()?;
+            let label = path.strip_prefix(config_dir).unwrap_or(&path);
+            Some((label.to_string_lossy().replace('\\', "/"), layer))
+        })
+        .collect()
+}
+
+fn layer_for_key(
+    layers: &[(String, Value)],
+    key_path: &str,
+) -> Option<(String, String, Option<usize>)> {
+    if layers.len() < 2 || key_path.is_empty() {
+        return None;
+    }
+    let (head, rest) = match key_path.split_once('.') {
+        Some((head, rest)) => (head, Some(rest)),
+        None => (key_path, None),
+    };
+    let Some((rows_key, merged_index)) = head
+        .strip_suffix(']')
+        .and_then(|head| head.split_once('['))
+        .and_then(|(rows_key, index)| Some((rows_key, index.parse::<usize>().ok()?)))
+    else {
+        return layers
+            .iter()
+            .rev()
+            .find(|(_, layer)| value_at(layer, key_path).is_some())
+            .map(|(file, _)| (file.clone(), key_path.to_string(), None));
+    };
+
+    let layer_rows: Vec<(&String, &Vec<Value>)> = layers
+        .iter()
+        .filter_map(|(file, layer)| Some((file, layer.get(rows_key)?.as_array()?)))
+        .collect();
+    let located = |file: &String, index: usize| {
+        let key_path = match rest {
+            Some(rest) => format!("{rows_key}[{index}].{rest}"),
+            None => format!("{rows_key}[{index}]"),
+        };
+        (file.clone(), key_path, Some(index))
+    };
+    if !layer_rows.iter().all(|(_, rows)| all_rows_have_ids(rows)) {
+        // Rows without ids are replaced wholesale, so the top-most layer owns them all.
+        let (file, _) = layer_rows.last()?;
+        return Some(located(file, merged_index));
+    }
+
+    let mut merged_ids: Vec<&str> = Vec::new();
+    for (_, rows) in &layer_rows {
+        for id in rows.iter().filter_map(row_id) {
+            if !merged_ids.contains(&id) {
+                merged_ids.push(id);
+            }
+        }
+    }
+    let id = *merged_ids.get(merged_index)?;
+    let holders: Vec<(&String, usize, &Value)> = layer_rows
+        .iter()
+        .filter_map(|(file, rows)| {
+            let index = rows.iter().position(|row| row_id(row) == Some(id))?;
+            Some((*file, index, &rows[index]))
+        })
+        .collect();
+    let (file, index, _) = holders
+        .iter()
+        .rev()
+        .find(|(_, _, row)| rest.is_none_or(|rest| value_at(row, rest).is_some()))
+        .or_else(|| holders.first())?;
+    Some(located(file, *index))
+}
+
+fn value_at<'a>(root: &'a Value, key_path: &str) -> Option<&'a Value> {
+    key_path
+        .split('.')
+        .try_fold(root, |value, segment| match segment.split_once('[') {
+            Some((key, index)) => value
+                .get(key)?
+                .get(index.trim_end_matches(']').parse::<usize>().ok()?),
+            None => value.get(segment),
+        })
+}
+
+// Tables merge key-by-key and `[[rows]]` that all carry an `id` merge row-by-id
+// (unknown ids are appended); any other value, including plain arrays, is replaced.
+pub(super) fn merge_toml_layer(base: &mut Value, layer: Value) {
+    match (base, layer) {
+        (Value::Table(base), Value::Table(layer)) => {
+            for (key, value) in layer {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_toml_layer(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (Value::Array(base), Value::Array(layer))
+            if all_rows_have_ids(base) && all_rows_have_ids(&layer) =>
+        {
+            for row in layer {
+                let existing = base
+                    .iter_mut()
+                    .find(|existing| row_id(existing) == row_id(&row));
+                match existing {
+                    Some(existing) => merge_toml_layer(existing, row),
+                    None => base.push(row),
+                }
+            }
+        }
+        (base, layer) => *base = layer,
+    }
+}
+
+fn all_rows_have_ids(rows: &[Value]) -> bool {
+    rows.iter().all(|row| row_id(row).is_some())
+}
+
+pub(super) fn row_id(row: &Value) -> Option<&str> {
+    row.get("id").and_then(Value::as_str)
+}
@@ -0,0 +1,191 @@
+use super::*;
+use toml_edit::{ImDocument, Item, TableLike};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    pub file: String,
+    pub key_path: String,
+    pub index: Option<usize>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    pub fn new(file: &str, key_path: String, index: Option<usize>, message: String) -> Self {
+        Self {
+            file: file.to_string(),
+            key_path,
+            index,
+            line: None,
+            column: None,
+            message,
+        }
+    }
+
+    pub fn location(&self) -> String {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => format!("{}:{line}:{column}", self.file),
+            _ => self.file.clone(),
+        }
+    }
+}
+
+impl Display for ConfigIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.key_path.is_empty() {
+            write!(f, "{}: {}", self.location(), self.message)
+        } else {
+            write!(f, "{}: {} {}", self.location(), self.key_path, self.message)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigReport {
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl ConfigReport {
+    pub fn push(
+        &mut self,
+        file: &str,
+        key_path: impl Into<String>,
+        index: Option<usize>,
+        message: impl Into<String>,
+    ) {
+        self.issues.push(ConfigIssue::new(
+            file,
+            key_path.into(),
+            index,
+            message.into(),
+        ));
+    }
+
+    // Keeps loading past a file that failed to read or parse so one run lists
+    // every broken file; parse errors carry their own line/column.
+    pub fn absorb<T>(&mut self, result: Result<T, ConfigError>) -> Option<T> {
+        let error = match result {
+            Ok(value) => return Some(value),
+            Err(error) => error,
+        };
+        match error {
+            ConfigError::Io { path, source } => self.push(
+                &path.to_string_lossy(),
+                "",
+                None,
+                format!("failed to read: {source}"),
+            ),
+            ConfigError::Parse { path, source } => {
+                let mut issue = ConfigIssue::new(
+                    &path.to_string_lossy(),
+                    String::new(),
+                    None,
+                    source.message().to_string(),
+                );
+                if let (Some(span), Ok(raw)) = (source.span(), fs::read_to_string(&path)) {
+                    let (line, column) = line_column(&raw, span.start);
+                    issue.line = Some(line);
+                    issue.column = Some(column);
+                }
+                self.issues.push(issue);
+            }
+            ConfigError::Invalid(report) => self.issues.extend(report.issues),
+        }
+        None
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub fn into_result(self) -> Result<(), ConfigError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(self))
+        }
+    }
+
+    pub fn locate(&mut self, read_source: impl Fn(&str) -> Option<String>) {
+        let mut documents: HashMap<String, Option<ImDocument<String>>> = HashMap::new();
+        for issue in &mut self.issues {
+            let document = documents.entry(issue.file.clone()).or_insert_with(|| {
+                read_source(&issue.file).and_then(|raw| ImDocument::parse(raw).ok())
+            });
+            let Some(document) = document.as_ref() else {
+                continue;
+            };
+            let Some(offset) = key_path_offset(document.as_table(), &issue.key_path) else {
+                continue;
+            };
+
+            let (line, column) = line_column(document.raw(), offset);
+            issue.line = Some(line);
+            issue.column = Some(column);
+        }
+    }
+}
+
+impl Display for ConfigReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} config validation error(s):", self.issues.len())?;
+        for issue in &self.issues {
+            write!(f, "\n  {issue}")?;
+        }
+        Ok(())
+    }
+}
+
+fn line_column(raw: &str, offset: usize) -> (usize, usize) {
+    let before = &raw[..offset.min(raw.len())];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+// Walks `a[1].b` through the parsed document and returns the byte offset of
+// the deepest key/table it could find, so a typo in a later segment still
+// points at its parent entry.
+fn key_path_offset(root: &dyn TableLike, key_path: &str) -> Option<usize> {
+    let mut table = Some(root);
+    let mut offset = None;
+    for segment in key_path.split('.').filter(|segment| !segment.is_empty()) {
+        let (name, index) = match segment.split_once('[') {
+            Some((name, rest)) => (name, rest.trim_end_matches(']').parse::<usize>().ok()),
+            None => (segment, None),
+        };
+        let Some((key, item)) = table.and_then(|table| table.get_key_value(name)) else {
+            break;
+        };
+        offset = key
+            .span()
+            .or_else(|| item.span())
+            .map(|span| span.start)
+            .or(offset);
+
+        table = match (item, index) {
+            (_, None) => item.as_table_like(),
+            (Item::ArrayOfTables(rows), Some(index)) => {
+                let Some(row) = rows.get(index) else {
+                    break;
+                };
+                offset = row.span().map(|span| span.start).or(offset);
+                Some(row as &dyn TableLike)
+            }
+            (Item::Value(value), Some(index)) => {
+                let Some(element) = value.as_array().and_then(|array| array.get(index)) else {
+                    break;
+                };
+                offset = element.span().map(|span| span.start).or(offset);
+                element
+                    .as_inline_table()
+                    .map(|inline| inline as &dyn TableLike)
+            }
+            _ => break,
+        };
+    }
+    offset
+}
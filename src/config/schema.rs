@@ -0,0 +1,71 @@
+use super::*;
+use schemars::generate::SchemaSettings;
+use schemars::transform::RecursiveTransform;
+use schemars::Schema;
+use serde_json::{json, Value};
+
+pub fn config_file_schemas() -> Vec<(&'static str, Schema)> {
+    vec![
+        ("game.toml", draft07_schema::<GameFile>()),
+        ("assets.toml", draft07_schema::<AssetsFile>()),
+        ("segments.toml", draft07_schema::<SegmentsFile>()),
+        ("backgrounds.toml", draft07_schema::<BackgroundsFile>()),
+        ("environments.toml", draft07_schema::<EnvironmentsFile>()),
+        ("enemy_types.toml", draft07_schema::<EnemyTypesFile>()),
+        ("spawners.toml", draft07_schema::<SpawnersFile>()),
+        ("weapons.toml", draft07_schema::<WeaponsFile>()),
+        ("vehicles.toml", draft07_schema::<VehiclesFile>()),
+        ("upgrades.toml", draft07_schema::<UpgradesFile>()),
+        ("commentator.toml", draft07_schema::<CommentatorFile>()),
+    ]
+}
+
+// Draft-07 keeps the output readable by Taplo / Even Better TOML.
+fn draft07_schema<T: JsonSchema>() -> Schema {
+    SchemaSettings::draft07()
+        .with_transform(RecursiveTransform(shorten_float_defaults))
+        .into_generator()
+        .into_root_schema_for::<T>()
+}
+
+// Config floats are f32, so `default_*` values would otherwise be emitted
+// widened (`1.7999999523162842` instead of `1.8`).
+fn shorten_float_defaults(schema: &mut Schema) {
+    if let Some(default) = schema.get_mut("default") {
+        shorten_floats(default);
+    }
+}
+
+fn shorten_floats(value: &mut Value) {
+    match value {
+        Value::Number(number) if number.is_f64() => {
+            if let Some(float) = number.as_f64() {
+                *value = json!(f32_to_short_f64(float as f32));
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(shorten_floats),
+        Value::Object(fields) => fields.values_mut().for_each(shorten_floats),
+        _ => {}
+    }
+}
+
+// Rows that declare `extends` inherit their missing fields, so only `id` is
+// always required and the rest only when there is no parent.
+pub(super) fn extendable_row_schema(schema: &mut Schema) {
+    let Some(required) = schema.remove("required") else {
+        return;
+    };
+    let inherited: Vec<Value> = required
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|key| key.as_str() != Some("id"))
+        .cloned()
+        .collect();
+    schema.insert("required".to_string(), json!(["id"]));
+    schema.insert(
+        "if".to_string(),
+        json!({ "not": { "required": ["extends"] } }),
+    );
+    schema.insert("then".to_string(), json!({ "required": inherited }));
+}
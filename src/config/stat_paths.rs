@@ -0,0 +1,306 @@
+use super::*;
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatTargetKind {
+    Weapon,
+    Vehicle,
+    Enemy,
+    Pickups,
+}
+
+impl StatTargetKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Weapon => "weapon",
+            Self::Vehicle => "vehicle",
+            Self::Enemy => "enemy",
+            Self::Pickups => "pickups",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatPath {
+    pub kind: StatTargetKind,
+    pub id: Option<String>,
+    pub field: String,
+}
+
+impl StatPath {
+    pub fn new(kind: StatTargetKind, id: Option<&str>, field: &str) -> Self {
+        Self {
+            kind,
+            id: id.map(str::to_string),
+            field: field.to_string(),
+        }
+    }
+
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let parts: Vec<&str> = raw.trim().split('.').collect();
+        let kind = match parts.first().copied() {
+            Some("weapon") => StatTargetKind::Weapon,
+            Some("vehicle") => StatTargetKind::Vehicle,
+            Some("enemy") => StatTargetKind::Enemy,
+            Some("pickups") => StatTargetKind::Pickups,
+            _ => {
+                return Err(format!(
+                    "stat path `{raw}` must start with weapon/vehicle/enemy/pickups"
+                ))
+            }
+        };
+
+        let (id, field) = match (kind, parts.as_slice()) {
+            (StatTargetKind::Pickups, [_, field]) => (None, *field),
+            (StatTargetKind::Pickups, _) => {
+                return Err(format!("stat path `{raw}` must be `pickups.<field>`"));
+            }
+            (_, [_, id, field]) => (Some(*id), *field),
+            _ => {
+                return Err(format!(
+                    "stat path `{raw}` must be `{}.<id>.<field>`",
+                    kind.label()
+                ));
+            }
+        };
+        if id.is_some_and(str::is_empty) || field.is_empty() {
+            return Err(format!("stat path `{raw}` has an empty segment"));
+        }
+
+        Ok(Self::new(kind, id, field))
+    }
+
+    // Floors and ceilings the hand-written upgrade effects used to enforce, so
+    // stacked modifiers cannot push a stat outside what the sim can handle.
+    pub fn bounds(&self) -> Option<(f32, f32)> {
+        match (self.kind, self.field.as_str()) {
+            (StatTargetKind::Weapon, "fire_rate") => Some((0.05, f32::MAX)),
+            (StatTargetKind::Weapon, "homing_turn_rate_degrees") => Some((0.0, f32::MAX)),
+            (StatTargetKind::Vehicle, "missile_fire_interval_seconds") => Some((0.05, f32::MAX)),
+            (StatTargetKind::Vehicle, "acceleration") => Some((0.01, f32::MAX)),
+            (StatTargetKind::Vehicle, "turret_cone_degrees") => Some((1.0, 180.0)),
+            (StatTargetKind::Vehicle, "turret_range_m") => Some((0.1, f32::MAX)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for StatPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.id {
+            Some(id) => write!(f, "{}.{id}.{}", self.kind.label(), self.field),
+            None => write!(f, "{}.{}", self.kind.label(), self.field),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatModifierOp {
+    Add(f32),
+    Multiply(f32),
+}
+
+impl StatModifierOp {
+    pub fn apply(self, value: f32) -> f32 {
+        match self {
+            Self::Add(amount) => value + amount,
+            Self::Multiply(factor) => value * factor,
+        }
+    }
+
+    pub fn inverse(self) -> Self {
+        match self {
+            Self::Add(amount) => Self::Add(-amount),
+            Self::Multiply(factor) => Self::Multiply(1.0 / factor),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatModifier {
+    pub path: StatPath,
+    pub op: StatModifierOp,
+}
+
+impl Display for StatModifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.op {
+            StatModifierOp::Add(amount) => write!(f, "{} {amount:+}", self.path),
+            StatModifierOp::Multiply(factor) => write!(f, "{} x{factor}", self.path),
+        }
+    }
+}
+
+impl GameConfig {
+    pub fn stat_value(&self, path: &StatPath) -> Result<f32, String> {
+        stat_target_value(
+            path,
+            &self.weapons_by_id,
+            &self.vehicles_by_id,
+            &self.enemy_types_by_id,
+            &self.game.pickups,
+        )
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct RunStats {
+    pub player_vehicle_id: String,
+    pub weapons_by_id: HashMap<String, WeaponConfig>,
+    pub vehicles_by_id: HashMap<String, VehicleConfig>,
+    pub enemy_types_by_id: HashMap<String, EnemyTypeConfig>,
+    pub pickups: PickupConfig,
+}
+
+impl RunStats {
+    pub fn from_config(config: &GameConfig) -> Self {
+        Self::for_vehicle(config, &config.game.app.default_vehicle)
+    }
+
+    // Falls back to `app.default_vehicle` when the id is no longer in the
+    // config, e.g. after a reload removed the selected vehicle.
+    pub fn for_vehicle(config: &GameConfig, vehicle_id: &str) -> Self {
+        let player_vehicle_id = if config.vehicles_by_id.contains_key(vehicle_id) {
+            vehicle_id
+        } else {
+            config.game.app.default_vehicle.as_str()
+        };
+        Self {
+            player_vehicle_id: player_vehicle_id.to_string(),
+            weapons_by_id: config.weapons_by_id.clone(),
+            vehicles_by_id: config.vehicles_by_id.clone(),
+            enemy_types_by_id: config.enemy_types_by_id.clone(),
+            pickups: config.game.pickups.clone(),
+        }
+    }
+
+    pub fn rebuild<'a>(
+        config: &GameConfig,
+        player_vehicle_id: &str,
+        modifiers: impl IntoIterator<Item = &'a StatModifier>,
+    ) -> (Self, Vec<String>) {
+        let mut stats = Self::for_vehicle(config, player_vehicle_id);
+        let mut errors = Vec::new();
+        for modifier in modifiers {
+            if let Err(error) = stats.apply_stat_modifier(modifier) {
+                errors.push(error);
+            }
+        }
+        (stats, errors)
+    }
+
+    pub fn player_vehicle(&self) -> Option<&VehicleConfig> {
+        self.vehicles_by_id.get(&self.player_vehicle_id)
+    }
+
+    pub fn stat_value(&self, path: &StatPath) -> Result<f32, String> {
+        stat_target_value(
+            path,
+            &self.weapons_by_id,
+            &self.vehicles_by_id,
+            &self.enemy_types_by_id,
+            &self.pickups,
+        )
+    }
+
+    pub fn apply_stat_modifier(&mut self, modifier: &StatModifier) -> Result<f32, String> {
+        let path = &modifier.path;
+        match path.kind {
+            StatTargetKind::Weapon => {
+                modify_stat_field(stat_target_mut(&mut self.weapons_by_id, path)?, modifier)
+            }
+            StatTargetKind::Vehicle => {
+                modify_stat_field(stat_target_mut(&mut self.vehicles_by_id, path)?, modifier)
+            }
+            StatTargetKind::Enemy => modify_stat_field(
+                stat_target_mut(&mut self.enemy_types_by_id, path)?,
+                modifier,
+            ),
+            StatTargetKind::Pickups => modify_stat_field(&mut self.pickups, modifier),
+        }
+    }
+}
+
+fn stat_target_value(
+    path: &StatPath,
+    weapons_by_id: &HashMap<String, WeaponConfig>,
+    vehicles_by_id: &HashMap<String, VehicleConfig>,
+    enemy_types_by_id: &HashMap<String, EnemyTypeConfig>,
+    pickups: &PickupConfig,
+) -> Result<f32, String> {
+    let serialized = match path.kind {
+        StatTargetKind::Weapon => serialize_stat_target(weapons_by_id.get(stat_id(path)?)),
+        StatTargetKind::Vehicle => serialize_stat_target(vehicles_by_id.get(stat_id(path)?)),
+        StatTargetKind::Enemy => serialize_stat_target(enemy_types_by_id.get(stat_id(path)?)),
+        StatTargetKind::Pickups => serialize_stat_target(Some(pickups)),
+    }
+    .ok_or_else(|| format!("stat path `{path}` references an unknown id"))??;
+
+    numeric_field(&serialized, path).map(|value| value as f32)
+}
+
+fn stat_target_mut<'a, T>(
+    targets: &'a mut HashMap<String, T>,
+    path: &StatPath,
+) -> Result<&'a mut T, String> {
+    targets.get_mut(stat_id(path)?).ok_or_else(|| {
+        format!(
+            "stat path `{path}` references unknown {}",
+            path.kind.label()
+        )
+    })
+}
+
+fn stat_id(path: &StatPath) -> Result<&str, String> {
+    path.id
+        .as_deref()
+        .ok_or_else(|| format!("stat path `{path}` is missing an id"))
+}
+
+fn serialize_stat_target<T: Serialize>(target: Option<&T>) -> Option<Result<Value, String>> {
+    target.map(|target| serde_json::to_value(target).map_err(|error| error.to_string()))
+}
+
+fn numeric_field(serialized: &Value, path: &StatPath) -> Result<f64, String> {
+    let field = serialized
+        .get(&path.field)
+        .ok_or_else(|| format!("stat path `{path}` references unknown field"))?;
+    field
+        .as_f64()
+        .ok_or_else(|| format!("stat path `{path}` does not point at a numeric field"))
+}
+
+fn modify_stat_field<T: Serialize + DeserializeOwned>(
+    target: &mut T,
+    modifier: &StatModifier,
+) -> Result<f32, String> {
+    let mut serialized = serde_json::to_value(&*target).map_err(|error| error.to_string())?;
+    let current = numeric_field(&serialized, &modifier.path)? as f32;
+    let mut next = modifier.op.apply(current);
+    if !next.is_finite() {
+        return Err(format!(
+            "modifier `{modifier}` produced a non-finite value from {current}"
+        ));
+    }
+    if let Some((min, max)) = modifier.path.bounds() {
+        next = next.clamp(min, max);
+    }
+
+    let Some(field) = serialized.get_mut(&modifier.path.field) else {
+        return Err(format!(
+            "stat path `{}` references unknown field",
+            modifier.path
+        ));
+    };
+    let stored = if field.is_u64() || field.is_i64() {
+        let rounded = next.round().max(0.0);
+        *field = Value::from(rounded as u64);
+        rounded
+    } else {
+        *field = Value::from(next);
+        next
+    };
+
+    *target = serde_json::from_value(serialized).map_err(|error| error.to_string())?;
+    Ok(stored)
+}
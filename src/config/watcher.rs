@@ -0,0 +1,245 @@
+use super::profiles::row_id;
+use super::*;
+use std::collections::BTreeSet;
+use std::time::SystemTime;
+use toml::Value;
+
+const CONFIG_WATCH_POLL_SECONDS: f32 = 0.25;
+const CONFIG_WATCH_DEBOUNCE_SECONDS: f64 = 0.4;
+
+#[derive(Resource)]
+pub(super) struct ConfigWatcher {
+    config_dir: PathBuf,
+    poll_timer: Timer,
+    modified: HashMap<PathBuf, Option<SystemTime>>,
+    loaded_sources: HashMap<PathBuf, String>,
+    changed_since_reload: BTreeSet<PathBuf>,
+    last_change_seconds: Option<f64>,
+}
+
+impl ConfigWatcher {
+    fn new(config_dir: &Path) -> Self {
+        let mut watcher = Self {
+            config_dir: config_dir.to_path_buf(),
+            poll_timer: Timer::from_seconds(CONFIG_WATCH_POLL_SECONDS, TimerMode::Repeating),
+            modified: HashMap::new(),
+            loaded_sources: HashMap::new(),
+            changed_since_reload: BTreeSet::new(),
+            last_change_seconds: None,
+        };
+        watcher.sync_to_disk();
+        watcher
+    }
+
+    // Takes the files as they are on disk now as the loaded config, dropping any
+    // pending change: the reload that just happened already picked it up.
+    fn sync_to_disk(&mut self) {
+        self.modified = scan_toml_files(&self.config_dir);
+        self.loaded_sources = self
+            .modified
+            .keys()
+            .filter_map(|path| Some((path.clone(), fs::read_to_string(path).ok()?)))
+            .collect();
+        self.changed_since_reload.clear();
+        self.last_change_seconds = None;
+    }
+
+    fn poll(&mut self, now_seconds: f64) {
+        let modified = scan_toml_files(&self.config_dir);
+        let mut changed = false;
+        for path in modified.keys().chain(self.modified.keys()) {
+            if modified.get(path) != self.modified.get(path) {
+                self.changed_since_reload.insert(path.clone());
+                changed = true;
+            }
+        }
+        if changed {
+            self.modified = modified;
+            self.last_change_seconds = Some(now_seconds);
+        }
+    }
+
+    fn settled(&self, now_seconds: f64) -> bool {
+        self.last_change_seconds
+            .is_some_and(|last| now_seconds - last >= CONFIG_WATCH_DEBOUNCE_SECONDS)
+    }
+
+    fn take_diffs(&mut self) -> Vec<ConfigFileDiff> {
+        let mut diffs = Vec::new();
+        for path in std::mem::take(&mut self.changed_since_reload) {
+            let old = self.loaded_sources.remove(&path).unwrap_or_default();
+            let new = fs::read_to_string(&path).unwrap_or_default();
+            let file = path
+                .strip_prefix(&self.config_dir)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            if let Some(diff) = diff_config_sources(&file, &old, &new) {
+                diffs.push(diff);
+            }
+            if path.is_file() {
+                self.loaded_sources.insert(path, new);
+            }
+        }
+        diffs
+    }
+}
+
+pub(super) fn start_config_watcher(mut commands: Commands, cli: Option<Res<CliArgs>>) {
+    if cli.is_some_and(|cli| cli.no_config_watch) {
+        info!("Config file watching disabled by `--no-config-watch`.");
+        return;
+    }
+
+    info!("Watching `{CONFIG_DIR}` for config changes.");
+    commands.insert_resource(ConfigWatcher::new(Path::new(CONFIG_DIR)));
+}
+
+pub(super) fn reload_changed_config_files(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut watcher: ResMut<ConfigWatcher>,
+    game_config: Option<ResMut<GameConfig>>,
+    mut reloaded_events: MessageReader<ConfigReloadedEvent>,
+) {
+    if reloaded_events.read().count() > 0 {
+        watcher.sync_to_disk();
+    }
+    let now_seconds = time.elapsed_secs_f64();
+    if watcher.poll_timer.tick(time.delta()).just_finished() {
+        watcher.poll(now_seconds);
+    }
+    if !watcher.settled(now_seconds) {
+        return;
+    }
+    let Some(mut current_config) = game_config else {
+        return;
+    };
+
+    watcher.last_change_seconds = None;
+    // On failure the changed files stay pending, so the next successful reload
+    // reports everything that differs from the last config that loaded.
+    if !reload_game_config(&mut commands, &mut current_config, "Auto-reloaded") {
+        return;
+    }
+    let diffs = watcher.take_diffs();
+    if diffs.is_empty() {
+        info!("Config files were touched but no values changed.");
+    }
+    for diff in diffs {
+        info!("Config change: {diff}");
+    }
+}
+
+fn scan_toml_files(dir: &Path) -> HashMap<PathBuf, Option<SystemTime>> {
+    let mut files = HashMap::new();
+    let mut pending_dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = pending_dirs.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                pending_dirs.push(path);
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension == "toml")
+            {
+                let modified = entry.metadata().and_then(|meta| meta.modified()).ok();
+                files.insert(path, modified);
+            }
+        }
+    }
+    files
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct ConfigFileDiff {
+    pub file: String,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl Display for ConfigFileDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file)?;
+        for (label, keys) in [
+            ("changed", &self.changed),
+            ("added", &self.added),
+            ("removed", &self.removed),
+        ] {
+            if !keys.is_empty() {
+                write!(f, " {label} [{}]", keys.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Compares two versions of a config file by top-level key, and by row id for
+// `[[rows]]` tables, so a tuning edit is reported as e.g. `vehicles.starter`.
+// Returns `None` when both sides hold the same values.
+pub(super) fn diff_config_sources(file: &str, old: &str, new: &str) -> Option<ConfigFileDiff> {
+    let parse = |raw: &str| match toml::from_str::<Value>(raw) {
+        Ok(Value::Table(table)) => Some(table),
+        _ => None,
+    };
+    let mut diff = ConfigFileDiff {
+        file: file.to_string(),
+        ..Default::default()
+    };
+    let (Some(old), Some(new)) = (parse(old), parse(new)) else {
+        diff.changed.push("(unparsed file)".to_string());
+        return Some(diff);
+    };
+
+    let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    for key in keys {
+        match (old.get(key), new.get(key)) {
+            (Some(old_value), Some(new_value)) if old_value == new_value => {}
+            (Some(Value::Array(old_rows)), Some(Value::Array(new_rows))) => {
+                match (rows_by_id(old_rows), rows_by_id(new_rows)) {
+                    (Some(old_rows), Some(new_rows)) => {
+                        diff_rows(key, &old_rows, &new_rows, &mut diff)
+                    }
+                    _ => diff.changed.push(key.clone()),
+                }
+            }
+            (Some(_), Some(_)) => diff.changed.push(key.clone()),
+            (Some(_), None) => diff.removed.push(key.clone()),
+            (None, _) => diff.added.push(key.clone()),
+        }
+    }
+
+    if diff.added.is_empty() && diff.removed.is_empty() && diff.changed.is_empty() {
+        None
+    } else {
+        Some(diff)
+    }
+}
+
+fn diff_rows(
+    key: &str,
+    old_rows: &[(&str, &Value)],
+    new_rows: &[(&str, &Value)],
+    diff: &mut ConfigFileDiff,
+) {
+    for (id, old_row) in old_rows {
+        match new_rows.iter().find(|(new_id, _)| new_id == id) {
+            Some((_, new_row)) if new_row == old_row => {}
+            Some(_) => diff.changed.push(format!("{key}.{id}")),
+            None => diff.removed.push(format!("{key}.{id}")),
+        }
+    }
+    for (id, _) in new_rows {
+        if !old_rows.iter().any(|(old_id, _)| old_id == id) {
+            diff.added.push(format!("{key}.{id}"));
+        }
+    }
+}
+
+fn rows_by_id(rows: &[Value]) -> Option<Vec<(&str, &Value)>> {
+    rows.iter().map(|row| Some((row_id(row)?, row))).collect()
+}
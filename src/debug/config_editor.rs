@@ -0,0 +1,340 @@
+use super::*;
+use crate::config::{ConfigReloadedEvent, EditableConfigTable};
+use toml::Value;
+
+#[derive(Resource, Debug, Default)]
+pub(super) struct ConfigEditorPanelState {
+    visible: bool,
+    table: EditableConfigTable,
+    row_id: Option<String>,
+    original: Option<toml::Table>,
+    draft: Option<toml::Table>,
+    status: String,
+}
+
+impl ConfigEditorPanelState {
+    fn select(&mut self, config: &GameConfig, table: EditableConfigTable, row_id: Option<String>) {
+        self.table = table;
+        self.row_id = row_id.or_else(|| table.row_ids(config).into_iter().next());
+        match table.read_row(config, self.row_id.as_deref()) {
+            Ok(row) => {
+                self.original = Some(row.clone());
+                self.draft = Some(row);
+            }
+            Err(error) => {
+                self.original = None;
+                self.draft = None;
+                self.status = format!("Config editor: {error}");
+            }
+        }
+    }
+}
+
+pub(super) fn toggle_config_editor_panel(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut panel_state: ResMut<ConfigEditorPanelState>,
+    config: Option<Res<GameConfig>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyE) {
+        return;
+    }
+
+    panel_state.visible = !panel_state.visible;
+    if panel_state.visible {
+        if let Some(config) = config {
+            let (table, row_id) = (panel_state.table, panel_state.row_id.clone());
+            panel_state.select(&config, table, row_id);
+        }
+        info!("Config table editor shown.");
+    } else {
+        info!("Config table editor hidden.");
+    }
+}
+
+pub(super) fn config_editor_panel_ui(
+    mut egui_contexts: EguiContexts,
+    mut panel_state: ResMut<ConfigEditorPanelState>,
+    mut config: ResMut<GameConfig>,
+    mut reloaded_events: MessageWriter<ConfigReloadedEvent>,
+) {
+    if !panel_state.visible {
+        return;
+    }
+
+    let table = panel_state.table;
+    let row_ids = table.row_ids(&config);
+    let mut selected_table = table;
+    let mut selected_row_id = panel_state.row_id.clone();
+    let mut draft = panel_state.draft.clone();
+    let original = panel_state.original.clone();
+    let mut window_open = panel_state.visible;
+    let mut draft_changed = false;
+    let mut revert_clicked = false;
+    let mut apply_clicked = false;
+    let status = panel_state.status.clone();
+
+    let Ok(ctx) = egui_contexts.ctx_mut() else {
+        return;
+    };
+    egui::Window::new("Config Table Editor")
+        .open(&mut window_open)
+        .resizable(true)
+        .default_width(560.0)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_label("table")
+                    .selected_text(selected_table.label())
+                    .show_ui(ui, |ui| {
+                        for option in EditableConfigTable::ALL {
+                            ui.selectable_value(&mut selected_table, option, option.label());
+                        }
+                    });
+                if table.has_rows() {
+                    egui::ComboBox::from_label("row")
+                        .selected_text(selected_row_id.as_deref().unwrap_or("-"))
+                        .show_ui(ui, |ui| {
+                            for id in &row_ids {
+                                ui.selectable_value(&mut selected_row_id, Some(id.clone()), id);
+                            }
+                        });
+                }
+            });
+            ui.label("Edits apply live to the in-memory config. Changed keys are marked with *.");
+            ui.separator();
+
+            if let Some(draft) = draft.as_mut() {
+                egui::ScrollArea::vertical()
+                    .max_height(420.0)
+                    .show(ui, |ui| {
+                        egui::Grid::new("config_editor_fields")
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for (key, value) in draft.iter_mut() {
+                                    let changed =
+                                        original.as_ref().and_then(|original| original.get(key))
+                                            != Some(&*value);
+                                    ui.label(if changed {
+                                        format!("{key} *")
+                                    } else {
+                                        key.clone()
+                                    });
+                                    if key == "id" || key == "extends" {
+                                        ui.monospace(value.to_string());
+                                    } else {
+                                        draft_changed |= config_value_editor(ui, value);
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+            }
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui.button("Revert Row").clicked() {
+                    revert_clicked = true;
+                }
+                if ui.button(format!("Apply To {}", table.file())).clicked() {
+                    apply_clicked = true;
+                }
+            });
+
+            if !status.is_empty() {
+                ui.separator();
+                ui.label(status);
+            }
+        });
+
+    panel_state.visible = window_open;
+
+    if selected_table != table || selected_row_id != panel_state.row_id {
+        let row_id = (selected_table == table)
+            .then_some(selected_row_id)
+            .flatten();
+        panel_state.status.clear();
+        panel_state.select(&config, selected_table, row_id);
+        return;
+    }
+
+    let row_id = panel_state.row_id.clone();
+    let (Some(original), Some(draft)) = (original, draft) else {
+        return;
+    };
+
+    // Rejected edits leave the config untouched, so only mark it changed (and
+    // wake every config-driven system) when a row actually went in.
+    let mut apply_live = |row: &toml::Table| {
+        let applied = table.apply_row(
+            config.bypass_change_detection(),
+            row_id.as_deref(),
+            row.clone(),
+        );
+        if applied.is_ok() {
+            config.set_changed();
+        }
+        applied
+    };
+
+    if revert_clicked {
+        panel_state.status = match apply_live(&original) {
+            Ok(()) => "Reverted row to its last loaded values.".to_string(),
+            Err(error) => format!("Revert failed: {error}"),
+        };
+        panel_state.draft = Some(original);
+        return;
+    }
+
+    if draft_changed {
+        panel_state.status = match apply_live(&draft) {
+            Ok(()) => "Live-tuning active (in-memory config updated).".to_string(),
+            Err(error) => format!("Not applied: {error}"),
+        };
+    }
+    panel_state.draft = Some(draft.clone());
+
+    if apply_clicked {
+        match persist_config_row_and_reload(
+            &mut config,
+            table,
+            row_id.as_deref(),
+            &original,
+            &draft,
+        ) {
+            Ok(message) => {
+                panel_state.select(&config, table, row_id);
+                panel_state.status = message;
+            }
+            Err(error) => panel_state.status = error,
+        }
+        reloaded_events.write(ConfigReloadedEvent);
+    }
+}
+
+fn config_value_editor(ui: &mut egui::Ui, value: &mut Value) -> bool {
+    match value {
+        Value::Float(number) => {
+            let speed = (number.abs() * 0.01).max(0.001);
+            ui.add(egui::DragValue::new(number).speed(speed)).changed()
+        }
+        Value::Integer(number) => ui.add(egui::DragValue::new(number)).changed(),
+        Value::Boolean(flag) => ui.checkbox(flag, "").changed(),
+        Value::String(text) => ui.text_edit_singleline(text).changed(),
+        Value::Array(items) if items.iter().all(Value::is_str) => {
+            let mut joined = items
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(", ");
+            if !ui.text_edit_singleline(&mut joined).changed() {
+                return false;
+            }
+            *items = joined
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(Value::from)
+                .collect();
+            true
+        }
+        other => {
+            ui.monospace(other.to_string());
+            false
+        }
+    }
+}
+
+fn persist_config_row_and_reload(
+    config: &mut GameConfig,
+    table: EditableConfigTable,
+    row_id: Option<&str>,
+    original: &toml::Table,
+    draft: &toml::Table,
+) -> Result<String, String> {
+    let changed: Vec<(&String, &Value)> = draft
+        .iter()
+        .filter(|(key, value)| original.get(*key) != Some(*value))
+        .collect();
+    if changed.is_empty() {
+        return Ok(format!("No changed keys to save to {}.", table.file()));
+    }
+
+    let path = Path::new("config").join(table.file());
+    let original_raw = fs::read_to_string(&path)
+        .map_err(|error| format!("Failed reading `{}`: {error}", path.display()))?;
+    let mut root: DocumentMut = original_raw
+        .parse()
+        .map_err(|error| format!("Failed parsing `{}`: {error}", path.display()))?;
+
+    let target = match row_id {
+        Some(row_id) if table.has_rows() => root
+            .get_mut(table.toml_key())
+            .and_then(Item::as_array_of_tables_mut)
+            .and_then(|rows| {
+                rows.iter_mut()
+                    .find(|row| row.get("id").and_then(Item::as_str) == Some(row_id))
+            }),
+        _ => root
+            .entry(table.toml_key())
+            .or_insert(toml_edit::table())
+            .as_table_mut(),
+    };
+    let Some(target) = target else {
+        return Err(format!(
+            "{}: missing `{}` entry `{}`",
+            table.file(),
+            table.toml_key(),
+            row_id.unwrap_or_default()
+        ));
+    };
+
+    for (key, value) in &changed {
+        match value {
+            Value::Float(number) => set_toml_float(target, key, *number as f32)?,
+            other => {
+                let value = other
+                    .to_string()
+                    .parse::<toml_edit::Value>()
+                    .map_err(|error| format!("`{key}` could not be written: {error}"))?;
+                replace_toml_value(target, key, value);
+            }
+        }
+    }
+
+    let changed_keys: Vec<&str> = changed.iter().map(|(key, _)| key.as_str()).collect();
+    let warning = profile_override_warning(
+        Path::new("config"),
+        &config.profiles,
+        table.file(),
+        table.toml_key(),
+        row_id.filter(|_| table.has_rows()),
+        &changed_keys,
+    )
+    .map(|warning| format!(" {warning}"))
+    .unwrap_or_default();
+
+    fs::write(&path, root.to_string())
+        .map_err(|error| format!("Failed writing `{}`: {error}", path.display()))?;
+
+    match GameConfig::load_with_profiles(Path::new("config"), &config.profiles) {
+        Ok(new_config) => {
+            *config = new_config;
+            Ok(format!(
+                "Saved {} changed key(s) to {}.{warning}",
+                changed.len(),
+                path.to_string_lossy()
+            ))
+        }
+        Err(error) => {
+            let _ = fs::write(&path, original_raw);
+            if let Ok(restored) =
+                GameConfig::load_with_profiles(Path::new("config"), &config.profiles)
+            {
+                *config = restored;
+            }
+            Err(format!(
+                "Apply failed validation: {error}. Reverted `{}`.",
+                path.display()
+            ))
+        }
+    }
+}
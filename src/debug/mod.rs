@@ -1,13 +1,24 @@
-use crate::config::{BackgroundConfig, GameConfig, SfxConfig, TerrainConfig, VehicleConfig};
+use crate::config::{
+    f32_to_short_f64, profile_override_warning, BackgroundConfig, ConfigReloadFailure,
+    ConfigReloadedEvent, GameConfig, RunStats, SfxConfig, TerrainConfig, VehicleConfig,
+};
 use crate::gameplay::vehicle::{
-    PlayerVehicle, VehicleInputState, VehicleStuntMetrics, VehicleTelemetry,
+    PlayerVehicle, VehicleInputState, VehiclePartAlignmentReport, VehicleStuntMetrics,
+    VehicleTelemetry,
 };
-use crate::states::{GameState, RunSummary};
+use crate::states::{ActiveRun, RunSummary};
 use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
+use serde::Serialize;
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use toml_edit::{DocumentMut, Item, Table};
+
+mod config_editor;
+
+use config_editor::{config_editor_panel_ui, toggle_config_editor_panel, ConfigEditorPanelState};
 
 pub struct DebugOverlayPlugin;
 
@@ -21,25 +32,27 @@ impl Plugin for DebugOverlayPlugin {
             .init_resource::<VehicleTuningPanelState>()
             .init_resource::<BackgroundTuningPanelState>()
             .init_resource::<AudioTuningPanelState>()
+            .init_resource::<ConfigEditorPanelState>()
             .add_systems(Update, spawn_debug_overlay)
             .add_systems(Update, toggle_debug_text_overlay)
             .add_systems(Update, toggle_keybind_overlay)
             .add_systems(Update, toggle_vehicle_tuning_panel)
             .add_systems(Update, toggle_background_tuning_panel)
             .add_systems(Update, toggle_audio_tuning_panel)
+            .add_systems(Update, toggle_config_editor_panel)
             .add_systems(Update, sync_debug_overlay_visibility)
             .add_systems(Update, sync_keybind_overlay_visibility)
-            .add_systems(OnEnter(GameState::InRun), reset_run_stats)
+            .add_systems(OnEnter(ActiveRun), reset_run_stats)
             .add_systems(
                 Update,
                 update_debug_camera_pan
-                    .run_if(in_state(GameState::InRun))
+                    .run_if(in_state(ActiveRun))
                     .run_if(resource_exists::<GameConfig>),
             )
             .add_systems(
                 Update,
                 (update_run_stats, update_debug_overlay_text)
-                    .run_if(in_state(GameState::InRun))
+                    .run_if(in_state(ActiveRun))
                     .run_if(resource_exists::<GameConfig>),
             )
             .add_systems(
@@ -48,9 +61,14 @@ impl Plugin for DebugOverlayPlugin {
                     vehicle_tuning_panel_ui,
                     background_tuning_panel_ui,
                     audio_tuning_panel_ui,
+                    config_editor_panel_ui,
                 )
-                    .run_if(in_state(GameState::InRun))
+                    .run_if(in_state(ActiveRun))
                     .run_if(resource_exists::<GameConfig>),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                config_reload_failure_ui.run_if(resource_exists::<ConfigReloadFailure>),
             );
     }
 }
@@ -82,6 +100,7 @@ pub struct DebugRunStats {
     pub speed_mps: f32,
     pub grounded: bool,
     pub active_segment_id: String,
+    pub difficulty_scalar: f32,
 }
 
 impl Default for DebugRunStats {
@@ -91,6 +110,7 @@ impl Default for DebugRunStats {
             speed_mps: 0.0,
             grounded: true,
             active_segment_id: "n/a".to_string(),
+            difficulty_scalar: 1.0,
         }
     }
 }
@@ -128,6 +148,15 @@ struct VehicleTuningParams {
     linear_inertia: f32,
     rotational_inertia: f32,
     gravity_scale: f32,
+    chassis_length_m: f32,
+    chassis_height_m: f32,
+    chassis_mass_kg: f32,
+    chassis_center_of_mass_y_m: f32,
+    wheel_radius_m: f32,
+    front_hardpoint_x_m: f32,
+    front_hardpoint_y_m: f32,
+    rear_hardpoint_x_m: f32,
+    rear_hardpoint_y_m: f32,
     suspension_rest_length_m: f32,
     suspension_stiffness: f32,
     suspension_damping: f32,
@@ -164,6 +193,15 @@ impl VehicleTuningParams {
             linear_inertia: vehicle.linear_inertia,
             rotational_inertia: vehicle.rotational_inertia,
             gravity_scale: vehicle.gravity_scale,
+            chassis_length_m: vehicle.chassis_length_m,
+            chassis_height_m: vehicle.chassis_height_m,
+            chassis_mass_kg: vehicle.chassis_mass_kg,
+            chassis_center_of_mass_y_m: vehicle.chassis_center_of_mass_y_m,
+            wheel_radius_m: vehicle.wheel_radius_m,
+            front_hardpoint_x_m: vehicle.front_hardpoint_x_m,
+            front_hardpoint_y_m: vehicle.front_hardpoint_y_m,
+            rear_hardpoint_x_m: vehicle.rear_hardpoint_x_m,
+            rear_hardpoint_y_m: vehicle.rear_hardpoint_y_m,
             suspension_rest_length_m: vehicle.suspension_rest_length_m,
             suspension_stiffness: vehicle.suspension_stiffness,
             suspension_damping: vehicle.suspension_damping,
@@ -199,6 +237,15 @@ impl VehicleTuningParams {
         vehicle.linear_inertia = self.linear_inertia;
         vehicle.rotational_inertia = self.rotational_inertia;
         vehicle.gravity_scale = self.gravity_scale;
+        vehicle.chassis_length_m = self.chassis_length_m;
+        vehicle.chassis_height_m = self.chassis_height_m;
+        vehicle.chassis_mass_kg = self.chassis_mass_kg;
+        vehicle.chassis_center_of_mass_y_m = self.chassis_center_of_mass_y_m;
+        vehicle.wheel_radius_m = self.wheel_radius_m;
+        vehicle.front_hardpoint_x_m = self.front_hardpoint_x_m;
+        vehicle.front_hardpoint_y_m = self.front_hardpoint_y_m;
+        vehicle.rear_hardpoint_x_m = self.rear_hardpoint_x_m;
+        vehicle.rear_hardpoint_y_m = self.rear_hardpoint_y_m;
         vehicle.suspension_rest_length_m = self.suspension_rest_length_m;
         vehicle.suspension_stiffness = self.suspension_stiffness;
         vehicle.suspension_damping = self.suspension_damping;
@@ -319,6 +366,7 @@ struct AudioTuningParams {
     missile_launch_volume: f32,
     missile_hit_volume: f32,
     explode_volume: f32,
+    nitro_boost_volume: f32,
 }
 
 impl AudioTuningParams {
@@ -333,6 +381,7 @@ impl AudioTuningParams {
             missile_launch_volume: sfx.missile_launch_volume,
             missile_hit_volume: sfx.missile_hit_volume,
             explode_volume: sfx.explode_volume,
+            nitro_boost_volume: sfx.nitro_boost_volume,
         }
     }
 
@@ -346,6 +395,7 @@ impl AudioTuningParams {
         sfx.missile_launch_volume = self.missile_launch_volume;
         sfx.missile_hit_volume = self.missile_hit_volume;
         sfx.explode_volume = self.explode_volume;
+        sfx.nitro_boost_volume = self.nitro_boost_volume;
     }
 }
 
@@ -432,6 +482,7 @@ fn reset_run_stats(mut run_stats: ResMut<DebugRunStats>) {
     run_stats.distance_m = 0.0;
     run_stats.speed_mps = 0.0;
     run_stats.grounded = true;
+    run_stats.difficulty_scalar = 1.0;
 }
 
 fn update_run_stats(
@@ -463,6 +514,8 @@ fn update_run_stats(
     }
 
     run_stats.active_segment_id = active_segment;
+    run_stats.difficulty_scalar =
+        config.difficulty_scalar_at_distance(run_stats.distance_m.max(0.0));
 }
 
 fn update_debug_camera_pan(
@@ -499,8 +552,10 @@ fn update_debug_overlay_text(
     run_summary: Option<Res<RunSummary>>,
     input_state: Option<Res<VehicleInputState>>,
     stunts: Option<Res<VehicleStuntMetrics>>,
+    part_report: Option<Res<VehiclePartAlignmentReport>>,
     camera_pan: Res<DebugCameraPanState>,
     debug_text_overlay: Res<DebugTextOverlayState>,
+    config: Res<GameConfig>,
     mut overlay_query: Query<&mut Text, With<DebugOverlayText>>,
 ) {
     if !debug_text_overlay.visible {
@@ -541,8 +596,17 @@ fn update_debug_overlay_text(
         None => (0.0, 0.0, 0, 0.0, 0.0),
     };
 
+    let parts = match part_report.as_deref() {
+        Some(report) if report.checked_parts > 0 => format!(
+            "{} checked, {} issue(s)",
+            report.checked_parts,
+            report.issues.len()
+        ),
+        _ => "n/a".to_string(),
+    };
+
     *text = Text::new(format!(
-        "DBG FPS: {fps:>5.1}\nX: {player_x:>7.1} m | Pan: {camera_pan_offset:>6.1} m | Enemy: {enemy_count}\nInput: accel={accel} brake={brake} grounded={grounded}\nSpeed: {speed:>6.1} m/s | Score: {score} | Kills: {kills} | Coins: {coins}\nAir: {air_cur:>4.2}s | Wheelie: {wheelie_cur:>4.2}s | Crashes: {crashes}\nMax: {max_speed:>6.1} m/s | Impact: {impact:>5.1} m/s\nSegment: {segment}",
+        "DBG FPS: {fps:>5.1}\nX: {player_x:>7.1} m | Pan: {camera_pan_offset:>6.1} m | Enemy: {enemy_count}\nInput: accel={accel} brake={brake} grounded={grounded}\nSpeed: {speed:>6.1} m/s | Score: {score} | Kills: {kills} | Coins: {coins}\nAir: {air_cur:>4.2}s | Wheelie: {wheelie_cur:>4.2}s | Crashes: {crashes}\nMax: {max_speed:>6.1} m/s | Impact: {impact:>5.1} m/s\nSegment: {segment} | Difficulty: x{difficulty:.2} | Profile: {profile}\nParts: {parts}",
         player_x = player_x,
         speed = run_stats.speed_mps,
         score = score,
@@ -558,6 +622,8 @@ fn update_debug_overlay_text(
         impact = last_impact,
         camera_pan_offset = camera_pan.offset_x_m,
         segment = run_stats.active_segment_id,
+        difficulty = run_stats.difficulty_scalar,
+        profile = config.profile_label(),
     ));
 }
 
@@ -649,6 +715,7 @@ fn toggle_vehicle_tuning_panel(
     mut debug_guards: ResMut<DebugGameplayGuards>,
     mut panel_state: ResMut<VehicleTuningPanelState>,
     config: Option<Res<GameConfig>>,
+    run_stats: Option<Res<RunStats>>,
 ) {
     if !keyboard.just_pressed(KeyCode::KeyV) {
         return;
@@ -657,8 +724,12 @@ fn toggle_vehicle_tuning_panel(
     panel_state.visible = !panel_state.visible;
     debug_guards.player_invulnerable = panel_state.visible;
     if panel_state.visible {
-        if let Some(config) = config {
-            if let Err(error) = sync_panel_state_from_config(&mut panel_state, &config) {
+        if let (Some(config), Some(run_stats)) = (config, run_stats) {
+            if let Err(error) = sync_panel_state_from_config(
+                &mut panel_state,
+                &config,
+                &run_stats.player_vehicle_id,
+            ) {
                 panel_state.status = error;
             }
         }
@@ -720,15 +791,18 @@ fn vehicle_tuning_panel_ui(
     mut debug_guards: ResMut<DebugGameplayGuards>,
     mut panel_state: ResMut<VehicleTuningPanelState>,
     mut config: ResMut<GameConfig>,
+    run_stats: Res<RunStats>,
+    mut reloaded_events: MessageWriter<ConfigReloadedEvent>,
 ) {
     if !panel_state.visible {
         return;
     }
 
-    if panel_state.params.is_none()
-        || panel_state.source_vehicle_id != config.game.app.default_vehicle
-    {
-        if let Err(error) = sync_panel_state_from_config(&mut panel_state, &config) {
+    let player_vehicle_id = run_stats.player_vehicle_id.as_str();
+    if panel_state.params.is_none() || panel_state.source_vehicle_id != player_vehicle_id {
+        if let Err(error) =
+            sync_panel_state_from_config(&mut panel_state, &config, player_vehicle_id)
+        {
             panel_state.status = error;
             return;
         }
@@ -863,6 +937,72 @@ fn vehicle_tuning_panel_ui(
                 );
             });
 
+            ui.collapsing("Chassis Geometry", |ui| {
+                params_changed |= tuning_slider_row(
+                    ui,
+                    "chassis_length_m",
+                    &mut params.chassis_length_m,
+                    0.5..=8.0,
+                    0.01,
+                );
+                params_changed |= tuning_slider_row(
+                    ui,
+                    "chassis_height_m",
+                    &mut params.chassis_height_m,
+                    0.2..=4.0,
+                    0.01,
+                );
+                params_changed |= tuning_slider_row(
+                    ui,
+                    "chassis_mass_kg",
+                    &mut params.chassis_mass_kg,
+                    0.1..=100.0,
+                    0.1,
+                );
+                params_changed |= tuning_slider_row(
+                    ui,
+                    "chassis_center_of_mass_y_m",
+                    &mut params.chassis_center_of_mass_y_m,
+                    -2.0..=2.0,
+                    0.01,
+                );
+                params_changed |= tuning_slider_row(
+                    ui,
+                    "wheel_radius_m",
+                    &mut params.wheel_radius_m,
+                    0.1..=2.0,
+                    0.01,
+                );
+                params_changed |= tuning_slider_row(
+                    ui,
+                    "front_hardpoint_x_m",
+                    &mut params.front_hardpoint_x_m,
+                    0.1..=4.0,
+                    0.01,
+                );
+                params_changed |= tuning_slider_row(
+                    ui,
+                    "front_hardpoint_y_m",
+                    &mut params.front_hardpoint_y_m,
+                    -2.0..=2.0,
+                    0.01,
+                );
+                params_changed |= tuning_slider_row(
+                    ui,
+                    "rear_hardpoint_x_m",
+                    &mut params.rear_hardpoint_x_m,
+                    -4.0..=-0.1,
+                    0.01,
+                );
+                params_changed |= tuning_slider_row(
+                    ui,
+                    "rear_hardpoint_y_m",
+                    &mut params.rear_hardpoint_y_m,
+                    -2.0..=2.0,
+                    0.01,
+                );
+            });
+
             ui.collapsing("Suspension + Tire Contact", |ui| {
                 params_changed |= tuning_slider_row(
                     ui,
@@ -1001,7 +1141,7 @@ fn vehicle_tuning_panel_ui(
     debug_guards.player_invulnerable = panel_state.visible;
 
     if reload_clicked {
-        match sync_panel_state_from_config(&mut panel_state, &config) {
+        match sync_panel_state_from_config(&mut panel_state, &config, player_vehicle_id) {
             Ok(()) => panel_state.status = "Reloaded values from current config.".to_string(),
             Err(error) => panel_state.status = error,
         }
@@ -1030,12 +1170,15 @@ fn vehicle_tuning_panel_ui(
         ) {
             Ok(message) => {
                 panel_state.status = message;
-                if let Err(error) = sync_panel_state_from_config(&mut panel_state, &config) {
+                if let Err(error) =
+                    sync_panel_state_from_config(&mut panel_state, &config, player_vehicle_id)
+                {
                     panel_state.status = error;
                 }
             }
             Err(error) => panel_state.status = error,
         }
+        reloaded_events.write(ConfigReloadedEvent);
     }
 }
 
@@ -1044,6 +1187,7 @@ fn background_tuning_panel_ui(
     mut panel_state: ResMut<BackgroundTuningPanelState>,
     mut config: ResMut<GameConfig>,
     run_stats: Res<DebugRunStats>,
+    mut reloaded_events: MessageWriter<ConfigReloadedEvent>,
 ) {
     if !panel_state.visible {
         return;
@@ -1228,6 +1372,7 @@ fn background_tuning_panel_ui(
             }
             Err(error) => panel_state.status = error,
         }
+        reloaded_events.write(ConfigReloadedEvent);
     }
 }
 
@@ -1235,6 +1380,7 @@ fn audio_tuning_panel_ui(
     mut egui_contexts: EguiContexts,
     mut panel_state: ResMut<AudioTuningPanelState>,
     mut config: ResMut<GameConfig>,
+    mut reloaded_events: MessageWriter<ConfigReloadedEvent>,
 ) {
     if !panel_state.visible {
         return;
@@ -1331,6 +1477,13 @@ fn audio_tuning_panel_ui(
                 0.0..=2.0,
                 0.01,
             );
+            params_changed |= tuning_slider_row(
+                ui,
+                "nitro_boost_volume",
+                &mut params.nitro_boost_volume,
+                0.0..=2.0,
+                0.01,
+            );
 
             ui.separator();
             ui.horizontal(|ui| {
@@ -1378,6 +1531,48 @@ fn audio_tuning_panel_ui(
             }
             Err(error) => panel_state.status = error,
         }
+        reloaded_events.write(ConfigReloadedEvent);
+    }
+}
+
+fn config_reload_failure_ui(
+    mut commands: Commands,
+    mut egui_contexts: EguiContexts,
+    failure: Res<ConfigReloadFailure>,
+) {
+    let Ok(ctx) = egui_contexts.ctx_mut() else {
+        return;
+    };
+
+    let mut window_open = true;
+    egui::Window::new("Config Reload Failed")
+        .open(&mut window_open)
+        .resizable(true)
+        .default_width(720.0)
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "{}. The previous config is still active; fix the files and press F5 again.",
+                failure.summary
+            ));
+            ui.separator();
+            egui::ScrollArea::vertical()
+                .max_height(420.0)
+                .show(ui, |ui| {
+                    egui::Grid::new("config_reload_issues")
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for issue in &failure.issues {
+                                ui.monospace(issue.location());
+                                ui.monospace(&issue.key_path);
+                                ui.label(&issue.message);
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+
+    if !window_open {
+        commands.remove_resource::<ConfigReloadFailure>();
     }
 }
 
@@ -1404,15 +1599,15 @@ fn tuning_slider_row(
 fn sync_panel_state_from_config(
     panel_state: &mut VehicleTuningPanelState,
     config: &GameConfig,
+    vehicle_id: &str,
 ) -> Result<(), String> {
-    let vehicle_id = config.game.app.default_vehicle.clone();
-    let Some(vehicle) = config.vehicles_by_id.get(&vehicle_id) else {
+    let Some(vehicle) = config.vehicles_by_id.get(vehicle_id) else {
         return Err(format!(
-            "Vehicle tuning panel: default vehicle `{vehicle_id}` not found in config."
+            "Vehicle tuning panel: player vehicle `{vehicle_id}` not found in config."
         ));
     };
 
-    panel_state.source_vehicle_id = vehicle_id;
+    panel_state.source_vehicle_id = vehicle_id.to_string();
     panel_state.params = Some(VehicleTuningParams::from_vehicle(vehicle));
     Ok(())
 }
@@ -1487,6 +1682,7 @@ fn apply_audio_tuning_to_runtime_config(
         ("missile_launch_volume", params.missile_launch_volume),
         ("missile_hit_volume", params.missile_hit_volume),
         ("explode_volume", params.explode_volume),
+        ("nitro_boost_volume", params.nitro_boost_volume),
     ] {
         if !value.is_finite() {
             return Err(format!("Audio tuning panel: `{label}` must be finite."));
@@ -1602,27 +1798,37 @@ fn persist_audio_tuning_and_reload(
     let game_path = Path::new("config").join("game.toml");
     let original_game_raw = fs::read_to_string(&game_path)
         .map_err(|error| format!("Failed reading `{}`: {error}", game_path.display()))?;
-    let mut game_root: toml::Value = toml::from_str(&original_game_raw)
+    let mut game_root: DocumentMut = original_game_raw
+        .parse()
         .map_err(|error| format!("Failed parsing `{}`: {error}", game_path.display()))?;
 
     write_sfx_params_to_game_toml_value(&mut game_root, params)?;
 
-    let updated_game_raw = toml::to_string_pretty(&game_root)
-        .map_err(|error| format!("Failed serializing game TOML: {error}"))?;
+    let updated_game_raw = game_root.to_string();
+    let warning = profile_shadow_note(
+        config,
+        "game.toml",
+        &original_game_raw,
+        &updated_game_raw,
+        "sfx",
+        None,
+    );
     fs::write(&game_path, updated_game_raw)
         .map_err(|error| format!("Failed writing `{}`: {error}", game_path.display()))?;
 
-    match GameConfig::load_from_dir(Path::new("config")) {
+    match GameConfig::load_with_profiles(Path::new("config"), &config.profiles) {
         Ok(new_config) => {
             *config = new_config;
             Ok(format!(
-                "Applied audio tuning and saved to {}.",
+                "Applied audio tuning and saved to {}.{warning}",
                 game_path.to_string_lossy()
             ))
         }
         Err(error) => {
             let _ = fs::write(&game_path, original_game_raw);
-            if let Ok(restored) = GameConfig::load_from_dir(Path::new("config")) {
+            if let Ok(restored) =
+                GameConfig::load_with_profiles(Path::new("config"), &config.profiles)
+            {
                 *config = restored;
             }
             Err(format!(
@@ -1641,27 +1847,37 @@ fn persist_vehicle_tuning_and_reload(
     let path = Path::new("config").join("vehicles.toml");
     let original_raw = fs::read_to_string(&path)
         .map_err(|error| format!("Failed reading `{}`: {error}", path.display()))?;
-    let mut root: toml::Value = toml::from_str(&original_raw)
+    let mut root: DocumentMut = original_raw
+        .parse()
         .map_err(|error| format!("Failed parsing `{}`: {error}", path.display()))?;
 
-    write_params_to_toml_value(&mut root, vehicle_id, params)?;
-
-    let updated_raw = toml::to_string_pretty(&root)
-        .map_err(|error| format!("Failed serializing vehicles TOML: {error}"))?;
+    write_params_to_toml_value(&mut root, vehicle_id, params, &config.vehicles_by_id)?;
+
+    let updated_raw = root.to_string();
+    let warning = profile_shadow_note(
+        config,
+        "vehicles.toml",
+        &original_raw,
+        &updated_raw,
+        "vehicles",
+        Some(vehicle_id),
+    );
     fs::write(&path, updated_raw)
         .map_err(|error| format!("Failed writing `{}`: {error}", path.display()))?;
 
-    match GameConfig::load_from_dir(Path::new("config")) {
+    match GameConfig::load_with_profiles(Path::new("config"), &config.profiles) {
         Ok(new_config) => {
             *config = new_config;
             Ok(format!(
-                "Applied tuning and saved to {}.",
+                "Applied tuning and saved to {}.{warning}",
                 path.to_string_lossy()
             ))
         }
         Err(error) => {
             let _ = fs::write(&path, original_raw);
-            if let Ok(restored) = GameConfig::load_from_dir(Path::new("config")) {
+            if let Ok(restored) =
+                GameConfig::load_with_profiles(Path::new("config"), &config.profiles)
+            {
                 *config = restored;
             }
             Err(format!(
@@ -1685,29 +1901,48 @@ fn persist_background_and_terrain_tuning_and_reload(
     let original_game_raw = fs::read_to_string(&game_path)
         .map_err(|error| format!("Failed reading `{}`: {error}", game_path.display()))?;
 
-    let mut backgrounds_root: toml::Value = toml::from_str(&original_backgrounds_raw)
+    let mut backgrounds_root: DocumentMut = original_backgrounds_raw
+        .parse()
         .map_err(|error| format!("Failed parsing `{}`: {error}", backgrounds_path.display()))?;
-    let mut game_root: toml::Value = toml::from_str(&original_game_raw)
+    let mut game_root: DocumentMut = original_game_raw
+        .parse()
         .map_err(|error| format!("Failed parsing `{}`: {error}", game_path.display()))?;
 
     write_background_params_to_toml_value(&mut backgrounds_root, background_id, params)?;
     write_terrain_params_to_game_toml_value(&mut game_root, params)?;
 
-    let updated_backgrounds_raw = toml::to_string_pretty(&backgrounds_root)
-        .map_err(|error| format!("Failed serializing backgrounds TOML: {error}"))?;
-    let updated_game_raw = toml::to_string_pretty(&game_root)
-        .map_err(|error| format!("Failed serializing game TOML: {error}"))?;
+    let updated_backgrounds_raw = backgrounds_root.to_string();
+    let updated_game_raw = game_root.to_string();
+    let warning = [
+        profile_shadow_note(
+            config,
+            "backgrounds.toml",
+            &original_backgrounds_raw,
+            &updated_backgrounds_raw,
+            "backgrounds",
+            Some(background_id),
+        ),
+        profile_shadow_note(
+            config,
+            "game.toml",
+            &original_game_raw,
+            &updated_game_raw,
+            "terrain",
+            None,
+        ),
+    ]
+    .concat();
 
     fs::write(&backgrounds_path, updated_backgrounds_raw)
         .map_err(|error| format!("Failed writing `{}`: {error}", backgrounds_path.display()))?;
     fs::write(&game_path, updated_game_raw)
         .map_err(|error| format!("Failed writing `{}`: {error}", game_path.display()))?;
 
-    match GameConfig::load_from_dir(Path::new("config")) {
+    match GameConfig::load_with_profiles(Path::new("config"), &config.profiles) {
         Ok(new_config) => {
             *config = new_config;
             Ok(format!(
-                "Applied tuning and saved to {} and {}.",
+                "Applied tuning and saved to {} and {}.{warning}",
                 backgrounds_path.to_string_lossy(),
                 game_path.to_string_lossy()
             ))
@@ -1715,7 +1950,9 @@ fn persist_background_and_terrain_tuning_and_reload(
         Err(error) => {
             let _ = fs::write(&backgrounds_path, original_backgrounds_raw);
             let _ = fs::write(&game_path, original_game_raw);
-            if let Ok(restored) = GameConfig::load_from_dir(Path::new("config")) {
+            if let Ok(restored) =
+                GameConfig::load_with_profiles(Path::new("config"), &config.profiles)
+            {
                 *config = restored;
             }
             Err(format!(
@@ -1728,158 +1965,108 @@ fn persist_background_and_terrain_tuning_and_reload(
 }
 
 fn write_params_to_toml_value(
-    root: &mut toml::Value,
+    root: &mut DocumentMut,
     vehicle_id: &str,
     params: &VehicleTuningParams,
+    vehicles_by_id: &HashMap<String, VehicleConfig>,
 ) -> Result<(), String> {
-    let Some(vehicles_array) = root.get_mut("vehicles").and_then(toml::Value::as_array_mut) else {
+    let Some(vehicles_array) = root
+        .get_mut("vehicles")
+        .and_then(Item::as_array_of_tables_mut)
+    else {
         return Err("vehicles.toml: missing or invalid `vehicles` array".to_string());
     };
 
-    let Some(vehicle_table) = vehicles_array.iter_mut().find_map(|vehicle_value| {
-        let table = vehicle_value.as_table_mut()?;
-        if table.get("id").and_then(toml::Value::as_str) == Some(vehicle_id) {
-            Some(table)
-        } else {
-            None
-        }
-    }) else {
+    let Some(vehicle_table) = vehicles_array
+        .iter_mut()
+        .find(|table| table.get("id").and_then(Item::as_str) == Some(vehicle_id))
+    else {
         return Err(format!(
             "vehicles.toml: could not find vehicle with id `{vehicle_id}`"
         ));
     };
 
-    set_toml_float(vehicle_table, "health", params.health)?;
-    set_toml_float(vehicle_table, "acceleration", params.acceleration)?;
-    set_toml_float(vehicle_table, "brake_strength", params.brake_strength)?;
-    set_toml_float(vehicle_table, "air_pitch_torque", params.air_pitch_torque)?;
-    set_toml_float(
-        vehicle_table,
-        "air_max_rotation_speed",
-        params.air_max_rotation_speed,
-    )?;
-    set_toml_float(vehicle_table, "max_forward_speed", params.max_forward_speed)?;
-    set_toml_float(vehicle_table, "max_reverse_speed", params.max_reverse_speed)?;
-    set_toml_float(vehicle_table, "max_fall_speed", params.max_fall_speed)?;
-    set_toml_float(
-        vehicle_table,
-        "linear_speed_scale",
-        params.linear_speed_scale,
-    )?;
-    set_toml_float(
-        vehicle_table,
-        "ground_coast_damping",
-        params.ground_coast_damping,
+    let inherited = inherited_row_values(vehicle_table, vehicles_by_id)?;
+    let mut set_float =
+        |key: &str, value: f32| set_row_float(vehicle_table, inherited.as_ref(), key, value);
+    set_float("health", params.health)?;
+    set_float("acceleration", params.acceleration)?;
+    set_float("brake_strength", params.brake_strength)?;
+    set_float("air_pitch_torque", params.air_pitch_torque)?;
+    set_float("air_max_rotation_speed", params.air_max_rotation_speed)?;
+    set_float("max_forward_speed", params.max_forward_speed)?;
+    set_float("max_reverse_speed", params.max_reverse_speed)?;
+    set_float("max_fall_speed", params.max_fall_speed)?;
+    set_float("linear_speed_scale", params.linear_speed_scale)?;
+    set_float("ground_coast_damping", params.ground_coast_damping)?;
+    set_float("air_base_damping", params.air_base_damping)?;
+    set_float("air_env_drag_factor", params.air_env_drag_factor)?;
+    set_float("linear_inertia", params.linear_inertia)?;
+    set_float("rotational_inertia", params.rotational_inertia)?;
+    set_float("gravity_scale", params.gravity_scale)?;
+    set_float("chassis_length_m", params.chassis_length_m)?;
+    set_float("chassis_height_m", params.chassis_height_m)?;
+    set_float("chassis_mass_kg", params.chassis_mass_kg)?;
+    set_float(
+        "chassis_center_of_mass_y_m",
+        params.chassis_center_of_mass_y_m,
     )?;
-    set_toml_float(vehicle_table, "air_base_damping", params.air_base_damping)?;
-    set_toml_float(
-        vehicle_table,
-        "air_env_drag_factor",
-        params.air_env_drag_factor,
-    )?;
-    set_toml_float(vehicle_table, "linear_inertia", params.linear_inertia)?;
-    set_toml_float(
-        vehicle_table,
-        "rotational_inertia",
-        params.rotational_inertia,
-    )?;
-    set_toml_float(vehicle_table, "gravity_scale", params.gravity_scale)?;
-    set_toml_float(
-        vehicle_table,
-        "suspension_rest_length_m",
-        params.suspension_rest_length_m,
-    )?;
-    set_toml_float(
-        vehicle_table,
-        "suspension_stiffness",
-        params.suspension_stiffness,
-    )?;
-    set_toml_float(
-        vehicle_table,
-        "suspension_damping",
-        params.suspension_damping,
-    )?;
-    set_toml_float(
-        vehicle_table,
+    set_float("wheel_radius_m", params.wheel_radius_m)?;
+    set_float("front_hardpoint_x_m", params.front_hardpoint_x_m)?;
+    set_float("front_hardpoint_y_m", params.front_hardpoint_y_m)?;
+    set_float("rear_hardpoint_x_m", params.rear_hardpoint_x_m)?;
+    set_float("rear_hardpoint_y_m", params.rear_hardpoint_y_m)?;
+    set_float("suspension_rest_length_m", params.suspension_rest_length_m)?;
+    set_float("suspension_stiffness", params.suspension_stiffness)?;
+    set_float("suspension_damping", params.suspension_damping)?;
+    set_float(
         "suspension_max_compression_m",
         params.suspension_max_compression_m,
     )?;
-    set_toml_float(
-        vehicle_table,
+    set_float(
         "suspension_max_extension_m",
         params.suspension_max_extension_m,
     )?;
-    set_toml_float(
-        vehicle_table,
-        "tire_longitudinal_grip",
-        params.tire_longitudinal_grip,
-    )?;
-    set_toml_float(
-        vehicle_table,
-        "tire_slip_grip_floor",
-        params.tire_slip_grip_floor,
-    )?;
-    set_toml_float(vehicle_table, "front_drive_ratio", params.front_drive_ratio)?;
-    set_toml_float(
-        vehicle_table,
+    set_float("tire_longitudinal_grip", params.tire_longitudinal_grip)?;
+    set_float("tire_slip_grip_floor", params.tire_slip_grip_floor)?;
+    set_float("front_drive_ratio", params.front_drive_ratio)?;
+    set_float(
         "rear_drive_traction_assist_distance_m",
         params.rear_drive_traction_assist_distance_m,
     )?;
-    set_toml_float(
-        vehicle_table,
+    set_float(
         "rear_drive_traction_assist_min_factor",
         params.rear_drive_traction_assist_min_factor,
     )?;
-    set_toml_float(vehicle_table, "turret_range_m", params.turret_range_m)?;
-    set_toml_float(
-        vehicle_table,
-        "turret_cone_degrees",
-        params.turret_cone_degrees,
-    )?;
-    set_toml_float(
-        vehicle_table,
+    set_float("turret_range_m", params.turret_range_m)?;
+    set_float("turret_cone_degrees", params.turret_cone_degrees)?;
+    set_float(
         "missile_fire_interval_seconds",
         params.missile_fire_interval_seconds,
     )?;
-    set_toml_float(
-        vehicle_table,
-        "camera_look_ahead_factor",
-        params.camera_look_ahead_factor,
-    )?;
-    set_toml_float(
-        vehicle_table,
-        "camera_look_ahead_min",
-        params.camera_look_ahead_min,
-    )?;
-    set_toml_float(
-        vehicle_table,
-        "camera_look_ahead_max",
-        params.camera_look_ahead_max,
-    )?;
+    set_float("camera_look_ahead_factor", params.camera_look_ahead_factor)?;
+    set_float("camera_look_ahead_min", params.camera_look_ahead_min)?;
+    set_float("camera_look_ahead_max", params.camera_look_ahead_max)?;
 
     Ok(())
 }
 
 fn write_background_params_to_toml_value(
-    root: &mut toml::Value,
+    root: &mut DocumentMut,
     background_id: &str,
     params: &BackgroundTuningParams,
 ) -> Result<(), String> {
     let Some(backgrounds_array) = root
         .get_mut("backgrounds")
-        .and_then(toml::Value::as_array_mut)
+        .and_then(Item::as_array_of_tables_mut)
     else {
         return Err("backgrounds.toml: missing or invalid `backgrounds` array".to_string());
     };
 
-    let Some(background_table) = backgrounds_array.iter_mut().find_map(|background_value| {
-        let table = background_value.as_table_mut()?;
-        if table.get("id").and_then(toml::Value::as_str) == Some(background_id) {
-            Some(table)
-        } else {
-            None
-        }
-    }) else {
+    let Some(background_table) = backgrounds_array
+        .iter_mut()
+        .find(|table| table.get("id").and_then(Item::as_str) == Some(background_id))
+    else {
         return Err(format!(
             "backgrounds.toml: could not find background with id `{background_id}`"
         ));
@@ -1928,10 +2115,10 @@ fn write_background_params_to_toml_value(
 }
 
 fn write_terrain_params_to_game_toml_value(
-    root: &mut toml::Value,
+    root: &mut DocumentMut,
     params: &BackgroundTuningParams,
 ) -> Result<(), String> {
-    let Some(terrain_table) = root.get_mut("terrain").and_then(toml::Value::as_table_mut) else {
+    let Some(terrain_table) = root.get_mut("terrain").and_then(Item::as_table_mut) else {
         return Err("game.toml: missing or invalid `terrain` table".to_string());
     };
 
@@ -1940,10 +2127,10 @@ fn write_terrain_params_to_game_toml_value(
 }
 
 fn write_sfx_params_to_game_toml_value(
-    root: &mut toml::Value,
+    root: &mut DocumentMut,
     params: &AudioTuningParams,
 ) -> Result<(), String> {
-    let Some(sfx_table) = root.get_mut("sfx").and_then(toml::Value::as_table_mut) else {
+    let Some(sfx_table) = root.get_mut("sfx").and_then(Item::as_table_mut) else {
         return Err("game.toml: missing or invalid `sfx` table".to_string());
     };
 
@@ -1960,22 +2147,127 @@ fn write_sfx_params_to_game_toml_value(
     )?;
     set_toml_float(sfx_table, "missile_hit_volume", params.missile_hit_volume)?;
     set_toml_float(sfx_table, "explode_volume", params.explode_volume)?;
+    set_toml_float(sfx_table, "nitro_boost_volume", params.nitro_boost_volume)?;
     Ok(())
 }
 
-fn set_toml_float(
-    table: &mut toml::map::Map<String, toml::Value>,
-    key: &str,
-    value: f32,
-) -> Result<(), String> {
+// Keys whose stored value already matches are left untouched; changed ones are
+// replaced in place so their position and trailing comment survive, and are
+// written in the f32's short form (`0.97`, not `0.9700000286102295`).
+fn set_toml_float(table: &mut Table, key: &str, value: f32) -> Result<(), String> {
     if !value.is_finite() {
         return Err(format!("`{key}` is not a finite number"));
     }
 
-    table.insert(key.to_string(), toml::Value::Float(value as f64));
+    let stored = table
+        .get(key)
+        .and_then(Item::as_value)
+        .and_then(|existing| {
+            existing
+                .as_float()
+                .or_else(|| existing.as_integer().map(|integer| integer as f64))
+        });
+    if stored.is_some_and(|stored| stored as f32 == value) {
+        return Ok(());
+    }
+    replace_toml_value(table, key, f32_to_short_f64(value).into());
     Ok(())
 }
 
+// Keys this save changed that an active profile still overrides, as a
+// sentence to append to the panel status (empty when nothing is shadowed).
+fn profile_shadow_note(
+    config: &GameConfig,
+    file: &str,
+    before_raw: &str,
+    after_raw: &str,
+    table_key: &str,
+    row_id: Option<&str>,
+) -> String {
+    let scope = |raw: &str| {
+        let root = toml::from_str::<toml::Value>(raw).ok()?;
+        let scope = match row_id {
+            Some(row_id) => root
+                .get(table_key)?
+                .as_array()?
+                .iter()
+                .find(|row| row.get("id").and_then(toml::Value::as_str) == Some(row_id))?,
+            None => root.get(table_key)?,
+        };
+        scope.as_table().cloned()
+    };
+    let (before, after) = (scope(before_raw).unwrap_or_default(), scope(after_raw));
+    let changed: Vec<&str> = after
+        .iter()
+        .flatten()
+        .filter(|(key, value)| before.get(*key) != Some(*value))
+        .map(|(key, _)| key.as_str())
+        .collect();
+    profile_override_warning(
+        Path::new("config"),
+        &config.profiles,
+        file,
+        table_key,
+        row_id,
+        &changed,
+    )
+    .map(|warning| format!(" {warning}"))
+    .unwrap_or_default()
+}
+
+// Rows that `extends` a parent only store their overrides, so a key the row
+// does not already set is skipped while it still matches the resolved parent.
+fn inherited_row_values<T: Serialize>(
+    table: &Table,
+    rows_by_id: &HashMap<String, T>,
+) -> Result<Option<toml::Table>, String> {
+    let Some(parent_id) = table.get("extends").and_then(Item::as_str) else {
+        return Ok(None);
+    };
+    let Some(parent) = rows_by_id.get(parent_id) else {
+        return Err(format!("`extends` references unknown id `{parent_id}`"));
+    };
+    match toml::Value::try_from(parent) {
+        Ok(toml::Value::Table(values)) => Ok(Some(values)),
+        Ok(_) => Err(format!("`{parent_id}` did not serialize to a table")),
+        Err(error) => Err(format!("Failed serializing `{parent_id}`: {error}")),
+    }
+}
+
+fn set_row_float(
+    table: &mut Table,
+    inherited: Option<&toml::Table>,
+    key: &str,
+    value: f32,
+) -> Result<(), String> {
+    if !table.contains_key(key) {
+        let inherited_value = inherited
+            .and_then(|values| values.get(key))
+            .and_then(|value| {
+                value
+                    .as_float()
+                    .or_else(|| value.as_integer().map(|integer| integer as f64))
+            });
+        if inherited_value.is_some_and(|inherited_value| inherited_value as f32 == value) {
+            return Ok(());
+        }
+    }
+    set_toml_float(table, key, value)
+}
+
+fn replace_toml_value(table: &mut Table, key: &str, value: toml_edit::Value) {
+    match table.get_mut(key).and_then(Item::as_value_mut) {
+        Some(existing) => {
+            let decor = existing.decor().clone();
+            *existing = value;
+            *existing.decor_mut() = decor;
+        }
+        None => {
+            table.insert(key, toml_edit::value(value));
+        }
+    }
+}
+
 fn keybind_overlay_text() -> &'static str {
     "Keybinds\n\
 H - Toggle this panel\n\
@@ -1983,9 +2275,13 @@ O - Toggle debug text overlays\n\
 V - Toggle vehicle tuning panel\n\
 B - Toggle background tuning panel\n\
 M - Toggle audio tuning panel\n\
+E - Toggle config table editor\n\
 I / P - Pan camera left / right\n\
 Tab - Debug warp to next segment\n\
-A/D or Left/Right - Choose upgrade option (when shown)\n\
+A / Left (brake) - Next upgrade option (when shown)\n\
+D / Right (accelerate) - Confirm upgrade option\n\
+U - Log run upgrade modifier stack\n\
+Backspace - Revert last run upgrade\n\
 F5 - Hot-reload config\n\
 D / Right - Accelerate\n\
 A / Left - Brake / reverse\n\
@@ -1999,3 +2295,37 @@ Enter - Pause -> results\n\
 Space - Results -> new run\n\
 Q - Quit from results"
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tuning_save_keeps_extends_rows_to_their_overrides() {
+        let config =
+            GameConfig::load_from_dir(Path::new("config")).expect("repo config should load");
+        let raw = fs::read_to_string("config/vehicles.toml").expect("vehicles.toml should read");
+        let mut root: DocumentMut = raw.parse().expect("vehicles.toml should parse");
+        let mut params = VehicleTuningParams::from_vehicle(&config.vehicles_by_id["light_buggy"]);
+
+        write_params_to_toml_value(&mut root, "light_buggy", &params, &config.vehicles_by_id)
+            .expect("unchanged params should write");
+        assert_eq!(root.to_string(), raw);
+
+        params.health += 10.0;
+        params.wheel_radius_m += 0.1;
+        write_params_to_toml_value(&mut root, "light_buggy", &params, &config.vehicles_by_id)
+            .expect("edited params should write");
+        let buggy = root["vehicles"]
+            .as_array_of_tables()
+            .and_then(|rows| {
+                rows.iter()
+                    .find(|row| row.get("id").and_then(Item::as_str) == Some("light_buggy"))
+            })
+            .expect("light_buggy row should exist");
+        assert_eq!(buggy["health"].as_float(), Some(150.0));
+        assert!(buggy.contains_key("wheel_radius_m"));
+        assert!(!buggy.contains_key("max_reverse_speed"));
+        assert!(!buggy.contains_key("turret_cone_degrees"));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::config::GameConfig;
+use crate::gameplay::fixed_step::GameplaySimSet;
+use crate::gameplay::vehicle::{
+    PlayerVehicle, VehicleInputSet, VehicleInputState, VehicleTelemetry,
+};
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+const AUTOPILOT_LOOK_AHEAD_M: f32 = 2.5;
+const AUTOPILOT_LANDING_LOOK_AHEAD_S: f32 = 0.35;
+const AUTOPILOT_AIR_PITCH_DEADBAND_RAD: f32 = 0.12;
+const AUTOPILOT_WHEELIE_RELEASE_RAD: f32 = 0.45;
+const AUTOPILOT_WHEELIE_BRAKE_RAD: f32 = 0.8;
+const AUTOPILOT_DOWNHILL_SLOPE_RAD: f32 = -0.35;
+const AUTOPILOT_DOWNHILL_MAX_SPEED_MPS: f32 = 18.0;
+
+pub struct AutopilotPlugin;
+
+impl Plugin for AutopilotPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            drive_autopilot
+                .in_set(GameplaySimSet::Input)
+                .after(VehicleInputSet)
+                .run_if(resource_exists::<Autopilot>),
+        );
+    }
+}
+
+// Present only for bot-driven runs; overrides whatever input the vehicle read this tick.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct Autopilot;
+
+fn drive_autopilot(
+    config: Res<GameConfig>,
+    telemetry: Res<VehicleTelemetry>,
+    player_query: Query<(&Transform, &Velocity), With<PlayerVehicle>>,
+    mut input_state: ResMut<VehicleInputState>,
+) {
+    let Ok((transform, velocity)) = player_query.single() else {
+        return;
+    };
+
+    let x = transform.translation.x;
+    let chassis_angle_rad = transform.rotation.to_euler(EulerRot::XYZ).2;
+
+    *input_state = if telemetry.grounded {
+        let slope_rad = terrain_angle_at_x(&config, x + AUTOPILOT_LOOK_AHEAD_M);
+        let pitch_rad = wrap_angle_rad(chassis_angle_rad - terrain_angle_at_x(&config, x));
+        let too_fast_downhill = slope_rad < AUTOPILOT_DOWNHILL_SLOPE_RAD
+            && telemetry.speed_mps > AUTOPILOT_DOWNHILL_MAX_SPEED_MPS;
+        VehicleInputState {
+            accelerate: pitch_rad < AUTOPILOT_WHEELIE_RELEASE_RAD && !too_fast_downhill,
+            brake: pitch_rad > AUTOPILOT_WHEELIE_BRAKE_RAD || too_fast_downhill,
+        }
+    } else {
+        // In the air throttle/brake rotate the chassis; line it up with the ground ahead.
+        let landing_x = x + velocity.linvel.x.max(0.0) * AUTOPILOT_LANDING_LOOK_AHEAD_S;
+        let pitch_error_rad =
+            wrap_angle_rad(terrain_angle_at_x(&config, landing_x) - chassis_angle_rad);
+        VehicleInputState {
+            accelerate: pitch_error_rad > AUTOPILOT_AIR_PITCH_DEADBAND_RAD,
+            brake: pitch_error_rad < -AUTOPILOT_AIR_PITCH_DEADBAND_RAD,
+        }
+    };
+}
+
+fn terrain_angle_at_x(config: &GameConfig, x: f32) -> f32 {
+    let tangent = config.terrain_tangent_at_x(x);
+    tangent.y.atan2(tangent.x)
+}
+
+fn wrap_angle_rad(angle_rad: f32) -> f32 {
+    (angle_rad + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU) - std::f32::consts::PI
+}
@@ -1,9 +1,11 @@
-use crate::config::GameConfig;
+use crate::config::{GameConfig, ProjectileKind, RunStats, TurretTargetPriority};
 use crate::gameplay::enemies::{
     enemy_hit_flash_duration_seconds, Enemy, EnemyHealth, EnemyHitFlash, EnemyHitbox, EnemyTypeId,
 };
+use crate::gameplay::fixed_step::{FixedStepInterpolation, GameplaySimSet};
 use crate::gameplay::vehicle::PlayerVehicle;
-use crate::states::GameState;
+use crate::rng::{RngStream, RunRng};
+use crate::states::ActiveRun;
 use crate::web::max_player_projectiles_for_platform;
 use bevy::prelude::*;
 use std::collections::HashSet;
@@ -77,23 +79,30 @@ impl Plugin for CombatGameplayPlugin {
             .add_message::<PlayerWeaponFiredEvent>()
             .add_message::<PlayerProjectileImpactEvent>()
             .add_systems(
-                OnEnter(GameState::InRun),
+                OnEnter(ActiveRun),
                 (reset_turret_targeting_state, reset_turret_fire_state),
             )
-            .add_systems(OnExit(GameState::InRun), cleanup_combat_entities)
+            .add_systems(OnExit(ActiveRun), cleanup_combat_entities)
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
-                    spawn_turret_visuals,
                     update_turret_targeting_state,
                     fire_turret_projectiles,
-                    sync_turret_targeting_visuals,
                     simulate_player_projectiles,
                     resolve_player_projectile_enemy_hits,
+                )
+                    .chain()
+                    .in_set(GameplaySimSet::Combat),
+            )
+            .add_systems(
+                Update,
+                (
+                    spawn_turret_visuals,
+                    sync_turret_targeting_visuals,
                     update_fade_out_fx,
                 )
                     .chain()
-                    .run_if(in_state(GameState::InRun))
+                    .run_if(in_state(ActiveRun))
                     .run_if(resource_exists::<GameConfig>),
             );
     }
@@ -111,8 +120,9 @@ struct TurretConeBoundaryVisual {
 }
 
 #[derive(Component, Debug, Clone, Copy)]
+#[require(FixedStepInterpolation)]
 struct PlayerProjectile {
-    kind: PlayerProjectileKind,
+    kind: ProjectileKind,
     damage: f32,
     velocity_mps: Vec2,
     drag: f32,
@@ -122,12 +132,6 @@ struct PlayerProjectile {
     target_entity: Option<Entity>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum PlayerProjectileKind {
-    Bullet,
-    Missile,
-}
-
 #[derive(Component, Debug, Clone, Copy)]
 struct FadeOutFx {
     remaining_s: f32,
@@ -158,31 +162,12 @@ impl Default for TurretTargetingState {
     }
 }
 
-#[derive(Resource, Debug, Clone)]
+#[derive(Resource, Debug, Clone, Default)]
 struct TurretFireState {
     next_fire_time_s: f64,
     next_missile_fire_time_s: f64,
     burst_shots_remaining: u32,
     next_burst_shot_time_s: f64,
-    rng_state: u64,
-}
-
-impl Default for TurretFireState {
-    fn default() -> Self {
-        Self {
-            next_fire_time_s: 0.0,
-            next_missile_fire_time_s: 0.0,
-            burst_shots_remaining: 0,
-            next_burst_shot_time_s: 0.0,
-            rng_state: 0xA77C_C1B5_D7E3_42FD,
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum TurretTargetPriority {
-    Nearest,
-    Strongest,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -271,7 +256,7 @@ fn spawn_turret_visuals(
 }
 
 fn update_turret_targeting_state(
-    config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     player_query: Query<&Transform, With<PlayerVehicle>>,
     enemy_query: Query<(Entity, &Transform, &EnemyHealth), With<Enemy>>,
     mut targeting: ResMut<TurretTargetingState>,
@@ -280,11 +265,11 @@ fn update_turret_targeting_state(
         return;
     };
 
-    let Some(vehicle_config) = config.vehicles_by_id.get(&config.game.app.default_vehicle) else {
+    let Some(vehicle_config) = run_stats.player_vehicle() else {
         return;
     };
 
-    let target_priority = parse_target_priority(&vehicle_config.turret_target_priority);
+    let target_priority = vehicle_config.turret_target_priority;
     let range_m = vehicle_config.turret_range_m.max(0.1);
     let cone_half_angle_rad =
         (vehicle_config.turret_cone_degrees.to_radians() * 0.5).clamp(0.001, PI);
@@ -346,14 +331,17 @@ fn update_turret_targeting_state(
     targeting.cone_half_angle_rad = cone_half_angle_rad;
 }
 
+#[allow(clippy::too_many_arguments)]
 fn fire_turret_projectiles(
     mut commands: Commands,
     time: Res<Time>,
     config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     targeting: Res<TurretTargetingState>,
     player_query: Query<&Transform, With<PlayerVehicle>>,
     projectile_query: Query<(), With<PlayerProjectile>>,
     mut fire_state: ResMut<TurretFireState>,
+    mut rng: ResMut<RunRng>,
     mut fired_events: MessageWriter<PlayerWeaponFiredEvent>,
 ) {
     if targeting.target_entity.is_none() {
@@ -365,11 +353,14 @@ fn fire_turret_projectiles(
         return;
     };
 
-    let Some(vehicle_config) = config.vehicles_by_id.get(&config.game.app.default_vehicle) else {
+    let Some(vehicle_config) = run_stats.player_vehicle() else {
         return;
     };
 
-    let Some(primary_weapon) = config.weapons_by_id.get(&vehicle_config.default_weapon_id) else {
+    let Some(primary_weapon) = run_stats
+        .weapons_by_id
+        .get(&vehicle_config.default_weapon_id)
+    else {
         return;
     };
 
@@ -409,7 +400,7 @@ fn fire_turret_projectiles(
         }
 
         let spread_angle_rad =
-            next_signed_unit_random(&mut fire_state.rng_state) * spread_half_angle_rad;
+            rng.stream(RngStream::WeaponSpread).signed_unit() * spread_half_angle_rad;
         let shot_direction_local =
             (Mat2::from_angle(spread_angle_rad) * base_direction_local).normalize_or_zero();
         let shot_direction_world = (player_rotation * shot_direction_local).normalize_or_zero();
@@ -438,7 +429,7 @@ fn fire_turret_projectiles(
             if live_projectile_count >= max_projectiles {
                 return;
             }
-            if let Some(secondary_weapon) = config.weapons_by_id.get(secondary_weapon_id) {
+            if let Some(secondary_weapon) = run_stats.weapons_by_id.get(secondary_weapon_id) {
                 let missile_direction_local =
                     Vec2::from_angle(targeting.cone_half_angle_rad).normalize_or_zero();
                 let missile_direction_world =
@@ -515,7 +506,7 @@ fn simulate_player_projectiles(
 
     let dt = time.delta_secs();
     for (entity, mut transform, mut projectile) in &mut projectile_query {
-        if projectile.kind == PlayerProjectileKind::Missile {
+        if projectile.kind == ProjectileKind::Missile {
             projectile.velocity_mps.y -= environment.gravity * projectile.gravity_scale * dt;
 
             if projectile.homing_turn_rate_rad_s > 0.0 {
@@ -559,7 +550,7 @@ fn simulate_player_projectiles(
                 target: PlayerProjectileImpactTarget::Ground,
                 world_position: impact_position,
             });
-            if projectile.kind == PlayerProjectileKind::Missile {
+            if projectile.kind == ProjectileKind::Missile {
                 spawn_explosion_fx(&mut commands, impact_position);
             }
             projectile.remaining_lifetime_s = -1.0;
@@ -591,7 +582,7 @@ fn resolve_player_projectile_enemy_hits(
     mut killed_message_writer: MessageWriter<EnemyKilledEvent>,
     mut impact_events: MessageWriter<PlayerProjectileImpactEvent>,
 ) {
-    let projectile_snapshots: Vec<(Entity, Vec2, f32, PlayerProjectileKind)> = projectile_query
+    let projectile_snapshots: Vec<(Entity, Vec2, f32, ProjectileKind)> = projectile_query
         .iter()
         .filter(|(_, _, projectile)| projectile.remaining_lifetime_s > 0.0)
         .map(|(entity, transform, projectile)| {
@@ -723,27 +714,6 @@ fn should_replace_target(
     }
 }
 
-fn parse_target_priority(raw: &str) -> TurretTargetPriority {
-    match raw {
-        "strongest" => TurretTargetPriority::Strongest,
-        _ => TurretTargetPriority::Nearest,
-    }
-}
-
-fn parse_projectile_kind(raw: &str) -> PlayerProjectileKind {
-    match raw {
-        "missile" => PlayerProjectileKind::Missile,
-        _ => PlayerProjectileKind::Bullet,
-    }
-}
-
-fn next_signed_unit_random(seed: &mut u64) -> f32 {
-    *seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
-
-    let value = ((*seed >> 32) as u32) as f32 / u32::MAX as f32;
-    (value * 2.0) - 1.0
-}
-
 fn shortest_angle_delta_rad(target: f32, current: f32) -> f32 {
     (target - current + PI).rem_euclid(TAU) - PI
 }
@@ -757,14 +727,14 @@ fn spawn_player_projectile(
     player_rotation: Mat2,
     target_entity: Option<Entity>,
 ) {
-    let projectile_kind = parse_projectile_kind(&weapon.projectile_type);
+    let projectile_kind = weapon.projectile_type;
     let (projectile_length, projectile_thickness, projectile_color) = match projectile_kind {
-        PlayerProjectileKind::Bullet => (
+        ProjectileKind::Bullet => (
             BULLET_LENGTH_M,
             BULLET_THICKNESS_M,
             Color::srgba(0.96, 0.92, 0.70, 0.92),
         ),
-        PlayerProjectileKind::Missile => (
+        ProjectileKind::Missile => (
             MISSILE_LENGTH_M,
             MISSILE_THICKNESS_M,
             Color::srgba(0.95, 0.58, 0.20, 0.95),
@@ -776,8 +746,8 @@ fn spawn_player_projectile(
     let projectile_center = muzzle_world + (shot_direction_world * (projectile_length * 0.5));
     let shot_angle_world = shot_direction_world.y.atan2(shot_direction_world.x);
     let muzzle_flash_size = match projectile_kind {
-        PlayerProjectileKind::Bullet => BULLET_MUZZLE_FLASH_SIZE_M,
-        PlayerProjectileKind::Missile => MISSILE_MUZZLE_FLASH_SIZE_M,
+        ProjectileKind::Bullet => BULLET_MUZZLE_FLASH_SIZE_M,
+        ProjectileKind::Missile => MISSILE_MUZZLE_FLASH_SIZE_M,
     };
     spawn_fade_out_fx(
         commands,
@@ -816,10 +786,8 @@ fn spawn_player_projectile(
 
     commands.entity(projectile_entity).with_children(|parent| {
         let (segment_count, segment_length_m) = match projectile_kind {
-            PlayerProjectileKind::Bullet => {
-                (BULLET_TRAIL_SEGMENT_COUNT, BULLET_TRAIL_SEGMENT_LENGTH_M)
-            }
-            PlayerProjectileKind::Missile => {
+            ProjectileKind::Bullet => (BULLET_TRAIL_SEGMENT_COUNT, BULLET_TRAIL_SEGMENT_LENGTH_M),
+            ProjectileKind::Missile => {
                 (MISSILE_TRAIL_SEGMENT_COUNT, MISSILE_TRAIL_SEGMENT_LENGTH_M)
             }
         };
@@ -846,17 +814,17 @@ fn spawn_player_projectile(
     });
 }
 
-fn projectile_kind_for_audio(kind: PlayerProjectileKind) -> PlayerProjectileAudioKind {
+fn projectile_kind_for_audio(kind: ProjectileKind) -> PlayerProjectileAudioKind {
     match kind {
-        PlayerProjectileKind::Bullet => PlayerProjectileAudioKind::Bullet,
-        PlayerProjectileKind::Missile => PlayerProjectileAudioKind::Missile,
+        ProjectileKind::Bullet => PlayerProjectileAudioKind::Bullet,
+        ProjectileKind::Missile => PlayerProjectileAudioKind::Missile,
     }
 }
 
-fn spawn_impact_fx(commands: &mut Commands, world_position: Vec2, kind: PlayerProjectileKind) {
+fn spawn_impact_fx(commands: &mut Commands, world_position: Vec2, kind: ProjectileKind) {
     let color = match kind {
-        PlayerProjectileKind::Bullet => Color::srgba(1.0, 0.96, 0.82, 0.88),
-        PlayerProjectileKind::Missile => Color::srgba(1.0, 0.74, 0.34, 0.9),
+        ProjectileKind::Bullet => Color::srgba(1.0, 0.96, 0.82, 0.88),
+        ProjectileKind::Missile => Color::srgba(1.0, 0.74, 0.34, 0.9),
     };
     spawn_fade_out_fx(
         commands,
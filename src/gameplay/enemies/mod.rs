@@ -1,9 +1,14 @@
 use crate::assets::{AssetRegistry, ModelAssetEntry};
-use crate::config::{EnemyTypeConfig, GameConfig, WeaponConfig};
+use crate::config::{
+    EnemyBehaviorKind, EnemyTypeConfig, GameConfig, ProjectileKind, RunStats, WeaponConfig,
+    SEGMENT_BOSS_ENEMY_ID,
+};
 use crate::debug::{DebugGameplayGuards, EnemyDebugMarker};
 use crate::gameplay::combat::EnemyKilledEvent;
+use crate::gameplay::fixed_step::{FixedStepInterpolation, GameplaySimSet};
 use crate::gameplay::vehicle::{PlayerHealth, PlayerVehicle};
-use crate::states::GameState;
+use crate::rng::{RngStream, RunRng, StreamRng};
+use crate::states::ActiveRun;
 use crate::web::max_enemy_projectiles_for_platform;
 use bevy::asset::LoadState;
 use bevy::mesh::VertexAttributeValues;
@@ -15,8 +20,10 @@ use std::f32::consts::{FRAC_PI_2, TAU};
 #[cfg(feature = "gaussian_splats")]
 use bevy_gaussian_splatting::PlanarGaussian3d;
 
-const ENEMY_SPAWN_START_AHEAD_M: f32 = 41.6;
-const ENEMY_SPAWN_SPACING_M: f32 = 26.0;
+mod spawners;
+
+use spawners::*;
+
 const ENEMY_DESPAWN_BEHIND_M: f32 = 48.0;
 const ENEMY_DESPAWN_AHEAD_M: f32 = 220.0;
 const GROUND_FOLLOW_SNAP_RATE: f32 = 14.0;
@@ -67,7 +74,6 @@ const ENEMY_CHARGER_UPHILL_SPEED_BOOST: f32 = 1.5;
 const ENEMY_WALKER_GROUND_FOLLOW_RATE: f32 = 18.0;
 const ENEMY_CHARGER_GROUND_FOLLOW_RATE: f32 = 20.0;
 const SEGMENT_BOSS_TRIGGER_BEFORE_END_M: f32 = 20.0;
-const SEGMENT_BOSS_ENEMY_ID: &str = "segment_boss_drone";
 const SEGMENT_BOSS_ENTRY_OFFSET_M: f32 = 6.0;
 const SEGMENT_BOSS_PLAYER_GATE_GAP_M: f32 = 1.4;
 const SEGMENT_PORTAL_LOADING_LOGO_PATH: &str = "sprites/autoauto_logo.jpg";
@@ -83,7 +89,7 @@ pub struct EnemyGameplayPlugin;
 
 impl Plugin for EnemyGameplayPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<EnemyBootstrapState>()
+        app.init_resource::<EnemySpawnerRuntime>()
             .init_resource::<EnemyContactTracker>()
             .init_resource::<SegmentBossEncounterState>()
             .init_resource::<SegmentPortalTransitionState>()
@@ -93,24 +99,22 @@ impl Plugin for EnemyGameplayPlugin {
             .add_message::<SegmentBossSpawnedEvent>()
             .add_message::<SegmentBossDefeatedEvent>()
             .add_systems(
-                OnEnter(GameState::InRun),
+                OnEnter(ActiveRun),
                 (
-                    reset_enemy_bootstrap,
+                    reset_enemy_spawner_runtime,
                     reset_enemy_contact_tracker,
                     reset_segment_boss_state,
                     reset_segment_portal_transition_state,
                 ),
             )
-            .add_systems(OnExit(GameState::InRun), cleanup_enemy_run_entities)
+            .add_systems(OnExit(ActiveRun), cleanup_enemy_run_entities)
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     sync_segment_boss_state,
-                    debug_warp_to_next_segment_hotkey,
                     enforce_player_boss_gate,
                     trigger_segment_boss_encounter,
-                    spawn_bootstrap_enemies,
-                    configure_enemy_model_visuals,
+                    run_enemy_spawners,
                     update_enemy_behaviors,
                     fire_enemy_projectiles,
                     simulate_enemy_projectiles,
@@ -118,20 +122,29 @@ impl Plugin for EnemyGameplayPlugin {
                     apply_enemy_contact_damage_to_player,
                     handle_segment_boss_defeat_transition,
                     process_segment_portal_transition,
+                    despawn_far_enemies,
+                )
+                    .chain()
+                    .in_set(GameplaySimSet::Enemies),
+            )
+            .add_systems(
+                Update,
+                (
+                    debug_warp_to_next_segment_hotkey,
+                    configure_enemy_model_visuals,
                     update_enemy_hit_flash_effects,
                     update_enemy_fade_out_fx,
                     update_enemy_health_bars,
-                    despawn_far_enemies,
-                    rearm_bootstrap_when_empty,
                 )
                     .chain()
-                    .run_if(in_state(GameState::InRun))
+                    .run_if(in_state(ActiveRun))
                     .run_if(resource_exists::<GameConfig>),
             );
     }
 }
 
 #[derive(Component)]
+#[require(FixedStepInterpolation)]
 pub struct Enemy;
 
 #[derive(Component, Debug, Clone)]
@@ -215,10 +228,16 @@ struct EnemyBehavior {
 #[derive(Component, Debug, Clone, Copy)]
 struct EnemyAttackState {
     cooldown_s: f32,
-    rng_state: u64,
 }
 
 #[derive(Component, Debug, Clone, Copy)]
+struct EnemyDifficultyScale {
+    damage_multiplier: f32,
+    fire_rate_multiplier: f32,
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+#[require(FixedStepInterpolation)]
 struct EnemyProjectile {
     kind: EnemyProjectileKind,
     damage: f32,
@@ -236,22 +255,6 @@ enum EnemyProjectileKind {
     Bomb,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum EnemyBehaviorKind {
-    Walker,
-    Flier,
-    Turret,
-    Charger,
-    Bomber,
-    Boss,
-}
-
-#[derive(Resource, Debug, Default)]
-struct EnemyBootstrapState {
-    seeded: bool,
-    wave_counter: u32,
-}
-
 #[derive(Resource, Debug, Default)]
 struct EnemyContactTracker {
     currently_colliding: HashSet<Entity>,
@@ -356,10 +359,6 @@ pub struct SegmentBossDefeatedEvent {
     pub segment_id: String,
 }
 
-fn reset_enemy_bootstrap(mut bootstrap: ResMut<EnemyBootstrapState>) {
-    bootstrap.seeded = false;
-}
-
 fn reset_enemy_contact_tracker(mut tracker: ResMut<EnemyContactTracker>) {
     tracker.currently_colliding.clear();
 }
@@ -416,7 +415,7 @@ fn debug_warp_to_next_segment_hotkey(
     keyboard: Res<ButtonInput<KeyCode>>,
     config: Res<GameConfig>,
     mut commands: Commands,
-    mut bootstrap: ResMut<EnemyBootstrapState>,
+    mut spawner_runtime: ResMut<EnemySpawnerRuntime>,
     mut boss_state: ResMut<SegmentBossEncounterState>,
     mut portal_state: ResMut<SegmentPortalTransitionState>,
     mut player_query: Query<
@@ -499,7 +498,7 @@ fn debug_warp_to_next_segment_hotkey(
     }
 
     portal_state.pending = None;
-    bootstrap.seeded = false;
+    spawner_runtime.reset_spawners();
     boss_state.active_segment_index = next_segment_bounds.index;
     boss_state.active_segment_id = next_segment_bounds.id.to_string();
     boss_state.active_segment_start_x = next_segment_start_x;
@@ -555,8 +554,9 @@ fn enforce_player_boss_gate(
 fn trigger_segment_boss_encounter(
     mut commands: Commands,
     config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     asset_registry: Option<Res<AssetRegistry>>,
-    mut bootstrap: ResMut<EnemyBootstrapState>,
+    mut rng: ResMut<RunRng>,
     mut boss_state: ResMut<SegmentBossEncounterState>,
     mut boss_spawned_writer: MessageWriter<SegmentBossSpawnedEvent>,
     player_query: Query<&Transform, With<PlayerVehicle>>,
@@ -575,7 +575,7 @@ fn trigger_segment_boss_encounter(
         return;
     }
 
-    let Some(enemy_cfg) = config.enemy_types_by_id.get(SEGMENT_BOSS_ENEMY_ID) else {
+    let Some(enemy_cfg) = run_stats.enemy_types_by_id.get(SEGMENT_BOSS_ENEMY_ID) else {
         warn!(
             "Boss trigger reached for segment `{}`, but enemy type `{}` is missing.",
             boss_state.active_segment_id, SEGMENT_BOSS_ENEMY_ID
@@ -600,10 +600,8 @@ fn trigger_segment_boss_encounter(
         asset_registry.as_deref(),
         enemy_cfg,
         spawn_x,
-        bootstrap.wave_counter,
+        rng.stream(RngStream::Spawns),
     );
-    bootstrap.wave_counter = bootstrap.wave_counter.saturating_add(1);
-    bootstrap.seeded = true;
     boss_state.boss_spawned_for_segment = true;
     boss_state.boss_alive = true;
     boss_spawned_writer.write(SegmentBossSpawnedEvent {
@@ -636,66 +634,19 @@ fn cleanup_enemy_run_entities(
     }
 }
 
-fn spawn_bootstrap_enemies(
-    mut commands: Commands,
-    config: Res<GameConfig>,
-    asset_registry: Option<Res<AssetRegistry>>,
-    mut bootstrap: ResMut<EnemyBootstrapState>,
-    boss_state: Res<SegmentBossEncounterState>,
-    player_query: Query<&Transform, With<PlayerVehicle>>,
-) {
-    if bootstrap.seeded || boss_state.boss_alive || boss_state.boss_spawned_for_segment {
-        return;
-    }
-
-    let Ok(player_transform) = player_query.single() else {
-        return;
-    };
-
-    if config.enemy_types.enemy_types.is_empty() {
-        return;
-    }
-
-    let mut spawned_count = 0_u32;
-    for enemy_cfg in &config.enemy_types.enemy_types {
-        if behavior_kind_from_config(enemy_cfg.behavior.as_str()) == EnemyBehaviorKind::Boss
-            || enemy_cfg.id == SEGMENT_BOSS_ENEMY_ID
-        {
-            continue;
-        }
-        let spawn_x = player_transform.translation.x
-            + ENEMY_SPAWN_START_AHEAD_M
-            + (spawned_count as f32 * ENEMY_SPAWN_SPACING_M);
-        spawn_enemy_instance(
-            &mut commands,
-            &config,
-            asset_registry.as_deref(),
-            enemy_cfg,
-            spawn_x,
-            bootstrap.wave_counter + spawned_count,
-        );
-        spawned_count = spawned_count.saturating_add(1);
-    }
-
-    if spawned_count > 0 {
-        bootstrap.wave_counter = bootstrap.wave_counter.saturating_add(spawned_count);
-        bootstrap.seeded = true;
-    }
-}
-
 fn spawn_enemy_instance(
     commands: &mut Commands,
     config: &GameConfig,
     asset_registry: Option<&AssetRegistry>,
     enemy_cfg: &EnemyTypeConfig,
     spawn_x: f32,
-    sequence: u32,
-) {
-    let behavior_kind = behavior_kind_from_config(enemy_cfg.behavior.as_str());
+    spawn_rng: &mut StreamRng,
+) -> Entity {
+    let behavior_kind = enemy_cfg.behavior;
     let body_size = body_size_for_behavior(behavior_kind, enemy_cfg.hitbox_radius);
     let body_color = color_for_behavior(behavior_kind);
     let ground_y = terrain_height_at_x(config, spawn_x) + enemy_cfg.hitbox_radius.max(0.15);
-    let phase_offset = (sequence as f32 * 0.37).rem_euclid(1.0) * TAU;
+    let phase_offset = spawn_rng.unit() * TAU;
 
     let base_altitude = match behavior_kind {
         EnemyBehaviorKind::Flier => ground_y + enemy_cfg.hover_amplitude.max(0.5) + 1.6,
@@ -726,6 +677,9 @@ fn spawn_enemy_instance(
         _ => 1.0,
     };
     let gameplay_box_color = body_color.with_alpha(ENEMY_GAMEPLAY_BOX_ALPHA);
+    let difficulty = &config.game.difficulty;
+    let difficulty_scalar = config.difficulty_scalar_at_distance(spawn_x.max(0.0));
+    let max_health = enemy_cfg.health * difficulty.health_multiplier(difficulty_scalar);
 
     let enemy_entity = commands
         .spawn((
@@ -735,8 +689,8 @@ fn spawn_enemy_instance(
             EnemyTypeId(enemy_cfg.id.clone()),
             EnemyBaseColor(gameplay_box_color),
             EnemyHealth {
-                current: enemy_cfg.health,
-                max: enemy_cfg.health,
+                current: max_health,
+                max: max_health,
             },
             EnemyHitbox {
                 radius_m: enemy_cfg.hitbox_radius,
@@ -754,8 +708,11 @@ fn spawn_enemy_instance(
                 elapsed_s: 0.0,
             },
             EnemyAttackState {
-                cooldown_s: 0.35 + ((sequence as f32 * 0.17).rem_euclid(0.8)),
-                rng_state: 0xD8E5_3A1C_9F2B_4D11 ^ sequence as u64 ^ (enemy_cfg.id.len() as u64),
+                cooldown_s: 0.35 + spawn_rng.unit() * 0.8,
+            },
+            EnemyDifficultyScale {
+                damage_multiplier: difficulty.damage_multiplier(difficulty_scalar),
+                fire_rate_multiplier: difficulty.fire_rate_multiplier(difficulty_scalar),
             },
             Sprite::from_color(gameplay_box_color, body_size),
             Transform::from_xyz(spawn_x, start_y, 8.0),
@@ -833,6 +790,8 @@ fn spawn_enemy_instance(
             Visibility::Hidden,
         ));
     });
+
+    enemy_entity
 }
 
 #[allow(clippy::type_complexity)]
@@ -1071,11 +1030,13 @@ fn update_enemy_behaviors(
     }
 }
 
-#[allow(clippy::type_complexity)]
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn fire_enemy_projectiles(
     mut commands: Commands,
     time: Res<Time>,
     config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
+    mut rng: ResMut<RunRng>,
     player_query: Query<&Transform, With<PlayerVehicle>>,
     projectile_query: Query<(), With<EnemyProjectile>>,
     mut enemy_query: Query<
@@ -1084,6 +1045,7 @@ fn fire_enemy_projectiles(
             &EnemyBehavior,
             &EnemyTypeId,
             &EnemyHitbox,
+            &EnemyDifficultyScale,
             &mut EnemyAttackState,
         ),
         With<Enemy>,
@@ -1096,17 +1058,20 @@ fn fire_enemy_projectiles(
     let dt = time.delta_secs();
     let max_projectiles = max_enemy_projectiles_for_platform(&config);
     let mut live_projectile_count = projectile_query.iter().count();
+    let spread_rng = rng.stream(RngStream::WeaponSpread);
 
-    for (enemy_transform, behavior, enemy_type_id, hitbox, mut attack_state) in &mut enemy_query {
+    for (enemy_transform, behavior, enemy_type_id, hitbox, difficulty_scale, mut attack_state) in
+        &mut enemy_query
+    {
         attack_state.cooldown_s -= dt;
         if attack_state.cooldown_s > 0.0 {
             continue;
         }
 
-        let Some(enemy_type) = config.enemy_types_by_id.get(&enemy_type_id.0) else {
+        let Some(enemy_type) = run_stats.enemy_types_by_id.get(&enemy_type_id.0) else {
             continue;
         };
-        let Some(weapon) = config.weapons_by_id.get(&enemy_type.weapon_id) else {
+        let Some(weapon) = run_stats.weapons_by_id.get(&enemy_type.weapon_id) else {
             continue;
         };
 
@@ -1118,8 +1083,9 @@ fn fire_enemy_projectiles(
         } else {
             ENEMY_ATTACK_RANGE_M
         };
+        let fire_rate_hz = weapon.fire_rate * difficulty_scale.fire_rate_multiplier;
         let fire_cooldown_s =
-            (1.0 / weapon.fire_rate.max(MIN_ENEMY_FIRE_RATE_HZ)).max(MIN_ENEMY_FIRE_COOLDOWN_S);
+            (1.0 / fire_rate_hz.max(MIN_ENEMY_FIRE_RATE_HZ)).max(MIN_ENEMY_FIRE_COOLDOWN_S);
 
         if behavior.kind == EnemyBehaviorKind::Bomber {
             let x_distance = (enemy_position.x - player_position.x).abs();
@@ -1140,6 +1106,7 @@ fn fire_enemy_projectiles(
                 &mut commands,
                 weapon,
                 behavior.kind,
+                difficulty_scale.damage_multiplier,
                 bomb_spawn_world,
                 Vec2::NEG_Y,
             );
@@ -1173,8 +1140,7 @@ fn fire_enemy_projectiles(
                 } else {
                     *pattern_offset
                 };
-                let random_spread =
-                    next_signed_unit_random(&mut attack_state.rng_state) * spread_half_angle_rad;
+                let random_spread = spread_rng.signed_unit() * spread_half_angle_rad;
                 let shot_angle =
                     aim_direction.y.atan2(aim_direction.x) + behavior_offset + random_spread;
                 let shot_direction_world =
@@ -1188,6 +1154,7 @@ fn fire_enemy_projectiles(
                     &mut commands,
                     weapon,
                     behavior.kind,
+                    difficulty_scale.damage_multiplier,
                     muzzle_world,
                     shot_direction_world,
                 );
@@ -1389,7 +1356,7 @@ fn enemy_projectile_impact_kind(kind: EnemyProjectileKind) -> EnemyProjectileImp
 fn apply_enemy_contact_damage_to_player(
     mut commands: Commands,
     time: Res<Time>,
-    config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     debug_guards: Option<Res<DebugGameplayGuards>>,
     mut contact_tracker: ResMut<EnemyContactTracker>,
     mut killed_message_writer: MessageWriter<EnemyKilledEvent>,
@@ -1402,6 +1369,7 @@ fn apply_enemy_contact_damage_to_player(
             &Transform,
             &EnemyHitbox,
             &EnemyTypeId,
+            &EnemyDifficultyScale,
             &mut EnemyHealth,
         ),
         With<Enemy>,
@@ -1420,8 +1388,14 @@ fn apply_enemy_contact_damage_to_player(
     let mut contact_source_weight = 0.0;
     let mut dead_enemies: Vec<(Entity, String, Vec2)> = Vec::new();
     let mut current_colliding_enemies = HashSet::new();
-    for (enemy_entity, enemy_transform, enemy_hitbox, enemy_type_id, mut enemy_health) in
-        &mut enemy_query
+    for (
+        enemy_entity,
+        enemy_transform,
+        enemy_hitbox,
+        enemy_type_id,
+        difficulty_scale,
+        mut enemy_health,
+    ) in &mut enemy_query
     {
         let enemy_position = enemy_transform.translation.truncate();
         if enemy_health.current <= 0.0 {
@@ -1429,7 +1403,7 @@ fn apply_enemy_contact_damage_to_player(
             continue;
         }
 
-        let Some(enemy_type) = config.enemy_types_by_id.get(&enemy_type_id.0) else {
+        let Some(enemy_type) = run_stats.enemy_types_by_id.get(&enemy_type_id.0) else {
             continue;
         };
 
@@ -1440,7 +1414,8 @@ fn apply_enemy_contact_damage_to_player(
             .distance_squared(player_position);
         if distance_sq <= combined_radius * combined_radius {
             current_colliding_enemies.insert(enemy_entity);
-            let enemy_contact_damage = enemy_type.contact_damage.max(0.0) * dt;
+            let enemy_contact_damage =
+                enemy_type.contact_damage.max(0.0) * difficulty_scale.damage_multiplier * dt;
             total_contact_damage += enemy_contact_damage;
             contact_source_sum += enemy_position * enemy_contact_damage.max(0.001);
             contact_source_weight += enemy_contact_damage.max(0.001);
@@ -1497,7 +1472,7 @@ fn handle_segment_boss_defeat_transition(
     time: Res<Time>,
     mut kill_events: MessageReader<EnemyKilledEvent>,
     mut boss_defeated_writer: MessageWriter<SegmentBossDefeatedEvent>,
-    mut bootstrap: ResMut<EnemyBootstrapState>,
+    mut spawner_runtime: ResMut<EnemySpawnerRuntime>,
     mut boss_state: ResMut<SegmentBossEncounterState>,
     mut portal_state: ResMut<SegmentPortalTransitionState>,
     mut player_query: Query<
@@ -1566,7 +1541,7 @@ fn handle_segment_boss_defeat_transition(
         commands.entity(projectile_entity).try_despawn();
     }
 
-    bootstrap.seeded = false;
+    spawner_runtime.reset_spawners();
     boss_state.boss_spawned_for_segment = true;
     boss_state.boss_alive = false;
 
@@ -1615,7 +1590,7 @@ fn process_segment_portal_transition(
     time: Res<Time>,
     config: Res<GameConfig>,
     asset_server: Res<AssetServer>,
-    mut bootstrap: ResMut<EnemyBootstrapState>,
+    mut spawner_runtime: ResMut<EnemySpawnerRuntime>,
     mut boss_state: ResMut<SegmentBossEncounterState>,
     mut portal_state: ResMut<SegmentPortalTransitionState>,
     mut player_query: Query<
@@ -1694,7 +1669,7 @@ fn process_segment_portal_transition(
         force.torque = 0.0;
     }
 
-    bootstrap.seeded = false;
+    spawner_runtime.reset_spawners();
     boss_state.active_segment_index = pending.next_segment_index;
     boss_state.active_segment_id = pending.next_segment_id.clone();
     boss_state.active_segment_start_x = pending.next_segment_start_x;
@@ -1735,16 +1710,6 @@ fn despawn_far_enemies(
     }
 }
 
-fn rearm_bootstrap_when_empty(
-    mut bootstrap: ResMut<EnemyBootstrapState>,
-    boss_state: Res<SegmentBossEncounterState>,
-    enemy_query: Query<Entity, With<Enemy>>,
-) {
-    if bootstrap.seeded && enemy_query.is_empty() && !boss_state.boss_alive {
-        bootstrap.seeded = false;
-    }
-}
-
 fn shot_pattern_for_behavior(kind: EnemyBehaviorKind) -> ([f32; 3], usize) {
     match kind {
         EnemyBehaviorKind::Walker | EnemyBehaviorKind::Turret | EnemyBehaviorKind::Bomber => {
@@ -1767,15 +1732,16 @@ fn spawn_enemy_projectile(
     commands: &mut Commands,
     weapon: &WeaponConfig,
     behavior_kind: EnemyBehaviorKind,
+    damage_multiplier: f32,
     muzzle_world: Vec2,
     shot_direction_world: Vec2,
 ) {
     let projectile_kind = if behavior_kind == EnemyBehaviorKind::Bomber {
         EnemyProjectileKind::Bomb
     } else {
-        match weapon.projectile_type.as_str() {
-            "missile" => EnemyProjectileKind::Missile,
-            _ => EnemyProjectileKind::Bullet,
+        match weapon.projectile_type {
+            ProjectileKind::Missile => EnemyProjectileKind::Missile,
+            ProjectileKind::Bullet => EnemyProjectileKind::Bullet,
         }
     };
     let (length_m, thickness_m, hit_radius_m, color) = match projectile_kind {
@@ -1825,7 +1791,7 @@ fn spawn_enemy_projectile(
         Name::new("EnemyProjectile"),
         EnemyProjectile {
             kind: projectile_kind,
-            damage: weapon.damage.max(0.0),
+            damage: weapon.damage.max(0.0) * damage_multiplier,
             hit_radius_m,
             velocity_mps: initial_velocity,
             drag: weapon.projectile_drag.max(0.0),
@@ -1862,12 +1828,6 @@ fn enemy_mass_from_hitbox(hitbox_radius_m: f32) -> f32 {
     ((radius * radius) * ENEMY_MASS_PER_RADIUS_SQUARED).max(ENEMY_MIN_MASS)
 }
 
-fn next_signed_unit_random(seed: &mut u64) -> f32 {
-    *seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
-    let value = ((*seed >> 32) as u32) as f32 / u32::MAX as f32;
-    (value * 2.0) - 1.0
-}
-
 #[allow(clippy::type_complexity)]
 fn update_enemy_hit_flash_effects(
     mut commands: Commands,
@@ -1991,17 +1951,6 @@ pub fn enemy_hit_flash_duration_seconds() -> f32 {
     ENEMY_HIT_FLASH_DURATION_S
 }
 
-fn behavior_kind_from_config(raw: &str) -> EnemyBehaviorKind {
-    match raw {
-        "flier" => EnemyBehaviorKind::Flier,
-        "turret" => EnemyBehaviorKind::Turret,
-        "charger" => EnemyBehaviorKind::Charger,
-        "bomber" => EnemyBehaviorKind::Bomber,
-        "boss" => EnemyBehaviorKind::Boss,
-        _ => EnemyBehaviorKind::Walker,
-    }
-}
-
 fn resolve_enemy_model_entry<'a>(
     registry: &'a AssetRegistry,
     enemy_type_id: &str,
@@ -0,0 +1,197 @@
+use super::*;
+use crate::config::{SpawnerConfig, SpawnerMode};
+use std::collections::HashMap;
+
+/// Tags enemies with the spawner that produced them so `max_alive` can be enforced per spawner.
+#[derive(Component, Debug, Clone)]
+pub(super) struct EnemySpawnerOrigin(pub String);
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct SpawnerState {
+    cooldown_s: f32,
+    last_spawn_x: Option<f32>,
+    next_enemy_index: usize,
+    waves_spawned: u32,
+}
+
+#[derive(Resource, Debug, Default)]
+pub(super) struct EnemySpawnerRuntime {
+    active_segment_index: Option<usize>,
+    spawners: HashMap<String, SpawnerState>,
+}
+
+impl EnemySpawnerRuntime {
+    /// Clears per-spawner progress so every spawner re-arms from `start_distance`.
+    pub(super) fn reset_spawners(&mut self) {
+        self.spawners.clear();
+    }
+}
+
+pub(super) fn reset_enemy_spawner_runtime(mut runtime: ResMut<EnemySpawnerRuntime>) {
+    *runtime = EnemySpawnerRuntime::default();
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn run_enemy_spawners(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
+    asset_registry: Option<Res<AssetRegistry>>,
+    mut runtime: ResMut<EnemySpawnerRuntime>,
+    mut rng: ResMut<RunRng>,
+    boss_state: Res<SegmentBossEncounterState>,
+    portal_state: Res<SegmentPortalTransitionState>,
+    player_query: Query<&Transform, With<PlayerVehicle>>,
+    origin_query: Query<&EnemySpawnerOrigin, With<Enemy>>,
+) {
+    if portal_state.pending.is_some() {
+        return;
+    }
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    let player_x = player_transform.translation.x.max(0.0);
+    let segment_distance_m = (player_x - boss_state.active_segment_start_x).max(0.0);
+    let dt = time.delta_secs();
+
+    let mut alive_by_spawner: HashMap<&str, u32> = HashMap::new();
+    for origin in &origin_query {
+        *alive_by_spawner.entry(origin.0.as_str()).or_default() += 1;
+    }
+
+    let runtime = &mut *runtime;
+    let active_segment_index = boss_state.active_segment_index;
+    let active_spawn_set = config
+        .segments
+        .segment_sequence
+        .get(active_segment_index)
+        .and_then(|segment| segment.spawn_set.as_deref());
+    if runtime.active_segment_index != Some(active_segment_index) {
+        // Enemies from the previous set stay alive; only spawner progress re-arms.
+        runtime.reset_spawners();
+        runtime.active_segment_index = Some(active_segment_index);
+        info!(
+            "Spawner set for segment `{}`: {}.",
+            boss_state.active_segment_id,
+            active_spawn_set.unwrap_or("all spawners")
+        );
+    }
+
+    for spawner_cfg in &config.spawners.spawners {
+        if active_spawn_set.is_some_and(|spawn_set| spawn_set != spawner_cfg.id) {
+            continue;
+        }
+        let mode = spawner_cfg.mode;
+        let state = runtime.spawners.entry(spawner_cfg.id.clone()).or_default();
+        state.cooldown_s = (state.cooldown_s - dt).max(0.0);
+
+        let active = match mode {
+            SpawnerMode::BossGate => boss_state.boss_alive,
+            _ => {
+                !boss_state.boss_alive
+                    && !boss_state.boss_spawned_for_segment
+                    && segment_distance_m >= spawner_cfg.start_distance
+            }
+        };
+        if !active || state.cooldown_s > 0.0 {
+            continue;
+        }
+
+        let alive = alive_by_spawner
+            .get(spawner_cfg.id.as_str())
+            .copied()
+            .unwrap_or(0);
+        let capacity = spawner_cfg.max_alive.saturating_sub(alive);
+        let spawn_count = match mode {
+            SpawnerMode::Distance => {
+                let travelled_enough = state
+                    .last_spawn_x
+                    .is_none_or(|last_x| player_x - last_x >= spawner_cfg.spawn_spacing_m);
+                u32::from(travelled_enough).min(capacity)
+            }
+            SpawnerMode::Timed | SpawnerMode::BossGate => capacity.min(1),
+            SpawnerMode::Wave if alive == 0 => {
+                (spawner_cfg.spawn_enemy_ids.len() as u32).min(capacity)
+            }
+            SpawnerMode::Wave => 0,
+        };
+        if spawn_count == 0 {
+            continue;
+        }
+
+        let spawned = spawn_from_spawner(
+            &mut commands,
+            &config,
+            &run_stats,
+            asset_registry.as_deref(),
+            spawner_cfg,
+            state,
+            rng.stream(RngStream::Spawns),
+            player_x,
+            spawn_count,
+        );
+        if spawned == 0 {
+            continue;
+        }
+
+        let difficulty_scalar = config.difficulty_scalar_at_distance(player_x);
+        state.cooldown_s = spawner_cfg.interval_seconds.max(0.0)
+            / config
+                .game
+                .difficulty
+                .spawn_rate_multiplier(difficulty_scalar);
+        state.last_spawn_x = Some(player_x);
+        if mode == SpawnerMode::Wave {
+            state.waves_spawned = state.waves_spawned.saturating_add(1);
+            info!(
+                "Spawner `{}` released wave {} ({} enemies) at x={:.1}.",
+                spawner_cfg.id, state.waves_spawned, spawned, player_x
+            );
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn spawn_from_spawner(
+    commands: &mut Commands,
+    config: &GameConfig,
+    run_stats: &RunStats,
+    asset_registry: Option<&AssetRegistry>,
+    spawner_cfg: &SpawnerConfig,
+    state: &mut SpawnerState,
+    spawn_rng: &mut StreamRng,
+    player_x: f32,
+    spawn_count: u32,
+) -> u32 {
+    let roster_len = spawner_cfg.spawn_enemy_ids.len();
+    if roster_len == 0 {
+        return 0;
+    }
+
+    let mut spawned = 0_u32;
+    for _ in 0..spawn_count {
+        let enemy_id = &spawner_cfg.spawn_enemy_ids[state.next_enemy_index % roster_len];
+        state.next_enemy_index = state.next_enemy_index.wrapping_add(1);
+        let Some(enemy_cfg) = run_stats.enemy_types_by_id.get(enemy_id) else {
+            continue;
+        };
+
+        let spawn_x =
+            player_x + spawner_cfg.spawn_ahead_m + spawned as f32 * spawner_cfg.spawn_spacing_m;
+        let enemy_entity = spawn_enemy_instance(
+            commands,
+            config,
+            asset_registry,
+            enemy_cfg,
+            spawn_x,
+            spawn_rng,
+        );
+        commands
+            .entity(enemy_entity)
+            .insert(EnemySpawnerOrigin(spawner_cfg.id.clone()));
+        spawned = spawned.saturating_add(1);
+    }
+    spawned
+}
@@ -7,9 +7,15 @@ use crate::gameplay::enemies::{
     PlayerDamageEvent, PlayerDamageSource, PlayerEnemyCrashEvent,
 };
 use crate::gameplay::pickups::{PickupCollectedEvent, PickupKind};
-use crate::gameplay::vehicle::{PlayerVehicle, VehicleLandingEvent};
-use crate::states::GameState;
-use crate::{config::GameConfig, web::should_reduce_fx_for_platform};
+use crate::gameplay::vehicle::{
+    PlayerNitro, PlayerVehicle, VehicleLandingEvent, VehicleNitroBoostEvent,
+};
+use crate::rng::{RngStream, RunRng, StreamRng};
+use crate::states::ActiveRun;
+use crate::{
+    config::{GameConfig, RunStats},
+    web::should_reduce_fx_for_platform,
+};
 use bevy::prelude::*;
 use std::f32::consts::TAU;
 
@@ -23,6 +29,7 @@ const FEEDBACK_PARTICLE_Z_M: f32 = 5.1;
 const FX_SMALL_BURST_COUNT: usize = 10;
 const FX_MEDIUM_BURST_COUNT: usize = 16;
 const FX_LARGE_BURST_COUNT: usize = 24;
+const NITRO_EXHAUST_PARTICLES_PER_SECOND: f32 = 70.0;
 
 pub struct FeedbackGameplayPlugin;
 
@@ -30,27 +37,26 @@ impl Plugin for FeedbackGameplayPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<DamageIndicatorState>()
             .init_resource::<CameraShakeState>()
+            .init_resource::<NitroExhaustState>()
             .add_systems(
-                OnEnter(GameState::InRun),
+                OnEnter(ActiveRun),
                 (reset_feedback_state, spawn_damage_indicator_ui),
             )
-            .add_systems(OnExit(GameState::InRun), cleanup_feedback_entities)
+            .add_systems(OnExit(ActiveRun), cleanup_feedback_entities)
             .add_systems(
                 Update,
                 (
                     collect_feedback_events,
+                    emit_nitro_exhaust_particles,
                     decay_damage_indicators,
                     update_damage_indicator_ui,
                     decay_camera_shake,
                     update_feedback_particles,
                 )
                     .chain()
-                    .run_if(in_state(GameState::InRun)),
+                    .run_if(in_state(ActiveRun)),
             )
-            .add_systems(
-                PostUpdate,
-                apply_camera_shake.run_if(in_state(GameState::InRun)),
-            );
+            .add_systems(PostUpdate, apply_camera_shake.run_if(in_state(ActiveRun)));
     }
 }
 
@@ -109,27 +115,26 @@ impl DamageIndicatorState {
     }
 }
 
-#[derive(Resource, Debug, Clone, Copy)]
+#[derive(Resource, Debug, Clone, Copy, Default)]
 struct CameraShakeState {
     trauma: f32,
-    rng_state: u64,
 }
 
-impl Default for CameraShakeState {
-    fn default() -> Self {
-        Self {
-            trauma: 0.0,
-            rng_state: 0x8A37_2BC1_D9E4_1023,
-        }
-    }
+// Fractional particles carried between frames so the exhaust rate does not
+// depend on frame rate.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+struct NitroExhaustState {
+    spawn_budget: f32,
 }
 
 fn reset_feedback_state(
     mut indicators: ResMut<DamageIndicatorState>,
     mut shake_state: ResMut<CameraShakeState>,
+    mut exhaust_state: ResMut<NitroExhaustState>,
 ) {
     *indicators = DamageIndicatorState::default();
     *shake_state = CameraShakeState::default();
+    *exhaust_state = NitroExhaustState::default();
 }
 
 fn spawn_damage_indicator_ui(
@@ -243,10 +248,13 @@ fn collect_feedback_events(
     mut player_projectile_impact_events: MessageReader<PlayerProjectileImpactEvent>,
     mut enemy_projectile_impact_events: MessageReader<EnemyProjectileImpactEvent>,
     mut enemy_killed_events: MessageReader<EnemyKilledEvent>,
+    mut nitro_boost_events: MessageReader<VehicleNitroBoostEvent>,
     player_query: Query<&Transform, With<PlayerVehicle>>,
     mut indicators: ResMut<DamageIndicatorState>,
     mut shake: ResMut<CameraShakeState>,
+    mut rng: ResMut<RunRng>,
 ) {
+    let fx_rng = rng.stream(RngStream::CosmeticFx);
     let reduce_particles = config
         .as_ref()
         .map(|config| should_reduce_fx_for_platform(config))
@@ -269,12 +277,7 @@ fn collect_feedback_events(
 
         if let Some(source_position) = event.source_world_position {
             if !reduce_particles {
-                spawn_player_hit_particles(
-                    &mut commands,
-                    source_position,
-                    event.source,
-                    &mut shake.rng_state,
-                );
+                spawn_player_hit_particles(&mut commands, source_position, event.source, fx_rng);
             }
         }
     }
@@ -290,7 +293,7 @@ fn collect_feedback_events(
                 &mut commands,
                 event.world_position,
                 event.impact_speed_mps,
-                &mut shake.rng_state,
+                fx_rng,
             );
         }
         let landing_trauma = if event.was_crash {
@@ -303,24 +306,19 @@ fn collect_feedback_events(
 
     for event in pickup_events.read() {
         if !reduce_particles {
-            spawn_pickup_sparkle_particles(
-                &mut commands,
-                event.world_position,
-                event.kind,
-                &mut shake.rng_state,
-            );
+            spawn_pickup_sparkle_particles(&mut commands, event.world_position, event.kind, fx_rng);
         }
     }
 
     for event in player_projectile_impact_events.read() {
         if !reduce_particles {
-            spawn_player_projectile_impact_particles(&mut commands, *event, &mut shake.rng_state);
+            spawn_player_projectile_impact_particles(&mut commands, *event, fx_rng);
         }
     }
 
     for event in enemy_projectile_impact_events.read() {
         if !reduce_particles {
-            spawn_enemy_projectile_impact_particles(&mut commands, *event, &mut shake.rng_state);
+            spawn_enemy_projectile_impact_particles(&mut commands, *event, fx_rng);
         }
         if event.kind == EnemyProjectileImpactKind::Bomb {
             let trauma_bump = match event.target {
@@ -333,10 +331,69 @@ fn collect_feedback_events(
 
     for event in enemy_killed_events.read() {
         if !reduce_particles {
-            spawn_enemy_death_particles(&mut commands, event.world_position, &mut shake.rng_state);
+            spawn_enemy_death_particles(&mut commands, event.world_position, fx_rng);
         }
         shake.trauma = (shake.trauma + 0.07).clamp(0.0, 1.0);
     }
+
+    for event in nitro_boost_events.read() {
+        if !reduce_particles {
+            spawn_radial_burst_particles(
+                &mut commands,
+                "NitroIgnitionFx",
+                event.world_position,
+                FX_MEDIUM_BURST_COUNT,
+                Color::srgba(0.62, 0.88, 1.0, 0.94),
+                Color::srgba(1.0, 0.70, 0.30, 0.90),
+                3.0,
+                9.0,
+                0.10,
+                4.0,
+                3.6,
+                0.06,
+                0.18,
+                0.18,
+                0.40,
+                fx_rng,
+            );
+        }
+        shake.trauma = (shake.trauma + 0.12).clamp(0.0, 1.0);
+    }
+}
+
+fn emit_nitro_exhaust_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Option<Res<GameConfig>>,
+    run_stats: Res<RunStats>,
+    player_query: Query<(&Transform, &PlayerNitro), With<PlayerVehicle>>,
+    mut exhaust: ResMut<NitroExhaustState>,
+    mut rng: ResMut<RunRng>,
+) {
+    let reduce_particles = config
+        .as_ref()
+        .map(|config| should_reduce_fx_for_platform(config))
+        .unwrap_or(false);
+    let Ok((transform, nitro)) = player_query.single() else {
+        return;
+    };
+    let Some(vehicle) = run_stats.player_vehicle() else {
+        return;
+    };
+    if !nitro.boosting() || reduce_particles {
+        exhaust.spawn_budget = 0.0;
+        return;
+    }
+
+    let dt = time.delta_secs().max(0.000_1);
+    exhaust.spawn_budget += NITRO_EXHAUST_PARTICLES_PER_SECOND * dt;
+    let fx_rng = rng.stream(RngStream::CosmeticFx);
+    let backward = (transform.rotation * Vec3::NEG_X).truncate();
+    let nozzle = transform.translation.truncate() + (backward * vehicle.chassis_length_m * 0.5);
+    while exhaust.spawn_budget >= 1.0 {
+        exhaust.spawn_budget -= 1.0;
+        spawn_nitro_exhaust_particle(&mut commands, nozzle, backward, fx_rng);
+    }
 }
 
 fn decay_damage_indicators(time: Res<Time>, mut indicators: ResMut<DamageIndicatorState>) {
@@ -372,7 +429,8 @@ fn decay_camera_shake(time: Res<Time>, mut shake: ResMut<CameraShakeState>) {
 }
 
 fn apply_camera_shake(
-    mut shake: ResMut<CameraShakeState>,
+    shake: Res<CameraShakeState>,
+    mut rng: ResMut<RunRng>,
     mut camera_query: Query<&mut Transform, With<Camera2d>>,
 ) {
     if shake.trauma <= f32::EPSILON {
@@ -380,10 +438,9 @@ fn apply_camera_shake(
     }
 
     let shake_amount = shake.trauma * shake.trauma;
-    let offset_x =
-        next_signed_unit_random(&mut shake.rng_state) * CAMERA_SHAKE_MAX_OFFSET_X_M * shake_amount;
-    let offset_y =
-        next_signed_unit_random(&mut shake.rng_state) * CAMERA_SHAKE_MAX_OFFSET_Y_M * shake_amount;
+    let fx_rng = rng.stream(RngStream::CosmeticFx);
+    let offset_x = fx_rng.signed_unit() * CAMERA_SHAKE_MAX_OFFSET_X_M * shake_amount;
+    let offset_y = fx_rng.signed_unit() * CAMERA_SHAKE_MAX_OFFSET_Y_M * shake_amount;
 
     for mut camera_transform in &mut camera_query {
         camera_transform.translation.x += offset_x;
@@ -458,18 +515,18 @@ fn spawn_landing_dust_particles(
     commands: &mut Commands,
     world_position: Vec2,
     impact_speed_mps: f32,
-    rng_state: &mut u64,
+    rng: &mut StreamRng,
 ) {
     let impact = impact_speed_mps.max(0.0);
     let count = ((impact * 0.7).round() as i32).clamp(6, 16) as usize;
     for _ in 0..count {
-        let x_jitter = next_signed_unit_random(rng_state) * (0.55 + (impact * 0.02));
-        let launch_speed = lerp(1.8, 6.4, next_unit_random(rng_state)) * (0.6 + impact * 0.03);
-        let vx = next_signed_unit_random(rng_state) * launch_speed;
-        let vy = lerp(1.0, 4.6, next_unit_random(rng_state));
-        let size = lerp(0.10, 0.24, next_unit_random(rng_state));
-        let lifetime = lerp(0.24, 0.46, next_unit_random(rng_state));
-        let alpha = lerp(0.35, 0.72, next_unit_random(rng_state));
+        let x_jitter = rng.signed_unit() * (0.55 + (impact * 0.02));
+        let launch_speed = lerp(1.8, 6.4, rng.unit()) * (0.6 + impact * 0.03);
+        let vx = rng.signed_unit() * launch_speed;
+        let vy = lerp(1.0, 4.6, rng.unit());
+        let size = lerp(0.10, 0.24, rng.unit());
+        let lifetime = lerp(0.24, 0.46, rng.unit());
+        let alpha = lerp(0.35, 0.72, rng.unit());
 
         commands.spawn((
             Name::new("LandingDustFx"),
@@ -495,19 +552,20 @@ fn spawn_pickup_sparkle_particles(
     commands: &mut Commands,
     world_position: Vec2,
     kind: PickupKind,
-    rng_state: &mut u64,
+    rng: &mut StreamRng,
 ) {
     let (count, color) = match kind {
         PickupKind::Coin => (8, Color::srgba(1.0, 0.86, 0.26, 0.95)),
         PickupKind::Health => (7, Color::srgba(0.32, 1.0, 0.42, 0.95)),
+        PickupKind::Fuel => (7, Color::srgba(1.0, 0.46, 0.24, 0.95)),
     };
 
     for _ in 0..count {
-        let angle = next_unit_random(rng_state) * std::f32::consts::TAU;
-        let speed = lerp(3.0, 9.0, next_unit_random(rng_state));
+        let angle = rng.unit() * std::f32::consts::TAU;
+        let speed = lerp(3.0, 9.0, rng.unit());
         let velocity = Vec2::new(angle.cos() * speed, angle.sin().abs() * speed + 2.2);
-        let size = lerp(0.06, 0.14, next_unit_random(rng_state));
-        let lifetime = lerp(0.16, 0.34, next_unit_random(rng_state));
+        let size = lerp(0.06, 0.14, rng.unit());
+        let lifetime = lerp(0.16, 0.34, rng.unit());
 
         commands.spawn((
             Name::new("PickupSparkleFx"),
@@ -533,7 +591,7 @@ fn spawn_player_hit_particles(
     commands: &mut Commands,
     world_position: Vec2,
     source: PlayerDamageSource,
-    rng_state: &mut u64,
+    rng: &mut StreamRng,
 ) {
     let (warm_color, cool_color, count, speed_scale) = match source {
         PlayerDamageSource::ProjectileBullet => (
@@ -578,14 +636,14 @@ fn spawn_player_hit_particles(
         0.16,
         0.18,
         0.44,
-        rng_state,
+        rng,
     );
 }
 
 fn spawn_player_projectile_impact_particles(
     commands: &mut Commands,
     event: PlayerProjectileImpactEvent,
-    rng_state: &mut u64,
+    rng: &mut StreamRng,
 ) {
     match (event.kind, event.target) {
         (PlayerProjectileAudioKind::Bullet, PlayerProjectileImpactTarget::Enemy) => {
@@ -605,7 +663,7 @@ fn spawn_player_projectile_impact_particles(
                 0.14,
                 0.16,
                 0.34,
-                rng_state,
+                rng,
             );
         }
         (PlayerProjectileAudioKind::Bullet, PlayerProjectileImpactTarget::Ground) => {
@@ -625,7 +683,7 @@ fn spawn_player_projectile_impact_particles(
                 0.18,
                 0.18,
                 0.42,
-                rng_state,
+                rng,
             );
         }
         (PlayerProjectileAudioKind::Missile, PlayerProjectileImpactTarget::Enemy)
@@ -646,9 +704,9 @@ fn spawn_player_projectile_impact_particles(
                 0.22,
                 0.20,
                 0.48,
-                rng_state,
+                rng,
             );
-            spawn_smoke_plume_particles(commands, event.world_position, 8, rng_state);
+            spawn_smoke_plume_particles(commands, event.world_position, 8, rng);
         }
     }
 }
@@ -656,7 +714,7 @@ fn spawn_player_projectile_impact_particles(
 fn spawn_enemy_projectile_impact_particles(
     commands: &mut Commands,
     event: EnemyProjectileImpactEvent,
-    rng_state: &mut u64,
+    rng: &mut StreamRng,
 ) {
     match event.kind {
         EnemyProjectileImpactKind::Bomb => {
@@ -691,9 +749,9 @@ fn spawn_enemy_projectile_impact_particles(
                 0.22,
                 0.22,
                 0.54,
-                rng_state,
+                rng,
             );
-            spawn_smoke_plume_particles(commands, event.world_position, 10, rng_state);
+            spawn_smoke_plume_particles(commands, event.world_position, 10, rng);
         }
         EnemyProjectileImpactKind::Missile => {
             spawn_radial_burst_particles(
@@ -712,9 +770,9 @@ fn spawn_enemy_projectile_impact_particles(
                 0.18,
                 0.18,
                 0.44,
-                rng_state,
+                rng,
             );
-            spawn_smoke_plume_particles(commands, event.world_position, 6, rng_state);
+            spawn_smoke_plume_particles(commands, event.world_position, 6, rng);
         }
         EnemyProjectileImpactKind::Bullet => {
             spawn_radial_burst_particles(
@@ -733,13 +791,13 @@ fn spawn_enemy_projectile_impact_particles(
                 0.13,
                 0.14,
                 0.30,
-                rng_state,
+                rng,
             );
         }
     }
 }
 
-fn spawn_enemy_death_particles(commands: &mut Commands, world_position: Vec2, rng_state: &mut u64) {
+fn spawn_enemy_death_particles(commands: &mut Commands, world_position: Vec2, rng: &mut StreamRng) {
     spawn_radial_burst_particles(
         commands,
         "EnemyDeathBurstFx",
@@ -756,9 +814,9 @@ fn spawn_enemy_death_particles(commands: &mut Commands, world_position: Vec2, rn
         0.24,
         0.20,
         0.56,
-        rng_state,
+        rng,
     );
-    spawn_smoke_plume_particles(commands, world_position, 12, rng_state);
+    spawn_smoke_plume_particles(commands, world_position, 12, rng);
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -778,23 +836,23 @@ fn spawn_radial_burst_particles(
     size_max: f32,
     life_min_s: f32,
     life_max_s: f32,
-    rng_state: &mut u64,
+    rng: &mut StreamRng,
 ) {
     let color_a = color_a.to_srgba();
     let color_b = color_b.to_srgba();
     for _ in 0..count {
-        let angle = next_unit_random(rng_state) * TAU;
+        let angle = rng.unit() * TAU;
         let mut direction = Vec2::new(angle.cos(), angle.sin() + upward_bias);
         if direction.length_squared() <= f32::EPSILON {
             direction = Vec2::Y;
         } else {
             direction = direction.normalize();
         }
-        let speed = lerp(speed_min, speed_max, next_unit_random(rng_state));
+        let speed = lerp(speed_min, speed_max, rng.unit());
         let velocity = direction * speed;
-        let size = lerp(size_min, size_max, next_unit_random(rng_state));
-        let lifetime = lerp(life_min_s, life_max_s, next_unit_random(rng_state));
-        let mix_t = next_unit_random(rng_state);
+        let size = lerp(size_min, size_max, rng.unit());
+        let lifetime = lerp(life_min_s, life_max_s, rng.unit());
+        let mix_t = rng.unit();
         let red = lerp(color_a.red, color_b.red, mix_t);
         let green = lerp(color_a.green, color_b.green, mix_t);
         let blue = lerp(color_a.blue, color_b.blue, mix_t);
@@ -820,21 +878,57 @@ fn spawn_radial_burst_particles(
     }
 }
 
+fn spawn_nitro_exhaust_particle(
+    commands: &mut Commands,
+    nozzle: Vec2,
+    backward: Vec2,
+    rng: &mut StreamRng,
+) {
+    let spread = backward.perp() * (rng.signed_unit() * 0.9);
+    let velocity = (backward * lerp(3.0, 7.5, rng.unit())) + spread;
+    let size = lerp(0.10, 0.22, rng.unit());
+    let lifetime = lerp(0.14, 0.30, rng.unit());
+    let alpha = lerp(0.62, 0.90, rng.unit());
+    let heat = rng.unit();
+
+    commands.spawn((
+        Name::new("NitroExhaustFx"),
+        FeedbackParticle {
+            velocity_mps: velocity,
+            gravity_mps2: 0.0,
+            drag_per_second: 4.2,
+            remaining_s: lifetime,
+            total_s: lifetime,
+            initial_alpha: alpha,
+        },
+        Sprite::from_color(
+            Color::srgba(
+                lerp(0.52, 1.0, heat),
+                lerp(0.84, 0.72, heat),
+                lerp(1.0, 0.34, heat),
+                alpha,
+            ),
+            Vec2::splat(size),
+        ),
+        Transform::from_xyz(nozzle.x, nozzle.y, FEEDBACK_PARTICLE_Z_M + 0.01),
+    ));
+}
+
 fn spawn_smoke_plume_particles(
     commands: &mut Commands,
     world_position: Vec2,
     count: usize,
-    rng_state: &mut u64,
+    rng: &mut StreamRng,
 ) {
     for _ in 0..count {
-        let x_jitter = next_signed_unit_random(rng_state) * 0.45;
-        let y_jitter = next_unit_random(rng_state) * 0.24;
-        let vx = next_signed_unit_random(rng_state) * 1.9;
-        let vy = lerp(2.6, 7.2, next_unit_random(rng_state));
-        let size = lerp(0.12, 0.30, next_unit_random(rng_state));
-        let lifetime = lerp(0.28, 0.74, next_unit_random(rng_state));
-        let alpha = lerp(0.26, 0.52, next_unit_random(rng_state));
-        let shade = lerp(0.18, 0.36, next_unit_random(rng_state));
+        let x_jitter = rng.signed_unit() * 0.45;
+        let y_jitter = rng.unit() * 0.24;
+        let vx = rng.signed_unit() * 1.9;
+        let vy = lerp(2.6, 7.2, rng.unit());
+        let size = lerp(0.12, 0.30, rng.unit());
+        let lifetime = lerp(0.28, 0.74, rng.unit());
+        let alpha = lerp(0.26, 0.52, rng.unit());
+        let shade = lerp(0.18, 0.36, rng.unit());
 
         commands.spawn((
             Name::new("ImpactSmokeFx"),
@@ -856,17 +950,6 @@ fn spawn_smoke_plume_particles(
     }
 }
 
-fn next_signed_unit_random(seed: &mut u64) -> f32 {
-    (next_unit_random(seed) * 2.0) - 1.0
-}
-
-fn next_unit_random(seed: &mut u64) -> f32 {
-    *seed = seed
-        .wrapping_mul(6_364_136_223_846_793_005)
-        .wrapping_add(1_442_695_040_888_963_407);
-    ((*seed >> 32) as u32) as f32 / u32::MAX as f32
-}
-
 fn lerp(a: f32, b: f32, t: f32) -> f32 {
     a + ((b - a) * t.clamp(0.0, 1.0))
 }
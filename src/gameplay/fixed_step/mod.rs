@@ -0,0 +1,131 @@
+use crate::config::GameConfig;
+use crate::states::ActiveRun;
+use bevy::app::RunFixedMainLoopSystems;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+pub struct FixedStepGameplayPlugin;
+
+impl Plugin for FixedStepGameplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TimestepMode::Fixed {
+            dt: Time::<Fixed>::default().timestep().as_secs_f32(),
+            substeps: 1,
+        })
+        .configure_sets(
+            FixedUpdate,
+            (
+                GameplaySimSet::Input,
+                GameplaySimSet::Vehicle,
+                GameplaySimSet::Combat,
+                GameplaySimSet::Enemies,
+                GameplaySimSet::Pickups,
+                GameplaySimSet::Progression,
+            )
+                .chain()
+                // Rapier shares FixedUpdate; bodies spawned this tick must exist before it syncs.
+                .before(PhysicsSet::SyncBackend)
+                .run_if(in_state(ActiveRun))
+                .run_if(resource_exists::<GameConfig>),
+        )
+        .add_systems(
+            PreUpdate,
+            sync_fixed_timestep_from_config.run_if(resource_exists_and_changed::<GameConfig>),
+        )
+        .add_systems(FixedFirst, restore_fixed_step_transforms)
+        .add_systems(FixedLast, capture_fixed_step_transforms)
+        .add_systems(
+            RunFixedMainLoop,
+            interpolate_fixed_step_transforms.in_set(RunFixedMainLoopSystems::AfterFixedMainLoop),
+        );
+    }
+}
+
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameplaySimSet {
+    Input,
+    Vehicle,
+    Combat,
+    Enemies,
+    Pickups,
+    Progression,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FixedStepPose {
+    translation: Vec3,
+    rotation: Quat,
+}
+
+impl FixedStepPose {
+    fn of(transform: &Transform) -> Self {
+        Self {
+            translation: transform.translation,
+            rotation: transform.rotation,
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct FixedStepInterpolation {
+    previous: Option<FixedStepPose>,
+    current: Option<FixedStepPose>,
+    rendered: Option<FixedStepPose>,
+}
+
+fn sync_fixed_timestep_from_config(
+    config: Res<GameConfig>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+    mut timestep_mode: ResMut<TimestepMode>,
+) {
+    fixed_time.set_timestep_hz(config.game.app.fixed_timestep_hz as f64);
+    *timestep_mode = TimestepMode::Fixed {
+        dt: fixed_time.timestep().as_secs_f32(),
+        substeps: 1,
+    };
+}
+
+fn restore_fixed_step_transforms(mut query: Query<(&mut Transform, &mut FixedStepInterpolation)>) {
+    for (mut transform, mut interpolation) in &mut query {
+        let (Some(current), Some(rendered)) =
+            (interpolation.current, interpolation.rendered.take())
+        else {
+            continue;
+        };
+        if FixedStepPose::of(&transform) != rendered {
+            // Moved outside the fixed step (debug warp, etc.); treat it as a teleport.
+            let pose = FixedStepPose::of(&transform);
+            interpolation.previous = Some(pose);
+            interpolation.current = Some(pose);
+            continue;
+        }
+
+        transform.translation = current.translation;
+        transform.rotation = current.rotation;
+    }
+}
+
+fn capture_fixed_step_transforms(mut query: Query<(&Transform, &mut FixedStepInterpolation)>) {
+    for (transform, mut interpolation) in &mut query {
+        let pose = FixedStepPose::of(transform);
+        interpolation.previous = interpolation.current.or(Some(pose));
+        interpolation.current = Some(pose);
+    }
+}
+
+fn interpolate_fixed_step_transforms(
+    fixed_time: Res<Time<Fixed>>,
+    mut query: Query<(&mut Transform, &mut FixedStepInterpolation)>,
+) {
+    let alpha = fixed_time.overstep_fraction().clamp(0.0, 1.0);
+    for (mut transform, mut interpolation) in &mut query {
+        let (Some(previous), Some(current)) = (interpolation.previous, interpolation.current)
+        else {
+            continue;
+        };
+
+        transform.translation = previous.translation.lerp(current.translation, alpha);
+        transform.rotation = previous.rotation.slerp(current.rotation, alpha);
+        interpolation.rendered = Some(FixedStepPose::of(&transform));
+    }
+}
@@ -1,15 +1,19 @@
+pub mod autopilot;
 pub mod combat;
 pub mod enemies;
 pub mod feedback;
+pub mod fixed_step;
 pub mod pickups;
 pub mod sfx;
 pub mod upgrades;
 pub mod vehicle;
 
+use autopilot::AutopilotPlugin;
 use bevy::prelude::*;
 use combat::CombatGameplayPlugin;
 use enemies::EnemyGameplayPlugin;
 use feedback::FeedbackGameplayPlugin;
+use fixed_step::FixedStepGameplayPlugin;
 use pickups::PickupGameplayPlugin;
 use sfx::GameplaySfxPlugin;
 use upgrades::UpgradeGameplayPlugin;
@@ -19,12 +23,23 @@ pub struct GameplayPlugin;
 
 impl Plugin for GameplayPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(VehicleGameplayPlugin)
+        app.add_plugins(GameplaySimulationPlugin)
+            .add_plugins(GameplaySfxPlugin)
+            .add_plugins(FeedbackGameplayPlugin);
+    }
+}
+
+// Everything a run needs without audio or screen feedback; shared with headless runs.
+pub struct GameplaySimulationPlugin;
+
+impl Plugin for GameplaySimulationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(FixedStepGameplayPlugin)
+            .add_plugins(VehicleGameplayPlugin)
             .add_plugins(EnemyGameplayPlugin)
             .add_plugins(PickupGameplayPlugin)
             .add_plugins(UpgradeGameplayPlugin)
             .add_plugins(CombatGameplayPlugin)
-            .add_plugins(GameplaySfxPlugin)
-            .add_plugins(FeedbackGameplayPlugin);
+            .add_plugins(AutopilotPlugin);
     }
 }
@@ -1,11 +1,11 @@
-use crate::config::{GameConfig, PickupConfig};
+use crate::config::{GameConfig, PickupConfig, RunStats};
 use crate::gameplay::combat::EnemyKilledEvent;
-use crate::gameplay::vehicle::{PlayerHealth, PlayerVehicle};
-use crate::states::GameState;
+use crate::gameplay::fixed_step::{FixedStepInterpolation, GameplaySimSet};
+use crate::gameplay::vehicle::{PlayerFuel, PlayerHealth, PlayerVehicle};
+use crate::rng::{RngStream, RunRng, StreamRng};
+use crate::states::ActiveRun;
 use bevy::math::primitives::RegularPolygon;
 use bevy::prelude::*;
-#[cfg(not(target_arch = "wasm32"))]
-use std::time::{SystemTime, UNIX_EPOCH};
 
 const PICKUP_Z_M: f32 = 7.2;
 
@@ -13,20 +13,20 @@ pub struct PickupGameplayPlugin;
 
 impl Plugin for PickupGameplayPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<PickupRngState>()
-            .add_message::<PickupCollectedEvent>()
-            .add_systems(OnEnter(GameState::InRun), reset_pickup_rng_state)
-            .add_systems(OnExit(GameState::InRun), cleanup_pickups)
+        app.add_message::<PickupCollectedEvent>()
+            .init_resource::<FuelTrackState>()
+            .add_systems(OnEnter(ActiveRun), reset_fuel_track_state)
+            .add_systems(OnExit(ActiveRun), cleanup_pickups)
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     spawn_pickups_from_enemy_kills,
+                    spawn_track_fuel_cans,
                     simulate_pickups,
                     collect_pickups,
                 )
                     .chain()
-                    .run_if(in_state(GameState::InRun))
-                    .run_if(resource_exists::<GameConfig>),
+                    .in_set(GameplaySimSet::Pickups),
             );
     }
 }
@@ -35,6 +35,7 @@ impl Plugin for PickupGameplayPlugin {
 pub enum PickupKind {
     Coin,
     Health,
+    Fuel,
 }
 
 #[derive(Message, Debug, Clone, Copy)]
@@ -42,10 +43,12 @@ pub struct PickupCollectedEvent {
     pub kind: PickupKind,
     pub score_added: u32,
     pub health_restored: f32,
+    pub fuel_added: f32,
     pub world_position: Vec2,
 }
 
 #[derive(Component, Debug, Clone, Copy)]
+#[require(FixedStepInterpolation)]
 struct PickupDrop {
     kind: PickupKind,
     velocity_mps: Vec2,
@@ -57,21 +60,13 @@ struct PickupDrop {
     spin_speed_rad_s: f32,
 }
 
-#[derive(Resource, Debug, Clone, Copy)]
-struct PickupRngState {
-    seed: u64,
+#[derive(Resource, Debug, Clone, Default)]
+struct FuelTrackState {
+    next_can_x_m: f32,
 }
 
-impl Default for PickupRngState {
-    fn default() -> Self {
-        Self {
-            seed: 0xD40A_A15E_7D12_9321,
-        }
-    }
-}
-
-fn reset_pickup_rng_state(mut rng_state: ResMut<PickupRngState>) {
-    rng_state.seed ^= unix_timestamp_seconds();
+fn reset_fuel_track_state(mut state: ResMut<FuelTrackState>, run_stats: Res<RunStats>) {
+    state.next_can_x_m = run_stats.pickups.fuel_track_spacing_m;
 }
 
 fn cleanup_pickups(mut commands: Commands, pickup_query: Query<Entity, With<PickupDrop>>) {
@@ -83,15 +78,19 @@ fn cleanup_pickups(mut commands: Commands, pickup_query: Query<Entity, With<Pick
 #[allow(clippy::too_many_arguments)]
 fn spawn_pickups_from_enemy_kills(
     mut commands: Commands,
-    config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     mut kill_events: MessageReader<EnemyKilledEvent>,
-    mut rng_state: ResMut<PickupRngState>,
+    mut rng: ResMut<RunRng>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
-    let pickup_cfg = &config.game.pickups;
+    let pickup_cfg = &run_stats.pickups;
+    let player_has_fuel = run_stats
+        .player_vehicle()
+        .is_some_and(|vehicle| vehicle.fuel.is_some());
+    let rng = rng.stream(RngStream::Drops);
     for event in kill_events.read() {
-        let kill_score = config
+        let kill_score = run_stats
             .enemy_types_by_id
             .get(&event.enemy_type_id)
             .map(|enemy| enemy.kill_score)
@@ -104,22 +103,74 @@ fn spawn_pickups_from_enemy_kills(
             &mut commands,
             &mut meshes,
             &mut materials,
-            &mut rng_state.seed,
+            rng,
             pickup_cfg,
             event.world_position,
             coin_score_value,
         );
 
-        let health_roll = next_unit_random(&mut rng_state.seed);
+        let health_roll = rng.unit();
         if health_roll <= pickup_cfg.health_drop_chance {
             spawn_health_drop(
                 &mut commands,
-                &mut rng_state.seed,
+                rng,
                 pickup_cfg,
                 event.world_position,
                 pickup_cfg.health_drop_heal_amount,
             );
         }
+
+        // Vehicles without a fuel tank skip the roll so their drops stay unchanged.
+        if player_has_fuel && rng.unit() <= pickup_cfg.fuel_drop_chance {
+            let horizontal_velocity =
+                rng.signed_unit() * (pickup_cfg.drop_horizontal_spread_mps * 0.8);
+            let vertical_velocity = lerp(
+                pickup_cfg.drop_vertical_speed_min_mps,
+                pickup_cfg.drop_vertical_speed_max_mps,
+                rng.unit(),
+            );
+            spawn_fuel_can(
+                &mut commands,
+                pickup_cfg,
+                event.world_position,
+                Vec2::new(horizontal_velocity, vertical_velocity),
+                pickup_cfg.despawn_seconds,
+            );
+        }
+    }
+}
+
+// Track cans rest on the ground and only despawn once the player has passed them.
+fn spawn_track_fuel_cans(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
+    mut state: ResMut<FuelTrackState>,
+    player_query: Query<&Transform, With<PlayerVehicle>>,
+) {
+    let pickup_cfg = &run_stats.pickups;
+    let player_has_fuel = run_stats
+        .player_vehicle()
+        .is_some_and(|vehicle| vehicle.fuel.is_some());
+    if !player_has_fuel || pickup_cfg.fuel_track_spacing_m <= 0.0 {
+        return;
+    }
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    let spawn_until_x = player_transform.translation.x + pickup_cfg.fuel_track_spawn_ahead_m;
+    while state.next_can_x_m <= spawn_until_x {
+        let x = state.next_can_x_m;
+        let ground_y = terrain_height_at_x(&config, x) + (pickup_cfg.fuel_can_size_m * 0.5);
+        spawn_fuel_can(
+            &mut commands,
+            pickup_cfg,
+            Vec2::new(x, ground_y),
+            Vec2::ZERO,
+            f32::INFINITY,
+        );
+        state.next_can_x_m += pickup_cfg.fuel_track_spacing_m;
     }
 }
 
@@ -128,6 +179,7 @@ fn simulate_pickups(
     mut commands: Commands,
     time: Res<Time>,
     config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     player_query: Query<&Transform, (With<PlayerVehicle>, Without<PickupDrop>)>,
     mut pickup_query: Query<
         (Entity, &mut Transform, &mut PickupDrop),
@@ -135,7 +187,7 @@ fn simulate_pickups(
     >,
 ) {
     let dt = time.delta_secs().max(0.000_1);
-    let pickup_cfg = &config.game.pickups;
+    let pickup_cfg = &run_stats.pickups;
     let player_x = player_query
         .single()
         .map(|transform| transform.translation.x)
@@ -172,10 +224,10 @@ fn simulate_pickups(
 #[allow(clippy::type_complexity)]
 fn collect_pickups(
     mut commands: Commands,
-    config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     mut pickup_events: MessageWriter<PickupCollectedEvent>,
     mut player_query: Query<
-        (&Transform, &mut PlayerHealth),
+        (&Transform, &mut PlayerHealth, Option<&mut PlayerFuel>),
         (With<PlayerVehicle>, Without<PickupDrop>),
     >,
     pickup_query: Query<
@@ -183,7 +235,8 @@ fn collect_pickups(
         (With<PickupDrop>, Without<PlayerVehicle>),
     >,
 ) {
-    let Ok((player_transform, mut player_health)) = player_query.single_mut() else {
+    let Ok((player_transform, mut player_health, mut player_fuel)) = player_query.single_mut()
+    else {
         return;
     };
     let player_position = player_transform.translation.truncate();
@@ -191,13 +244,14 @@ fn collect_pickups(
     for (entity, pickup_transform, pickup) in &pickup_query {
         let pickup_position = pickup_transform.translation.truncate();
         if pickup_position.distance_squared(player_position)
-            > (config.game.pickups.collection_radius_m + pickup.pickup_radius_m).powi(2)
+            > (run_stats.pickups.collection_radius_m + pickup.pickup_radius_m).powi(2)
         {
             continue;
         }
 
         let mut score_added = 0;
         let mut health_restored = 0.0;
+        let mut fuel_added = 0.0;
         match pickup.kind {
             PickupKind::Coin => {
                 score_added = pickup.score_value.max(1);
@@ -210,12 +264,21 @@ fn collect_pickups(
                     health_restored = restore_amount;
                 }
             }
+            PickupKind::Fuel => {
+                if let Some(fuel) = player_fuel.as_deref_mut() {
+                    let refill = fuel.max * run_stats.pickups.fuel_refill_fraction;
+                    fuel_added = refill.min((fuel.max - fuel.current).max(0.0));
+                    fuel.current += fuel_added;
+                    fuel.stalled_s = 0.0;
+                }
+            }
         }
 
         pickup_events.write(PickupCollectedEvent {
             kind: pickup.kind,
             score_added,
             health_restored,
+            fuel_added,
             world_position: pickup_position,
         });
         commands.entity(entity).try_despawn();
@@ -226,25 +289,25 @@ fn spawn_coin_drop(
     commands: &mut Commands,
     meshes: &mut Assets<Mesh>,
     materials: &mut Assets<ColorMaterial>,
-    seed: &mut u64,
+    rng: &mut StreamRng,
     pickup_cfg: &PickupConfig,
     world_position: Vec2,
     score_value: u32,
 ) {
-    let horizontal_velocity = next_signed_unit_random(seed) * pickup_cfg.drop_horizontal_spread_mps;
+    let horizontal_velocity = rng.signed_unit() * pickup_cfg.drop_horizontal_spread_mps;
     let vertical_velocity = lerp(
         pickup_cfg.drop_vertical_speed_min_mps,
         pickup_cfg.drop_vertical_speed_max_mps,
-        next_unit_random(seed),
+        rng.unit(),
     );
     let jitter = Vec2::new(
-        next_signed_unit_random(seed) * pickup_cfg.coin_jitter_x_m,
+        rng.signed_unit() * pickup_cfg.coin_jitter_x_m,
         pickup_cfg.coin_jitter_y_m,
     );
     let spin_speed = lerp(
         pickup_cfg.coin_spin_speed_min_rad_s,
         pickup_cfg.coin_spin_speed_max_rad_s,
-        next_unit_random(seed),
+        rng.unit(),
     );
 
     commands.spawn((
@@ -271,26 +334,25 @@ fn spawn_coin_drop(
 
 fn spawn_health_drop(
     commands: &mut Commands,
-    seed: &mut u64,
+    rng: &mut StreamRng,
     pickup_cfg: &PickupConfig,
     world_position: Vec2,
     heal_amount: f32,
 ) {
-    let horizontal_velocity =
-        next_signed_unit_random(seed) * (pickup_cfg.drop_horizontal_spread_mps * 0.8);
+    let horizontal_velocity = rng.signed_unit() * (pickup_cfg.drop_horizontal_spread_mps * 0.8);
     let vertical_velocity = lerp(
         pickup_cfg.drop_vertical_speed_min_mps,
         pickup_cfg.drop_vertical_speed_max_mps,
-        next_unit_random(seed),
+        rng.unit(),
     );
     let jitter = Vec2::new(
-        next_signed_unit_random(seed) * pickup_cfg.health_jitter_x_m,
+        rng.signed_unit() * pickup_cfg.health_jitter_x_m,
         pickup_cfg.health_jitter_y_m,
     );
     let spin_speed = lerp(
         pickup_cfg.health_spin_speed_min_rad_s,
         pickup_cfg.health_spin_speed_max_rad_s,
-        next_unit_random(seed),
+        rng.unit(),
     );
     let health_box_size = Vec2::splat(pickup_cfg.health_box_size_m);
 
@@ -315,34 +377,39 @@ fn spawn_health_drop(
     ));
 }
 
-fn next_signed_unit_random(seed: &mut u64) -> f32 {
-    (next_unit_random(seed) * 2.0) - 1.0
-}
+fn spawn_fuel_can(
+    commands: &mut Commands,
+    pickup_cfg: &PickupConfig,
+    world_position: Vec2,
+    velocity_mps: Vec2,
+    lifetime_s: f32,
+) {
+    let can_size = Vec2::new(
+        pickup_cfg.fuel_can_size_m * 0.78,
+        pickup_cfg.fuel_can_size_m,
+    );
 
-fn next_unit_random(seed: &mut u64) -> f32 {
-    *seed = seed
-        .wrapping_mul(6_364_136_223_846_793_005)
-        .wrapping_add(1_442_695_040_888_963_407);
-    ((*seed >> 32) as u32) as f32 / u32::MAX as f32
+    commands.spawn((
+        Name::new("PickupFuel"),
+        PickupDrop {
+            kind: PickupKind::Fuel,
+            velocity_mps,
+            lifetime_s,
+            pickup_radius_m: pickup_cfg.fuel_pickup_radius_m,
+            ground_clearance_m: can_size.y * 0.5,
+            score_value: 0,
+            heal_amount: 0.0,
+            spin_speed_rad_s: 0.0,
+        },
+        Sprite::from_color(Color::srgb(0.88, 0.24, 0.16), can_size),
+        Transform::from_xyz(world_position.x, world_position.y, PICKUP_Z_M),
+    ));
 }
 
 fn terrain_height_at_x(config: &GameConfig, x: f32) -> f32 {
     config.terrain_height_at_x(x)
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-fn unix_timestamp_seconds() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs()
-}
-
-#[cfg(target_arch = "wasm32")]
-fn unix_timestamp_seconds() -> u64 {
-    0
-}
-
 fn lerp(a: f32, b: f32, t: f32) -> f32 {
     a + ((b - a) * t.clamp(0.0, 1.0))
 }
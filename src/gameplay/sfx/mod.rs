@@ -1,11 +1,12 @@
 use crate::assets::AssetRegistry;
-use crate::config::{GameConfig, SfxConfig};
+use crate::config::{GameConfig, RunStats, SfxConfig};
 use crate::gameplay::combat::{
     EnemyKilledEvent, PlayerProjectileAudioKind, PlayerProjectileImpactEvent,
     PlayerProjectileImpactTarget, PlayerWeaponFiredEvent,
 };
-use crate::gameplay::vehicle::{VehicleInputState, VehicleTelemetry};
-use crate::states::GameState;
+use crate::gameplay::vehicle::{VehicleInputState, VehicleNitroBoostEvent, VehicleTelemetry};
+use crate::rng::{RngStream, RunRng, StreamRng};
+use crate::states::{ActiveRun, GameState};
 use crate::web::{audio_playback_allowed, AudioUnlockState};
 use bevy::audio::{
     AudioPlayer, AudioSink, AudioSinkPlayback, AudioSource, PlaybackSettings, Volume,
@@ -15,8 +16,6 @@ use std::collections::{HashMap, HashSet};
 #[cfg(not(target_arch = "wasm32"))]
 use std::fs;
 use std::path::{Path, PathBuf};
-#[cfg(not(target_arch = "wasm32"))]
-use std::time::{SystemTime, UNIX_EPOCH};
 
 const AUDIO_ID_ENGINE_LOOP: &str = "sfx_engine_loop";
 const AUDIO_ID_EXPLODE: &str = "sfx_explode";
@@ -26,6 +25,7 @@ const AUDIO_ID_GUN_SHOT: &str = "sfx_gun_shot";
 const AUDIO_ID_MUSIC_LOOP: &str = "music_background_loop";
 const AUDIO_ID_MISSILE_HIT: &str = "sfx_missile_hit";
 const AUDIO_ID_MISSILE_LAUNCH: &str = "sfx_missile_launch";
+const AUDIO_ID_NITRO_BOOST: &str = "sfx_nitro_boost";
 
 const ENGINE_JITTER_REFRESH_MIN_S: f32 = 0.14;
 const ENGINE_JITTER_REFRESH_MAX_S: f32 = 0.36;
@@ -35,16 +35,11 @@ pub struct GameplaySfxPlugin;
 
 impl Plugin for GameplaySfxPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<SfxRngState>()
-            .init_resource::<SfxMissingAssetWarnings>()
+        app.init_resource::<SfxMissingAssetWarnings>()
             .init_resource::<SfxRuntimeAudioCache>()
             .add_systems(
-                OnEnter(GameState::InRun),
-                (
-                    reset_sfx_rng_state,
-                    clear_sfx_warnings,
-                    clear_sfx_audio_cache,
-                ),
+                OnEnter(ActiveRun),
+                (clear_sfx_warnings, clear_sfx_audio_cache),
             )
             .add_systems(
                 Update,
@@ -52,7 +47,7 @@ impl Plugin for GameplaySfxPlugin {
                     .chain()
                     .run_if(resource_exists::<GameConfig>),
             )
-            .add_systems(OnExit(GameState::InRun), cleanup_sfx_entities)
+            .add_systems(OnExit(ActiveRun), cleanup_sfx_entities)
             .add_systems(
                 Update,
                 (
@@ -61,7 +56,7 @@ impl Plugin for GameplaySfxPlugin {
                     play_gameplay_sfx,
                 )
                     .chain()
-                    .run_if(in_state(GameState::InRun))
+                    .run_if(in_state(ActiveRun))
                     .run_if(resource_exists::<GameConfig>),
             );
     }
@@ -108,19 +103,6 @@ impl Default for EngineLoopRuntime {
 #[derive(Component)]
 struct GameplaySfxTransient;
 
-#[derive(Resource, Debug, Clone, Copy)]
-struct SfxRngState {
-    seed: u64,
-}
-
-impl Default for SfxRngState {
-    fn default() -> Self {
-        Self {
-            seed: 0x7C5E_A48B_D113_90F2,
-        }
-    }
-}
-
 #[derive(Resource, Debug, Default)]
 struct SfxMissingAssetWarnings {
     missing_ids: HashSet<String>,
@@ -131,10 +113,6 @@ struct SfxRuntimeAudioCache {
     handles_by_id: HashMap<String, Handle<AudioSource>>,
 }
 
-fn reset_sfx_rng_state(mut rng: ResMut<SfxRngState>) {
-    rng.seed ^= unix_timestamp_seconds();
-}
-
 fn clear_sfx_warnings(mut warnings: ResMut<SfxMissingAssetWarnings>) {
     warnings.missing_ids.clear();
 }
@@ -287,9 +265,10 @@ fn ensure_engine_loop_audio(
 fn update_engine_loop_audio(
     time: Res<Time>,
     config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     input: Res<VehicleInputState>,
     telemetry: Res<VehicleTelemetry>,
-    mut rng: ResMut<SfxRngState>,
+    mut rng: ResMut<RunRng>,
     mut engine_query: Query<(&mut AudioSink, &mut EngineLoopRuntime), With<EngineLoopAudio>>,
 ) {
     if !config.game.sfx.enabled {
@@ -297,9 +276,10 @@ fn update_engine_loop_audio(
     }
 
     let dt = time.delta_secs().max(0.000_1);
+    let rng = rng.stream(RngStream::CosmeticFx);
     let vehicle_max_speed = config
         .vehicles_by_id
-        .get(&config.game.app.default_vehicle)
+        .get(&run_stats.player_vehicle_id)
         .map(|vehicle| vehicle.max_forward_speed.max(1.0))
         .unwrap_or(120.0);
     let speed_norm = (telemetry.speed_mps.abs() / vehicle_max_speed).clamp(0.0, 1.0);
@@ -316,10 +296,9 @@ fn update_engine_loop_audio(
             runtime.pitch_jitter_refresh_s = lerp(
                 ENGINE_JITTER_REFRESH_MIN_S,
                 ENGINE_JITTER_REFRESH_MAX_S,
-                next_unit_random(&mut rng.seed),
+                rng.unit(),
             );
-            runtime.pitch_jitter_target =
-                next_signed_unit_random(&mut rng.seed) * config.game.sfx.engine_pitch_jitter;
+            runtime.pitch_jitter_target = rng.signed_unit() * config.game.sfx.engine_pitch_jitter;
         }
         runtime.pitch_jitter_current = runtime
             .pitch_jitter_current
@@ -346,16 +325,18 @@ fn play_gameplay_sfx(
     registry: Option<Res<AssetRegistry>>,
     mut audio_sources: ResMut<Assets<AudioSource>>,
     mut runtime_audio_cache: ResMut<SfxRuntimeAudioCache>,
-    mut rng: ResMut<SfxRngState>,
+    mut rng: ResMut<RunRng>,
     mut warnings: ResMut<SfxMissingAssetWarnings>,
     mut fired_events: MessageReader<PlayerWeaponFiredEvent>,
     mut impact_events: MessageReader<PlayerProjectileImpactEvent>,
     mut killed_events: MessageReader<EnemyKilledEvent>,
+    mut nitro_boost_events: MessageReader<VehicleNitroBoostEvent>,
 ) {
     if !config.game.sfx.enabled || !audio_playback_allowed(&config, audio_unlock.as_deref()) {
         let _ = fired_events.read().count();
         let _ = impact_events.read().count();
         let _ = killed_events.read().count();
+        let _ = nitro_boost_events.read().count();
         return;
     }
 
@@ -363,10 +344,12 @@ fn play_gameplay_sfx(
         let _ = fired_events.read().count();
         let _ = impact_events.read().count();
         let _ = killed_events.read().count();
+        let _ = nitro_boost_events.read().count();
         return;
     };
 
     let sfx = &config.game.sfx;
+    let rng = rng.stream(RngStream::CosmeticFx);
 
     for event in fired_events.read() {
         let _shot_position = event.world_position;
@@ -379,7 +362,7 @@ fn play_gameplay_sfx(
                 sfx,
                 AUDIO_ID_GUN_SHOT,
                 sfx.gun_shot_volume,
-                &mut rng.seed,
+                rng,
                 &mut warnings,
             ),
             PlayerProjectileAudioKind::Missile => play_sfx_by_id(
@@ -390,7 +373,7 @@ fn play_gameplay_sfx(
                 sfx,
                 AUDIO_ID_MISSILE_LAUNCH,
                 sfx.missile_launch_volume,
-                &mut rng.seed,
+                rng,
                 &mut warnings,
             ),
         }
@@ -408,7 +391,7 @@ fn play_gameplay_sfx(
                     sfx,
                     AUDIO_ID_GUN_HIT,
                     sfx.gun_hit_volume,
-                    &mut rng.seed,
+                    rng,
                     &mut warnings,
                 );
             }
@@ -421,7 +404,7 @@ fn play_gameplay_sfx(
                     sfx,
                     AUDIO_ID_GUN_MISS,
                     sfx.gun_miss_volume,
-                    &mut rng.seed,
+                    rng,
                     &mut warnings,
                 );
             }
@@ -434,7 +417,7 @@ fn play_gameplay_sfx(
                     sfx,
                     AUDIO_ID_MISSILE_HIT,
                     sfx.missile_hit_volume,
-                    &mut rng.seed,
+                    rng,
                     &mut warnings,
                 );
             }
@@ -450,7 +433,21 @@ fn play_gameplay_sfx(
             sfx,
             AUDIO_ID_EXPLODE,
             sfx.explode_volume,
-            &mut rng.seed,
+            rng,
+            &mut warnings,
+        );
+    }
+
+    for _ in nitro_boost_events.read() {
+        play_sfx_by_id(
+            &mut commands,
+            registry.as_ref(),
+            &mut audio_sources,
+            &mut runtime_audio_cache,
+            sfx,
+            AUDIO_ID_NITRO_BOOST,
+            sfx.nitro_boost_volume,
+            rng,
             &mut warnings,
         );
     }
@@ -465,7 +462,7 @@ fn play_sfx_by_id(
     sfx: &SfxConfig,
     audio_id: &str,
     relative_volume: f32,
-    seed: &mut u64,
+    rng: &mut StreamRng,
     warnings: &mut SfxMissingAssetWarnings,
 ) {
     let Some(handle) = resolve_runtime_audio_handle(
@@ -483,12 +480,7 @@ fn play_sfx_by_id(
         return;
     }
 
-    let pitch = lerp(
-        sfx.pitch_random_min,
-        sfx.pitch_random_max,
-        next_unit_random(seed),
-    )
-    .max(0.01);
+    let pitch = lerp(sfx.pitch_random_min, sfx.pitch_random_max, rng.unit()).max(0.01);
 
     commands.spawn((
         Name::new("GameplaySfxShot"),
@@ -628,7 +620,7 @@ fn embedded_wasm_audio_bytes(audio_id: &str) -> Option<&'static [u8]> {
         AUDIO_ID_GUN_MISS => Some(include_bytes!("../../../assets/audio/sfx/gun_miss.wav")),
         AUDIO_ID_GUN_SHOT => Some(include_bytes!("../../../assets/audio/sfx/gun_shot.wav")),
         AUDIO_ID_MISSILE_HIT => Some(include_bytes!("../../../assets/audio/sfx/missile_hit.wav")),
-        AUDIO_ID_MISSILE_LAUNCH => Some(include_bytes!(
+        AUDIO_ID_MISSILE_LAUNCH | AUDIO_ID_NITRO_BOOST => Some(include_bytes!(
             "../../../assets/audio/sfx/missile_launch.wav"
         )),
         AUDIO_ID_MUSIC_LOOP => Some(include_bytes!("../../../assets/audio/music.wav")),
@@ -784,30 +776,6 @@ fn normalize_wav_linear_pcm_header_fields(bytes: &mut [u8]) -> Result<(), String
     Ok(())
 }
 
-fn next_unit_random(seed: &mut u64) -> f32 {
-    *seed = seed
-        .wrapping_mul(6_364_136_223_846_793_005)
-        .wrapping_add(1_442_695_040_888_963_407);
-    ((*seed >> 32) as u32) as f32 / u32::MAX as f32
-}
-
-fn next_signed_unit_random(seed: &mut u64) -> f32 {
-    (next_unit_random(seed) * 2.0) - 1.0
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn unix_timestamp_seconds() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs()
-}
-
-#[cfg(target_arch = "wasm32")]
-fn unix_timestamp_seconds() -> u64 {
-    0
-}
-
 fn lerp(a: f32, b: f32, t: f32) -> f32 {
     a + ((b - a) * t.clamp(0.0, 1.0))
 }
@@ -1,15 +1,20 @@
-use crate::config::{GameConfig, RunUpgradeEffectKind, RunUpgradeOptionConfig};
+use crate::config::{
+    GameConfig, RunStats, RunUpgradeEffectKind, RunUpgradeOptionConfig, StatModifier,
+    StatModifierOp, StatPath, StatTargetKind,
+};
+use crate::gameplay::autopilot::Autopilot;
+use crate::gameplay::fixed_step::GameplaySimSet;
 use crate::gameplay::pickups::{PickupCollectedEvent, PickupKind};
 use crate::gameplay::vehicle::{PlayerHealth, PlayerVehicle};
-use crate::states::GameState;
+use crate::replay::{ReplayPlayback, ReplayRecorder, ReplayUpgradeDecision};
+use crate::rng::{RngStream, RunRng, StreamRng};
+use crate::states::ActiveRun;
 use crate::web::VirtualControlState;
 use bevy::prelude::*;
-use bevy::time::{Real, Virtual};
+use bevy::time::{Fixed, Real, Virtual};
 use std::collections::HashMap;
-#[cfg(not(target_arch = "wasm32"))]
-use std::time::{SystemTime, UNIX_EPOCH};
 
-const MAX_VISIBLE_UPGRADE_CHOICES: usize = 2;
+const MAX_VISIBLE_UPGRADE_CHOICES: usize = 3;
 const UPGRADE_SELECTION_CONFIRM_SECONDS: f32 = 0.4;
 
 pub struct UpgradeGameplayPlugin;
@@ -17,9 +22,11 @@ pub struct UpgradeGameplayPlugin;
 impl Plugin for UpgradeGameplayPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<UpgradeProgressState>()
+            .init_resource::<RunModifierStack>()
             .add_message::<UpgradeAppliedEvent>()
+            .add_message::<UpgradeRevertedEvent>()
             .add_systems(
-                OnEnter(GameState::InRun),
+                OnEnter(ActiveRun),
                 (
                     reset_upgrade_progress_state,
                     spawn_upgrade_offer_ui,
@@ -27,20 +34,32 @@ impl Plugin for UpgradeGameplayPlugin {
                 ),
             )
             .add_systems(
-                OnExit(GameState::InRun),
-                (cleanup_upgrade_offer_ui, resume_game_time_for_upgrades),
+                OnExit(ActiveRun),
+                (
+                    cleanup_upgrade_offer_ui,
+                    reset_run_modifier_stack,
+                    resume_game_time_for_upgrades,
+                ),
+            )
+            .add_systems(
+                Update,
+                rebuild_run_stats_on_config_change.run_if(resource_exists::<GameConfig>),
+            )
+            .add_systems(
+                FixedUpdate,
+                track_coin_progress_and_open_offer.in_set(GameplaySimSet::Progression),
             )
             .add_systems(
                 Update,
                 (
-                    track_coin_progress_and_open_offer,
                     sync_upgrade_pause_time,
                     handle_upgrade_offer_input,
                     update_upgrade_selection_confirmation,
                     update_upgrade_offer_ui,
+                    debug_run_modifier_hotkeys,
                 )
                     .chain()
-                    .run_if(in_state(GameState::InRun))
+                    .run_if(in_state(ActiveRun))
                     .run_if(resource_exists::<GameConfig>),
             );
     }
@@ -55,6 +74,49 @@ pub struct UpgradeAppliedEvent {
     pub effect_summary: String,
 }
 
+#[derive(Message, Debug, Clone)]
+pub struct UpgradeRevertedEvent {
+    pub upgrade_id: String,
+    pub stack: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct RunModifierEntry {
+    pub upgrade_id: String,
+    pub label: String,
+    pub modifiers: Vec<StatModifier>,
+}
+
+#[derive(Resource, Debug, Clone, Default)]
+pub struct RunModifierStack {
+    entries: Vec<RunModifierEntry>,
+}
+
+impl RunModifierStack {
+    pub fn modifiers(&self) -> impl Iterator<Item = &StatModifier> {
+        self.entries.iter().flat_map(|entry| entry.modifiers.iter())
+    }
+
+    pub fn summary_lines(&self) -> Vec<String> {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let modifiers = entry
+                    .modifiers
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    "#{index} {} [{}]: {modifiers}",
+                    entry.label, entry.upgrade_id
+                )
+            })
+            .collect()
+    }
+}
+
 #[derive(Component)]
 struct UpgradeOfferRoot;
 
@@ -71,12 +133,38 @@ struct UpgradeOfferCardTextSlot {
     slot: usize,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UpgradeOfferAction {
+    Reroll,
+    Skip,
+}
+
+const UPGRADE_OFFER_ACTIONS: [UpgradeOfferAction; 2] =
+    [UpgradeOfferAction::Reroll, UpgradeOfferAction::Skip];
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+struct UpgradeOfferActionSlot {
+    action: UpgradeOfferAction,
+}
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+struct UpgradeOfferActionTextSlot {
+    action: UpgradeOfferAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UpgradeOfferInput {
+    Next,
+    Confirm,
+}
+
 #[derive(Debug, Clone)]
 struct UpgradeOfferChoice {
     id: String,
     label: String,
-    effect: RunUpgradeEffectKind,
-    value: f32,
+    description: String,
+    rarity: String,
+    modifiers: Vec<StatModifier>,
     max_stacks: u32,
 }
 
@@ -94,35 +182,80 @@ struct UpgradeSelectionConfirmation {
 #[derive(Resource, Debug, Clone)]
 struct UpgradeProgressState {
     total_coin_pickups: u32,
+    coins_spent: u32,
     next_offer_coin_threshold: u32,
     stack_counts: HashMap<String, u32>,
     pending_offer: Option<PendingUpgradeOffer>,
+    cursor: usize,
     selection_confirmation: Option<UpgradeSelectionConfirmation>,
     wait_for_fresh_selection_input: bool,
-    rng_state: u64,
 }
 
 impl Default for UpgradeProgressState {
     fn default() -> Self {
         Self {
             total_coin_pickups: 0,
+            coins_spent: 0,
             next_offer_coin_threshold: 5,
             stack_counts: HashMap::new(),
             pending_offer: None,
+            cursor: 0,
             selection_confirmation: None,
             wait_for_fresh_selection_input: false,
-            rng_state: 0xD94A_4B53_9E13_BC87,
         }
     }
 }
 
+impl UpgradeProgressState {
+    fn available_coins(&self) -> u32 {
+        self.total_coin_pickups.saturating_sub(self.coins_spent)
+    }
+}
+
 fn reset_upgrade_progress_state(mut state: ResMut<UpgradeProgressState>, config: Res<GameConfig>) {
-    let mut next = UpgradeProgressState {
+    *state = UpgradeProgressState {
         next_offer_coin_threshold: config.game.run_upgrades.coins_per_offer.max(1),
         ..Default::default()
     };
-    next.rng_state ^= unix_timestamp_seconds();
-    *state = next;
+}
+
+fn reset_run_modifier_stack(
+    config: Option<Res<GameConfig>>,
+    mut stack: ResMut<RunModifierStack>,
+    run_stats: Option<ResMut<RunStats>>,
+) {
+    stack.entries.clear();
+    if let (Some(config), Some(mut run_stats)) = (config, run_stats) {
+        *run_stats = RunStats::for_vehicle(&config, &run_stats.player_vehicle_id);
+    }
+}
+
+fn rebuild_run_stats_on_config_change(
+    config: Res<GameConfig>,
+    stack: Res<RunModifierStack>,
+    mut run_stats: ResMut<RunStats>,
+    mut player_query: Query<&mut PlayerHealth, With<PlayerVehicle>>,
+) {
+    if !config.is_changed() || config.is_added() {
+        return;
+    }
+
+    let previous_health = run_stats.player_vehicle().map(|vehicle| vehicle.health);
+    let (rebuilt, errors) =
+        RunStats::rebuild(&config, &run_stats.player_vehicle_id, stack.modifiers());
+    for error in &errors {
+        warn!("Run modifier no longer applies after config change: {error}");
+    }
+    *run_stats = rebuilt;
+    if let (Some(previous), Some(vehicle)) = (previous_health, run_stats.player_vehicle()) {
+        sync_player_max_health(&mut player_query, vehicle.health - previous);
+    }
+    if !stack.entries.is_empty() {
+        info!(
+            "Rebuilt run stats from updated config with {} upgrade stacks.",
+            stack.entries.len()
+        );
+    }
 }
 
 fn resume_game_time_for_upgrades(mut time: ResMut<Time<Virtual>>) {
@@ -222,6 +355,43 @@ fn spawn_upgrade_offer_ui(
                                     });
                             }
                         });
+                    panel
+                        .spawn((
+                            Name::new("UpgradeOfferActionsRow"),
+                            Node {
+                                width: Val::Percent(100.0),
+                                justify_content: JustifyContent::Center,
+                                column_gap: Val::Px(12.0),
+                                ..default()
+                            },
+                        ))
+                        .with_children(|actions_row| {
+                            for action in UPGRADE_OFFER_ACTIONS {
+                                actions_row
+                                    .spawn((
+                                        Name::new("UpgradeOfferAction"),
+                                        UpgradeOfferActionSlot { action },
+                                        Node {
+                                            padding: UiRect::axes(Val::Px(14.0), Val::Px(8.0)),
+                                            border: UiRect::all(Val::Px(1.0)),
+                                            ..default()
+                                        },
+                                        BackgroundColor(Color::srgba(0.13, 0.17, 0.22, 0.98)),
+                                        BorderColor::all(Color::srgba(0.56, 0.66, 0.74, 0.95)),
+                                    ))
+                                    .with_children(|button| {
+                                        button.spawn((
+                                            UpgradeOfferActionTextSlot { action },
+                                            Text::new(""),
+                                            TextFont {
+                                                font_size: 16.0,
+                                                ..default()
+                                            },
+                                            TextColor(Color::srgb(0.93, 0.96, 1.00)),
+                                        ));
+                                    });
+                            }
+                        });
                 });
         });
 }
@@ -237,7 +407,10 @@ fn cleanup_upgrade_offer_ui(
 
 fn track_coin_progress_and_open_offer(
     config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
+    mut rng: ResMut<RunRng>,
     mut state: ResMut<UpgradeProgressState>,
+    mut fixed_time: ResMut<Time<Fixed>>,
     mut pickup_events: MessageReader<PickupCollectedEvent>,
 ) {
     for event in pickup_events.read() {
@@ -259,8 +432,13 @@ fn track_coin_progress_and_open_offer(
         }
 
         let stack_counts = state.stack_counts.clone();
-        let Some(offer) = roll_upgrade_offer(run_upgrade_cfg, &stack_counts, &mut state.rng_state)
-        else {
+        let Some(offer) = roll_upgrade_offer(
+            &config,
+            &run_stats.player_vehicle_id,
+            &stack_counts,
+            None,
+            rng.stream(RngStream::Upgrades),
+        ) else {
             continue;
         };
 
@@ -270,7 +448,11 @@ fn track_coin_progress_and_open_offer(
             offer.choices.len()
         );
         state.pending_offer = Some(offer);
+        state.cursor = 0;
         state.wait_for_fresh_selection_input = true;
+        // Stop the fixed loop here so the offer opens on the same tick in live runs and replays.
+        let overstep = fixed_time.overstep();
+        fixed_time.discard_overstep(overstep);
         break;
     }
 }
@@ -292,8 +474,14 @@ fn sync_upgrade_pause_time(state: Res<UpgradeProgressState>, mut time: ResMut<Ti
 fn handle_upgrade_offer_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     virtual_controls: Option<Res<VirtualControlState>>,
-    mut config: ResMut<GameConfig>,
+    config: Res<GameConfig>,
+    mut rng: ResMut<RunRng>,
+    mut run_stats: ResMut<RunStats>,
     mut state: ResMut<UpgradeProgressState>,
+    mut modifier_stack: ResMut<RunModifierStack>,
+    mut playback: Option<ResMut<ReplayPlayback>>,
+    mut recorder: ResMut<ReplayRecorder>,
+    autopilot: Option<Res<Autopilot>>,
     mut player_query: Query<&mut PlayerHealth, With<PlayerVehicle>>,
     mut applied_events: MessageWriter<UpgradeAppliedEvent>,
 ) {
@@ -306,21 +494,54 @@ fn handle_upgrade_offer_input(
         return;
     };
 
-    if state.wait_for_fresh_selection_input {
-        if selection_keys_held(&keyboard, virtual_controls.as_deref()) {
-            return;
+    let selected_index = match playback.as_deref_mut() {
+        // Bot runs take the first card; offers are already rolled by rarity weight.
+        _ if autopilot.is_some() => 0,
+        Some(playback) => {
+            let Some(selected_index) = replay_offer_selection(playback, &offer) else {
+                return;
+            };
+            state.cursor = selected_index;
+            selected_index
         }
-        state.wait_for_fresh_selection_input = false;
-        return;
-    }
+        None => {
+            if state.wait_for_fresh_selection_input {
+                if selection_keys_held(&keyboard, virtual_controls.as_deref()) {
+                    return;
+                }
+                state.wait_for_fresh_selection_input = false;
+                return;
+            }
 
-    let Some(selected_index) = selected_upgrade_index_from_input(
-        &keyboard,
-        virtual_controls.as_deref(),
-        offer.choices.len(),
-    ) else {
-        return;
+            let Some(input) = upgrade_offer_input(&keyboard, virtual_controls.as_deref()) else {
+                return;
+            };
+            if input == UpgradeOfferInput::Next {
+                state.cursor =
+                    (state.cursor + 1) % (offer.choices.len() + UPGRADE_OFFER_ACTIONS.len());
+                return;
+            }
+            state.cursor
+        }
     };
+
+    if let Some(action) = offer_action_at_cursor(selected_index, offer.choices.len()) {
+        let applied = handle_upgrade_offer_action(
+            action,
+            &config,
+            &run_stats.player_vehicle_id,
+            rng.stream(RngStream::Upgrades),
+            &mut state,
+            &offer,
+        );
+        if applied {
+            recorder.record_upgrade_decision(match action {
+                UpgradeOfferAction::Reroll => ReplayUpgradeDecision::Reroll,
+                UpgradeOfferAction::Skip => ReplayUpgradeDecision::Skip,
+            });
+        }
+        return;
+    }
     let Some(choice) = offer.choices.get(selected_index).cloned() else {
         return;
     };
@@ -330,10 +551,15 @@ fn handle_upgrade_offer_input(
         return;
     }
 
-    match apply_upgrade_choice(&choice, &mut config, &mut player_query) {
+    match apply_stat_modifiers(&choice.modifiers, &mut run_stats, &mut player_query) {
         Ok(effect_summary) => {
             let stack = current_stacks.saturating_add(1);
             state.stack_counts.insert(choice.id.clone(), stack);
+            modifier_stack.entries.push(RunModifierEntry {
+                upgrade_id: choice.id.clone(),
+                label: choice.label.clone(),
+                modifiers: choice.modifiers.clone(),
+            });
             state.wait_for_fresh_selection_input = false;
             state.selection_confirmation = Some(UpgradeSelectionConfirmation {
                 selected_index,
@@ -343,6 +569,7 @@ fn handle_upgrade_offer_input(
                 "Applied upgrade `{}` (stack {stack}/{}): {}",
                 choice.label, choice.max_stacks, effect_summary
             );
+            recorder.record_upgrade_decision(ReplayUpgradeDecision::Pick(choice.id.clone()));
             applied_events.write(UpgradeAppliedEvent {
                 upgrade_id: choice.id,
                 label: choice.label,
@@ -356,6 +583,103 @@ fn handle_upgrade_offer_input(
     }
 }
 
+fn handle_upgrade_offer_action(
+    action: UpgradeOfferAction,
+    config: &GameConfig,
+    player_vehicle_id: &str,
+    rng: &mut StreamRng,
+    state: &mut UpgradeProgressState,
+    offer: &PendingUpgradeOffer,
+) -> bool {
+    let cost = upgrade_offer_action_cost(action, config);
+    if state.available_coins() < cost {
+        info!(
+            "Not enough coins to {} the upgrade offer ({} needed, {} available).",
+            upgrade_offer_action_verb(action),
+            cost,
+            state.available_coins()
+        );
+        return false;
+    }
+
+    match action {
+        UpgradeOfferAction::Reroll => {
+            let stack_counts = state.stack_counts.clone();
+            let Some(rerolled) =
+                roll_upgrade_offer(config, player_vehicle_id, &stack_counts, Some(offer), rng)
+            else {
+                return false;
+            };
+            state.pending_offer = Some(rerolled);
+        }
+        UpgradeOfferAction::Skip => {
+            state.pending_offer = None;
+        }
+    }
+    state.coins_spent = state.coins_spent.saturating_add(cost);
+    state.cursor = 0;
+    info!(
+        "Upgrade offer {} for {cost} coins ({} left).",
+        match action {
+            UpgradeOfferAction::Reroll => "rerolled",
+            UpgradeOfferAction::Skip => "skipped",
+        },
+        state.available_coins()
+    );
+    true
+}
+
+fn replay_offer_selection(
+    playback: &mut ReplayPlayback,
+    offer: &PendingUpgradeOffer,
+) -> Option<usize> {
+    // A pick that is not on offer stays queued rather than being dropped, so the
+    // rest of the replay does not shift onto the wrong offers.
+    let action = match playback.peek_upgrade_decision()?.clone() {
+        ReplayUpgradeDecision::Pick(upgrade_id) => {
+            let Some(selected_index) = offer
+                .choices
+                .iter()
+                .position(|choice| choice.id == upgrade_id)
+            else {
+                playback.report_upgrade_desync(&format!(
+                    "picked upgrade `{upgrade_id}`, which is not in the current offer"
+                ));
+                return None;
+            };
+            playback.next_upgrade_decision();
+            return Some(selected_index);
+        }
+        ReplayUpgradeDecision::Reroll => UpgradeOfferAction::Reroll,
+        ReplayUpgradeDecision::Skip => UpgradeOfferAction::Skip,
+    };
+    playback.next_upgrade_decision();
+    UPGRADE_OFFER_ACTIONS
+        .iter()
+        .position(|candidate| *candidate == action)
+        .map(|index| offer.choices.len() + index)
+}
+
+fn offer_action_at_cursor(cursor: usize, choice_count: usize) -> Option<UpgradeOfferAction> {
+    cursor
+        .checked_sub(choice_count)
+        .and_then(|index| UPGRADE_OFFER_ACTIONS.get(index).copied())
+}
+
+fn upgrade_offer_action_cost(action: UpgradeOfferAction, config: &GameConfig) -> u32 {
+    match action {
+        UpgradeOfferAction::Reroll => config.game.run_upgrades.reroll_cost_coins,
+        UpgradeOfferAction::Skip => config.game.run_upgrades.skip_cost_coins,
+    }
+}
+
+fn upgrade_offer_action_verb(action: UpgradeOfferAction) -> &'static str {
+    match action {
+        UpgradeOfferAction::Reroll => "reroll",
+        UpgradeOfferAction::Skip => "skip",
+    }
+}
+
 fn update_upgrade_selection_confirmation(
     time: Res<Time<Real>>,
     mut state: ResMut<UpgradeProgressState>,
@@ -372,13 +696,16 @@ fn update_upgrade_selection_confirmation(
     }
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn update_upgrade_offer_ui(
+    config: Res<GameConfig>,
     state: Res<UpgradeProgressState>,
     mut root_query: Query<&mut Visibility, With<UpgradeOfferRoot>>,
     mut header_query: Query<&mut Text, With<UpgradeOfferHeaderText>>,
     mut card_query: Query<
         (
             &UpgradeOfferCardSlot,
+            &mut Node,
             &mut Visibility,
             &mut BackgroundColor,
             &mut BorderColor,
@@ -389,6 +716,21 @@ fn update_upgrade_offer_ui(
         (&UpgradeOfferCardTextSlot, &mut Text),
         Without<UpgradeOfferHeaderText>,
     >,
+    mut action_query: Query<
+        (
+            &UpgradeOfferActionSlot,
+            &mut BackgroundColor,
+            &mut BorderColor,
+        ),
+        Without<UpgradeOfferCardSlot>,
+    >,
+    mut action_text_query: Query<
+        (&UpgradeOfferActionTextSlot, &mut Text, &mut TextColor),
+        (
+            Without<UpgradeOfferHeaderText>,
+            Without<UpgradeOfferCardTextSlot>,
+        ),
+    >,
 ) {
     let Ok(mut root_visibility) = root_query.single_mut() else {
         return;
@@ -407,12 +749,17 @@ fn update_upgrade_offer_ui(
         .selection_confirmation
         .as_ref()
         .map(|confirm| confirm.selected_index);
+    let cursor_slot = selected_slot.is_none().then_some(state.cursor);
     *header = Text::new(format!(
-        "Choose Upgrade (game paused)\nPress A/D or Left/Right    Coins: {}    Next Offer: {}",
-        state.total_coin_pickups, state.next_offer_coin_threshold
+        "Choose Upgrade (game paused)\nA/Left: next option    D/Right: confirm    Coins: {}    Next Offer In: {}",
+        state.available_coins(),
+        state
+            .next_offer_coin_threshold
+            .saturating_sub(state.total_coin_pickups)
     ));
 
-    for (slot, mut visibility, mut bg, mut border) in &mut card_query {
+    let card_width_percent = (96.0 / offer.choices.len().max(1) as f32).min(48.0);
+    for (slot, mut node, mut visibility, mut bg, mut border) in &mut card_query {
         *visibility = if slot.slot < offer.choices.len() {
             Visibility::Inherited
         } else {
@@ -421,6 +768,7 @@ fn update_upgrade_offer_ui(
         if *visibility == Visibility::Hidden {
             continue;
         }
+        node.width = Val::Percent(card_width_percent);
 
         if Some(slot.slot) == selected_slot {
             *bg = BackgroundColor(Color::srgba(0.24, 0.35, 0.22, 0.98));
@@ -428,310 +776,389 @@ fn update_upgrade_offer_ui(
         } else if selected_slot.is_some() {
             *bg = BackgroundColor(Color::srgba(0.11, 0.14, 0.17, 0.82));
             *border = BorderColor::all(Color::srgba(0.28, 0.34, 0.38, 0.75));
+        } else if Some(slot.slot) == cursor_slot {
+            *bg = BackgroundColor(Color::srgba(0.18, 0.24, 0.31, 0.98));
+            *border = BorderColor::all(Color::srgba(0.96, 0.86, 0.45, 0.98));
         } else {
             *bg = BackgroundColor(Color::srgba(0.13, 0.17, 0.22, 0.98));
-            *border = BorderColor::all(Color::srgba(0.56, 0.66, 0.74, 0.95));
+            *border = BorderColor::all(
+                offer
+                    .choices
+                    .get(slot.slot)
+                    .map_or(Color::srgba(0.56, 0.66, 0.74, 0.95), |choice| {
+                        rarity_border_color(&choice.rarity)
+                    }),
+            );
         }
     }
 
     for (slot, mut text) in &mut card_text_query {
         if let Some(choice) = offer.choices.get(slot.slot) {
-            *text = Text::new(build_card_text(slot.slot, choice, &state.stack_counts));
+            *text = Text::new(build_card_text(choice, &state.stack_counts));
         } else {
             *text = Text::new("");
         }
     }
+
+    let action_cursor =
+        cursor_slot.and_then(|cursor| offer_action_at_cursor(cursor, offer.choices.len()));
+    for (slot, mut bg, mut border) in &mut action_query {
+        if action_cursor == Some(slot.action) {
+            *bg = BackgroundColor(Color::srgba(0.18, 0.24, 0.31, 0.98));
+            *border = BorderColor::all(Color::srgba(0.96, 0.86, 0.45, 0.98));
+        } else {
+            *bg = BackgroundColor(Color::srgba(0.13, 0.17, 0.22, 0.98));
+            *border = BorderColor::all(Color::srgba(0.56, 0.66, 0.74, 0.95));
+        }
+    }
+
+    for (slot, mut text, mut color) in &mut action_text_query {
+        let cost = upgrade_offer_action_cost(slot.action, &config);
+        let label = match slot.action {
+            UpgradeOfferAction::Reroll => "Reroll",
+            UpgradeOfferAction::Skip => "Skip",
+        };
+        *text = Text::new(format!("{label} ({cost} coins)"));
+        *color = if state.available_coins() >= cost {
+            TextColor(Color::srgb(0.93, 0.96, 1.00))
+        } else {
+            TextColor(Color::srgb(0.48, 0.52, 0.56))
+        };
+    }
 }
 
-fn build_card_text(
-    slot: usize,
-    choice: &UpgradeOfferChoice,
-    stack_counts: &HashMap<String, u32>,
-) -> String {
+fn build_card_text(choice: &UpgradeOfferChoice, stack_counts: &HashMap<String, u32>) -> String {
     let current_stacks = stack_counts.get(&choice.id).copied().unwrap_or(0);
-    let slot_hint = if slot == 0 { "LEFT" } else { "RIGHT" };
     format!(
-        "{slot_hint}\n\n{}\n{}\n\nStacks: {}/{}",
+        "{}\n\n{}\n{}\n\nStacks: {}/{}",
+        choice.rarity.to_uppercase(),
         choice.label,
-        describe_effect(choice.effect, choice.value),
+        choice.description,
         current_stacks,
         choice.max_stacks
     )
 }
 
+fn rarity_border_color(rarity: &str) -> Color {
+    match rarity {
+        "rare" => Color::srgba(0.45, 0.68, 0.98, 0.95),
+        "epic" => Color::srgba(0.78, 0.52, 0.98, 0.95),
+        _ => Color::srgba(0.56, 0.66, 0.74, 0.95),
+    }
+}
+
 fn roll_upgrade_offer(
-    config: &crate::config::RunUpgradeConfig,
+    config: &GameConfig,
+    player_vehicle_id: &str,
     stack_counts: &HashMap<String, u32>,
-    seed: &mut u64,
+    previous_offer: Option<&PendingUpgradeOffer>,
+    rng: &mut StreamRng,
 ) -> Option<PendingUpgradeOffer> {
-    let mut eligible_options: Vec<&RunUpgradeOptionConfig> = config
-        .options
-        .iter()
-        .filter(|option| stack_counts.get(&option.id).copied().unwrap_or(0) < option.max_stacks)
-        .collect();
-
-    let choice_count = config
+    let run_upgrade_cfg = &config.game.run_upgrades;
+    let mut eligible_choices: Vec<(UpgradeOfferChoice, f32)> =
+        upgrade_offer_pool(config, player_vehicle_id)
+            .into_iter()
+            .filter(|choice| stack_counts.get(&choice.id).copied().unwrap_or(0) < choice.max_stacks)
+            .map(|choice| {
+                let weight = run_upgrade_cfg.rarity_weight(&choice.rarity);
+                (choice, weight)
+            })
+            .filter(|(_, weight)| *weight > 0.0)
+            .collect();
+
+    let choice_count = run_upgrade_cfg
         .choices_per_offer
         .min(MAX_VISIBLE_UPGRADE_CHOICES)
-        .min(eligible_options.len());
+        .min(eligible_choices.len());
     if choice_count == 0 {
         return None;
     }
 
+    // Rerolls prefer fresh cards, but fall back to repeats when the pool is too small.
+    if let Some(previous_offer) = previous_offer {
+        let fresh_count = eligible_choices
+            .iter()
+            .filter(|(choice, _)| {
+                !previous_offer
+                    .choices
+                    .iter()
+                    .any(|previous| previous.id == choice.id)
+            })
+            .count();
+        if fresh_count >= choice_count {
+            eligible_choices.retain(|(choice, _)| {
+                !previous_offer
+                    .choices
+                    .iter()
+                    .any(|previous| previous.id == choice.id)
+            });
+        }
+    }
+
     let mut choices = Vec::with_capacity(choice_count);
     while choices.len() < choice_count {
-        let index = random_index(seed, eligible_options.len());
-        let option = eligible_options.swap_remove(index);
-        choices.push(UpgradeOfferChoice {
-            id: option.id.clone(),
-            label: option.label.clone(),
-            effect: option.effect,
-            value: option.value,
-            max_stacks: option.max_stacks,
-        });
+        let weights: Vec<f32> = eligible_choices.iter().map(|(_, weight)| *weight).collect();
+        let index = weighted_random_index(rng, &weights);
+        choices.push(eligible_choices.swap_remove(index).0);
     }
 
     Some(PendingUpgradeOffer { choices })
 }
 
-fn random_index(seed: &mut u64, len: usize) -> usize {
-    if len <= 1 {
-        return 0;
+fn upgrade_offer_pool(config: &GameConfig, player_vehicle_id: &str) -> Vec<UpgradeOfferChoice> {
+    let mut pool = Vec::new();
+    for option in &config.game.run_upgrades.options {
+        match run_upgrade_option_modifiers(option, config, player_vehicle_id) {
+            Ok(modifiers) => pool.push(UpgradeOfferChoice {
+                id: option.id.clone(),
+                label: option.label.clone(),
+                description: describe_effect(option.effect, option.value),
+                rarity: option.rarity.clone(),
+                modifiers,
+                max_stacks: option.max_stacks,
+            }),
+            Err(error) => warn!("Skipping upgrade option `{}`: {error}", option.id),
+        }
     }
-
-    *seed = seed
-        .wrapping_mul(6_364_136_223_846_793_005)
-        .wrapping_add(1_442_695_040_888_963_407);
-    ((*seed >> 32) as usize) % len
-}
-
-fn apply_upgrade_choice(
-    choice: &UpgradeOfferChoice,
-    config: &mut GameConfig,
-    player_query: &mut Query<&mut PlayerHealth, With<PlayerVehicle>>,
-) -> Result<String, String> {
-    match choice.effect {
-        RunUpgradeEffectKind::HealthFlat => {
-            let health_delta = choice.value.max(0.0);
-            let Ok(mut health) = player_query.single_mut() else {
-                return Err("player entity not found for health upgrade".to_string());
-            };
-            health.max += health_delta;
-            health.current = (health.current + health_delta).min(health.max);
-            Ok(format!("max health +{health_delta:.1}"))
+    for upgrade in &config.upgrades.upgrades {
+        match upgrade.stat_modifiers() {
+            Ok(modifiers) => pool.push(UpgradeOfferChoice {
+                id: upgrade.id.clone(),
+                label: upgrade.label.clone(),
+                description: describe_stat_modifiers(&modifiers),
+                rarity: upgrade.rarity.clone(),
+                modifiers,
+                max_stacks: upgrade.max_stacks,
+            }),
+            Err(error) => warn!("Skipping upgrade `{}`: {error}", upgrade.id),
         }
-        RunUpgradeEffectKind::WeaponFireRatePercent => {
-            let scale = 1.0 + choice.value.max(0.0);
-            let default_vehicle_id = config.game.app.default_vehicle.clone();
-            let default_weapon_id = config
-                .vehicles_by_id
-                .get(&default_vehicle_id)
-                .ok_or_else(|| {
-                    format!("default vehicle `{default_vehicle_id}` not found for upgrade")
-                })?
-                .default_weapon_id
-                .clone();
-
-            let new_fire_rate = {
-                let weapon = config
-                    .weapons_by_id
-                    .get_mut(&default_weapon_id)
-                    .ok_or_else(|| {
-                        format!("default weapon `{default_weapon_id}` not found for upgrade")
-                    })?;
-                weapon.fire_rate = (weapon.fire_rate * scale).max(0.05);
-                weapon.fire_rate
-            };
+    }
+    pool
+}
 
-            if let Some(weapon) = config
-                .weapons
-                .weapons
-                .iter_mut()
-                .find(|weapon| weapon.id == default_weapon_id)
-            {
-                weapon.fire_rate = new_fire_rate;
-            }
+fn weighted_random_index(rng: &mut StreamRng, weights: &[f32]) -> usize {
+    if weights.len() <= 1 {
+        return 0;
+    }
 
-            Ok(format!(
-                "{default_weapon_id} fire rate -> {new_fire_rate:.3} shots/s"
-            ))
+    let mut roll = rng.unit() * weights.iter().sum::<f32>();
+    for (index, weight) in weights.iter().enumerate() {
+        if roll < *weight {
+            return index;
         }
-        RunUpgradeEffectKind::MissileFireRatePercent => {
-            let scale = 1.0 + choice.value.max(0.0);
-            let default_vehicle_id = config.game.app.default_vehicle.clone();
-
-            let new_interval_seconds = {
-                let vehicle = config
-                    .vehicles_by_id
-                    .get_mut(&default_vehicle_id)
-                    .ok_or_else(|| {
-                        format!("default vehicle `{default_vehicle_id}` not found for upgrade")
-                    })?;
-                vehicle.missile_fire_interval_seconds =
-                    (vehicle.missile_fire_interval_seconds / scale).max(0.05);
-                vehicle.missile_fire_interval_seconds
-            };
+        roll -= weight;
+    }
+    weights.len() - 1
+}
 
-            if let Some(vehicle) = config
-                .vehicles
-                .vehicles
-                .iter_mut()
-                .find(|vehicle| vehicle.id == default_vehicle_id)
-            {
-                vehicle.missile_fire_interval_seconds = new_interval_seconds;
+fn run_upgrade_option_modifiers(
+    option: &RunUpgradeOptionConfig,
+    config: &GameConfig,
+    vehicle_id: &str,
+) -> Result<Vec<StatModifier>, String> {
+    let value = option.value.max(0.0);
+    let vehicle = config
+        .vehicles_by_id
+        .get(vehicle_id)
+        .ok_or_else(|| format!("player vehicle `{vehicle_id}` not found for upgrade"))?;
+    let vehicle_stat =
+        |field: &str| StatPath::new(StatTargetKind::Vehicle, Some(vehicle_id), field);
+
+    let modifier = match option.effect {
+        RunUpgradeEffectKind::HealthFlat => StatModifier {
+            path: vehicle_stat("health"),
+            op: StatModifierOp::Add(value),
+        },
+        RunUpgradeEffectKind::WeaponFireRatePercent => StatModifier {
+            path: StatPath::new(
+                StatTargetKind::Weapon,
+                Some(vehicle.default_weapon_id.as_str()),
+                "fire_rate",
+            ),
+            op: StatModifierOp::Multiply(1.0 + value),
+        },
+        RunUpgradeEffectKind::MissileFireRatePercent => StatModifier {
+            path: vehicle_stat("missile_fire_interval_seconds"),
+            op: StatModifierOp::Multiply(1.0 / (1.0 + value)),
+        },
+        RunUpgradeEffectKind::VehiclePowerPercent => StatModifier {
+            path: vehicle_stat("acceleration"),
+            op: StatModifierOp::Multiply(1.0 + value),
+        },
+        RunUpgradeEffectKind::TurretConeDegreesFlat => StatModifier {
+            path: vehicle_stat("turret_cone_degrees"),
+            op: StatModifierOp::Add(value),
+        },
+        RunUpgradeEffectKind::MissileTurnRatePercent => {
+            let secondary_weapon_id = vehicle.secondary_weapon_id.as_deref().ok_or_else(|| {
+                format!("vehicle `{vehicle_id}` has no secondary weapon for missile turn upgrade")
+            })?;
+            StatModifier {
+                path: StatPath::new(
+                    StatTargetKind::Weapon,
+                    Some(secondary_weapon_id),
+                    "homing_turn_rate_degrees",
+                ),
+                op: StatModifierOp::Multiply(1.0 + value),
             }
+        }
+        RunUpgradeEffectKind::TurretRangePercent => StatModifier {
+            path: vehicle_stat("turret_range_m"),
+            op: StatModifierOp::Multiply(1.0 + value),
+        },
+    };
+    Ok(vec![modifier])
+}
 
-            Ok(format!(
-                "missile fire interval -> {new_interval_seconds:.3}s"
-            ))
+fn apply_stat_modifiers(
+    modifiers: &[StatModifier],
+    run_stats: &mut RunStats,
+    player_query: &mut Query<&mut PlayerHealth, With<PlayerVehicle>>,
+) -> Result<String, String> {
+    let player_health_path = StatPath::new(
+        StatTargetKind::Vehicle,
+        Some(run_stats.player_vehicle_id.as_str()),
+        "health",
+    );
+
+    // Apply to a scratch copy so a failing modifier leaves the run stats untouched.
+    let mut next_stats = run_stats.clone();
+    let mut health_delta = 0.0;
+    let mut summaries = Vec::with_capacity(modifiers.len());
+    for modifier in modifiers {
+        let previous = next_stats.stat_value(&modifier.path)?;
+        let value = next_stats.apply_stat_modifier(modifier)?;
+        if modifier.path == player_health_path {
+            health_delta += value - previous;
         }
-        RunUpgradeEffectKind::VehiclePowerPercent => {
-            let scale = 1.0 + choice.value.max(0.0);
-            let default_vehicle_id = config.game.app.default_vehicle.clone();
-
-            let new_acceleration = {
-                let vehicle = config
-                    .vehicles_by_id
-                    .get_mut(&default_vehicle_id)
-                    .ok_or_else(|| {
-                        format!("default vehicle `{default_vehicle_id}` not found for upgrade")
-                    })?;
-                vehicle.acceleration = (vehicle.acceleration * scale).max(0.01);
-                vehicle.acceleration
-            };
+        summaries.push(format!(
+            "{} {} -> {value:.3}",
+            modifier
+                .path
+                .id
+                .as_deref()
+                .unwrap_or(modifier.path.kind.label()),
+            modifier.path.field
+        ));
+    }
 
-            if let Some(vehicle) = config
-                .vehicles
-                .vehicles
-                .iter_mut()
-                .find(|vehicle| vehicle.id == default_vehicle_id)
-            {
-                vehicle.acceleration = new_acceleration;
-            }
+    *run_stats = next_stats;
+    sync_player_max_health(player_query, health_delta);
+    Ok(summaries.join(", "))
+}
 
-            Ok(format!("acceleration -> {new_acceleration:.3}"))
-        }
-        RunUpgradeEffectKind::TurretConeDegreesFlat => {
-            let cone_delta = choice.value.max(0.0);
-            let default_vehicle_id = config.game.app.default_vehicle.clone();
-
-            let new_cone_degrees = {
-                let vehicle = config
-                    .vehicles_by_id
-                    .get_mut(&default_vehicle_id)
-                    .ok_or_else(|| {
-                        format!("default vehicle `{default_vehicle_id}` not found for upgrade")
-                    })?;
-                vehicle.turret_cone_degrees =
-                    (vehicle.turret_cone_degrees + cone_delta).clamp(1.0, 180.0);
-                vehicle.turret_cone_degrees
-            };
+fn sync_player_max_health(
+    player_query: &mut Query<&mut PlayerHealth, With<PlayerVehicle>>,
+    delta: f32,
+) {
+    if delta.abs() <= f32::EPSILON {
+        return;
+    }
+    if let Ok(mut health) = player_query.single_mut() {
+        health.max = (health.max + delta).max(1.0);
+        health.current = (health.current + delta.max(0.0)).min(health.max);
+    }
+}
 
-            if let Some(vehicle) = config
-                .vehicles
-                .vehicles
-                .iter_mut()
-                .find(|vehicle| vehicle.id == default_vehicle_id)
-            {
-                vehicle.turret_cone_degrees = new_cone_degrees;
-            }
+fn revert_last_run_modifier(
+    config: &GameConfig,
+    run_stats: &mut RunStats,
+    state: &mut UpgradeProgressState,
+    modifier_stack: &mut RunModifierStack,
+    player_query: &mut Query<&mut PlayerHealth, With<PlayerVehicle>>,
+) -> Option<(RunModifierEntry, u32)> {
+    let entry = modifier_stack.entries.pop()?;
+
+    let previous_health = run_stats.player_vehicle().map(|vehicle| vehicle.health);
+    let (rebuilt, errors) = RunStats::rebuild(
+        config,
+        &run_stats.player_vehicle_id,
+        modifier_stack.modifiers(),
+    );
+    for error in &errors {
+        warn!("Run modifier no longer applies: {error}");
+    }
+    *run_stats = rebuilt;
+    if let (Some(previous), Some(vehicle)) = (previous_health, run_stats.player_vehicle()) {
+        sync_player_max_health(player_query, vehicle.health - previous);
+    }
 
-            Ok(format!("turret cone -> {new_cone_degrees:.1} deg"))
-        }
-        RunUpgradeEffectKind::MissileTurnRatePercent => {
-            let scale = 1.0 + choice.value.max(0.0);
-            let default_vehicle_id = config.game.app.default_vehicle.clone();
-            let secondary_weapon_id = config
-                .vehicles_by_id
-                .get(&default_vehicle_id)
-                .ok_or_else(|| {
-                    format!("default vehicle `{default_vehicle_id}` not found for upgrade")
-                })?
-                .secondary_weapon_id
-                .clone()
-                .ok_or_else(|| {
-                    "default vehicle has no secondary weapon for missile turn upgrade".to_string()
-                })?;
-
-            let new_turn_rate_degrees = {
-                let weapon = config
-                    .weapons_by_id
-                    .get_mut(&secondary_weapon_id)
-                    .ok_or_else(|| {
-                        format!("secondary weapon `{secondary_weapon_id}` not found for upgrade")
-                    })?;
-                weapon.homing_turn_rate_degrees =
-                    (weapon.homing_turn_rate_degrees * scale).max(0.0);
-                weapon.homing_turn_rate_degrees
-            };
+    let stack = state
+        .stack_counts
+        .get(&entry.upgrade_id)
+        .copied()
+        .unwrap_or(0)
+        .saturating_sub(1);
+    if stack == 0 {
+        state.stack_counts.remove(&entry.upgrade_id);
+    } else {
+        state.stack_counts.insert(entry.upgrade_id.clone(), stack);
+    }
+    Some((entry, stack))
+}
 
-            if let Some(weapon) = config
-                .weapons
-                .weapons
-                .iter_mut()
-                .find(|weapon| weapon.id == secondary_weapon_id)
-            {
-                weapon.homing_turn_rate_degrees = new_turn_rate_degrees;
-            }
+fn debug_run_modifier_hotkeys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    config: Res<GameConfig>,
+    mut run_stats: ResMut<RunStats>,
+    mut state: ResMut<UpgradeProgressState>,
+    mut modifier_stack: ResMut<RunModifierStack>,
+    mut player_query: Query<&mut PlayerHealth, With<PlayerVehicle>>,
+    mut reverted_events: MessageWriter<UpgradeRevertedEvent>,
+) {
+    if !config.game.app.debug_overlay || state.pending_offer.is_some() {
+        return;
+    }
 
-            Ok(format!(
-                "{secondary_weapon_id} turn rate -> {new_turn_rate_degrees:.3} deg/s"
-            ))
+    if keyboard.just_pressed(KeyCode::KeyU) {
+        let lines = modifier_stack.summary_lines();
+        if lines.is_empty() {
+            info!("Run modifier stack is empty.");
+        } else {
+            info!("Run modifier stack:\n{}", lines.join("\n"));
         }
-        RunUpgradeEffectKind::TurretRangePercent => {
-            let scale = 1.0 + choice.value.max(0.0);
-            let default_vehicle_id = config.game.app.default_vehicle.clone();
-
-            let new_range_m = {
-                let vehicle = config
-                    .vehicles_by_id
-                    .get_mut(&default_vehicle_id)
-                    .ok_or_else(|| {
-                        format!("default vehicle `{default_vehicle_id}` not found for upgrade")
-                    })?;
-                vehicle.turret_range_m = (vehicle.turret_range_m * scale).max(0.1);
-                vehicle.turret_range_m
-            };
-
-            if let Some(vehicle) = config
-                .vehicles
-                .vehicles
-                .iter_mut()
-                .find(|vehicle| vehicle.id == default_vehicle_id)
-            {
-                vehicle.turret_range_m = new_range_m;
-            }
+    }
 
-            Ok(format!("turret range -> {new_range_m:.3} m"))
+    if !keyboard.just_pressed(KeyCode::Backspace) {
+        return;
+    }
+    match revert_last_run_modifier(
+        &config,
+        &mut run_stats,
+        &mut state,
+        &mut modifier_stack,
+        &mut player_query,
+    ) {
+        Some((entry, stack)) => {
+            info!("Reverted upgrade `{}` (now stack {stack}).", entry.label);
+            reverted_events.write(UpgradeRevertedEvent {
+                upgrade_id: entry.upgrade_id,
+                stack,
+            });
         }
+        None => info!("No run upgrades to revert."),
     }
 }
 
-fn selected_upgrade_index_from_input(
+fn upgrade_offer_input(
     keyboard: &ButtonInput<KeyCode>,
     virtual_controls: Option<&VirtualControlState>,
-    choice_count: usize,
-) -> Option<usize> {
-    if choice_count == 0 {
-        return None;
-    }
-
+) -> Option<UpgradeOfferInput> {
     if keyboard.just_pressed(KeyCode::KeyA)
         || keyboard.just_pressed(KeyCode::ArrowLeft)
         || virtual_controls
             .map(|controls| controls.brake_just_pressed)
             .unwrap_or(false)
     {
-        return Some(0);
-    }
-    if choice_count >= 2
-        && (keyboard.just_pressed(KeyCode::KeyD)
-            || keyboard.just_pressed(KeyCode::ArrowRight)
-            || virtual_controls
-                .map(|controls| controls.accelerate_just_pressed)
-                .unwrap_or(false))
+        return Some(UpgradeOfferInput::Next);
+    }
+    if keyboard.just_pressed(KeyCode::KeyD)
+        || keyboard.just_pressed(KeyCode::ArrowRight)
+        || virtual_controls
+            .map(|controls| controls.accelerate_just_pressed)
+            .unwrap_or(false)
     {
-        return Some(1);
+        return Some(UpgradeOfferInput::Confirm);
     }
     None
 }
@@ -749,6 +1176,27 @@ fn selection_keys_held(
             .unwrap_or(false)
 }
 
+fn describe_stat_modifiers(modifiers: &[StatModifier]) -> String {
+    modifiers
+        .iter()
+        .map(|modifier| {
+            let target = modifier
+                .path
+                .id
+                .as_deref()
+                .unwrap_or(modifier.path.kind.label());
+            let field = modifier.path.field.replace('_', " ");
+            match modifier.op {
+                StatModifierOp::Add(amount) => format!("{target} {field} {amount:+.1}"),
+                StatModifierOp::Multiply(factor) => {
+                    format!("{target} {field} {:+.0}%", (factor - 1.0) * 100.0)
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn describe_effect(effect: RunUpgradeEffectKind, value: f32) -> String {
     match effect {
         RunUpgradeEffectKind::HealthFlat => format!("+{value:.1} hp"),
@@ -772,16 +1220,3 @@ fn describe_effect(effect: RunUpgradeEffectKind, value: f32) -> String {
         }
     }
 }
-
-#[cfg(not(target_arch = "wasm32"))]
-fn unix_timestamp_seconds() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs()
-}
-
-#[cfg(target_arch = "wasm32")]
-fn unix_timestamp_seconds() -> u64 {
-    0
-}
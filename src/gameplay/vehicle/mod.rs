@@ -1,9 +1,11 @@
 use crate::assets::{AssetRegistry, ModelAssetEntry};
-use crate::config::GameConfig;
+use crate::config::{GameConfig, RunStats, VehicleConfig};
 use crate::debug::{DebugCameraPanState, DebugGameplayGuards};
-use crate::gameplay::combat::TurretTargetingState;
+use crate::gameplay::combat::{EnemyKilledEvent, TurretTargetingState};
 use crate::gameplay::enemies::{Enemy, EnemyTypeId};
-use crate::states::GameState;
+use crate::gameplay::fixed_step::{FixedStepInterpolation, GameplaySimSet};
+use crate::replay::ReplayPlayback;
+use crate::states::ActiveRun;
 use crate::web::{should_disable_splats_for_platform, VirtualControlState};
 use bevy::asset::RenderAssetUsages;
 use bevy::camera::visibility::RenderLayers;
@@ -24,11 +26,13 @@ use bevy_gaussian_splatting::{
 };
 
 mod model;
+mod parts;
 mod runtime;
 mod scene;
 mod terrain;
 
 use model::*;
+use parts::*;
 use runtime::*;
 use scene::*;
 use terrain::*;
@@ -62,21 +66,8 @@ const SPLAT_BACKGROUND_Z_M: f32 = -120.0;
 const SPLAT_BACKGROUND_Y_OFFSET_M: f32 = -8.5;
 #[cfg(feature = "gaussian_splats")]
 const SPLAT_BACKGROUND_RENDER_LAYER: usize = 1;
-const PLAYER_CHASSIS_SIZE: Vec2 = Vec2::new(3.45, 1.08);
 const PLAYER_TURRET_SIZE: Vec2 = Vec2::new(1.42, 0.52);
 const PLAYER_TURRET_OFFSET_LOCAL: Vec3 = Vec3::new(0.38, 0.66, 0.4);
-const PLAYER_WHEEL_RADIUS_M: f32 = 0.552;
-const PLAYER_WHEEL_SPREAD_EXTRA_PER_SIDE_RADII: f32 = 0.4;
-const PLAYER_WHEEL_SPREAD_EXTRA_PER_SIDE_M: f32 =
-    PLAYER_WHEEL_RADIUS_M * PLAYER_WHEEL_SPREAD_EXTRA_PER_SIDE_RADII;
-const PLAYER_CHASSIS_RAISE_EXTRA_M: f32 =
-    PLAYER_WHEEL_RADIUS_M * PLAYER_WHEEL_SPREAD_EXTRA_PER_SIDE_RADII;
-const PLAYER_FRONT_HARDPOINT_X_M: f32 = 1.06 + PLAYER_WHEEL_SPREAD_EXTRA_PER_SIDE_M;
-const PLAYER_REAR_HARDPOINT_X_M: f32 = -1.08 - PLAYER_WHEEL_SPREAD_EXTRA_PER_SIDE_M;
-const PLAYER_FRONT_HARDPOINT_Y_M: f32 = -0.15 + PLAYER_CHASSIS_RAISE_EXTRA_M;
-const PLAYER_REAR_HARDPOINT_Y_M: f32 = -0.10 + PLAYER_CHASSIS_RAISE_EXTRA_M;
-const PLAYER_CHASSIS_MASS_KG: f32 = 6.0;
-const PLAYER_CHASSIS_CENTER_OF_MASS_Y_M: f32 = -0.54;
 const PLAYER_REAR_WHEEL_GROUND_EPSILON_M: f32 = 0.05;
 const SUSPENSION_FORCE_CLAMP_N: f32 = 240.0;
 const WHEEL_FRICTION_MIN_FACTOR: f32 = 0.30;
@@ -120,6 +111,10 @@ const PLAYER_VISUAL_RIDE_HEIGHT_OFFSET_M: f32 = 0.46;
 const PLAYER_MODEL_WHEEL_FOREGROUND_Z_BIAS_M: f32 = 1.2;
 const DRAW_PLAYER_GAMEPLAY_BOX_VISUALS: bool = false;
 const TURRET_VISUAL_AIM_SMOOTH_RATE_HZ: f32 = 18.0;
+const PART_ALIGNMENT_TOLERANCE_M: f32 = 0.12;
+const PART_SCALE_TOLERANCE: f32 = 0.20;
+const FUEL_STALL_SPEED_MPS: f32 = 0.6;
+const NITRO_OVERSPEED_FADE_S: f32 = 0.8;
 
 pub struct VehicleGameplayPlugin;
 
@@ -133,44 +128,64 @@ impl Plugin for VehicleGameplayPlugin {
             .init_resource::<StuntTrackingState>()
             .init_resource::<VehicleModelDebugState>()
             .init_resource::<VehicleVisualTurretAimState>()
+            .init_resource::<VehiclePartAlignmentReport>()
             .add_message::<VehicleStuntEvent>()
             .add_message::<VehicleLandingEvent>()
+            .add_message::<VehicleNitroBoostEvent>()
             .add_systems(
-                OnEnter(GameState::InRun),
+                OnEnter(ActiveRun),
                 (
                     configure_camera_units,
                     spawn_vehicle_scene,
                     reset_stunt_metrics,
                     reset_camera_follow_state,
                     reset_vehicle_visual_turret_aim_state,
+                    reset_vehicle_part_alignment_report,
                 ),
             )
-            .add_systems(OnExit(GameState::InRun), cleanup_vehicle_scene)
+            .add_systems(OnExit(ActiveRun), cleanup_vehicle_scene)
             .add_systems(
                 Update,
                 (
                     request_vehicle_model_scene_dump_hotkey,
                     dump_loaded_vehicle_model_scene_info,
                 )
-                    .run_if(in_state(GameState::InRun))
+                    .run_if(in_state(ActiveRun))
                     .run_if(resource_exists::<GameConfig>),
             )
+            .add_systems(
+                FixedUpdate,
+                read_vehicle_input
+                    .in_set(VehicleInputSet)
+                    .in_set(GameplaySimSet::Input),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    sync_rapier_gravity_from_config,
+                    sync_player_chassis_from_config,
+                    update_player_nitro,
+                    apply_vehicle_kinematics,
+                    burn_player_fuel,
+                    recover_player_from_ground_embed,
+                    update_stunt_metrics,
+                    update_vehicle_telemetry,
+                )
+                    .chain()
+                    .in_set(GameplaySimSet::Vehicle),
+            )
             .add_systems(
                 Update,
                 (
-                    read_vehicle_input,
                     update_ground_spline_segments,
-                    sync_rapier_gravity_from_config,
                     #[cfg(feature = "gaussian_splats")]
                     sort_splat_background_by_z_once,
-                    apply_vehicle_kinematics,
-                    recover_player_from_ground_embed,
                     configure_player_vehicle_model_visuals,
                     spin_wheel_pairs,
                     sync_player_vehicle_visual_aim_and_model_wheels,
-                    update_stunt_metrics,
+                    sync_player_vehicle_part_transforms,
+                    report_player_vehicle_part_alignment,
                     update_player_health_bar,
-                    update_vehicle_telemetry,
                     camera_follow_vehicle,
                     sync_vehicle_model_camera_with_gameplay_camera,
                     #[cfg(feature = "gaussian_splats")]
@@ -179,7 +194,7 @@ impl Plugin for VehicleGameplayPlugin {
                     update_splat_background_parallax,
                 )
                     .chain()
-                    .run_if(in_state(GameState::InRun))
+                    .run_if(in_state(ActiveRun))
                     .run_if(resource_exists::<GameConfig>),
             );
     }
@@ -196,6 +211,7 @@ fn configure_camera_units(mut camera_query: Query<&mut Projection, With<Camera2d
 }
 
 #[derive(Component)]
+#[require(FixedStepInterpolation)]
 pub struct PlayerVehicle;
 
 #[derive(Component)]
@@ -207,6 +223,36 @@ pub struct PlayerHealth {
     pub max: f32,
 }
 
+// Only present when the vehicle has a `fuel` table. `stalled_s` counts how long
+// the vehicle has sat below `FUEL_STALL_SPEED_MPS` with an empty tank.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PlayerFuel {
+    pub current: f32,
+    pub max: f32,
+    pub stalled_s: f32,
+}
+
+// Only present when the vehicle has a `nitro` table. `overspeed_mps` is how far
+// past `max_forward_speed` the vehicle may go; it holds during a boost and then
+// eases back to zero so the speed clamp does not snap.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PlayerNitro {
+    pub charge: f32,
+    pub max: f32,
+    pub boost_remaining_s: f32,
+    pub overspeed_mps: f32,
+}
+
+impl PlayerNitro {
+    pub fn boosting(&self) -> bool {
+        self.boost_remaining_s > 0.0
+    }
+
+    pub fn ready(&self) -> bool {
+        !self.boosting() && self.charge >= self.max
+    }
+}
+
 #[derive(Component)]
 struct GroundVisual;
 
@@ -257,9 +303,7 @@ struct PlayerVehiclePlaceholderVisual;
 #[derive(Component, Debug, Clone, Copy)]
 struct PlayerWheelPairVisual {
     axle: WheelAxle,
-    radius_m: f32,
     driven: bool,
-    hardpoint_local: Vec2,
 }
 
 #[derive(Component, Debug, Clone)]
@@ -275,6 +319,29 @@ struct PlayerVehicleModelSceneSpawn {
     scene_metadata: PlayerVehicleModelScene,
 }
 
+#[derive(Component, Debug, Clone)]
+struct PlayerVehiclePart {
+    slot: &'static str,
+    model_id: String,
+    anchor: Vec3,
+    offset: Vec3,
+    base_rotation: Quat,
+    alignment_checked: bool,
+}
+
+#[derive(Debug, Clone)]
+struct PlayerVehiclePartSpawn {
+    handle: Handle<Scene>,
+    part: PlayerVehiclePart,
+    transform: Transform,
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct VehiclePartAlignmentReport {
+    pub checked_parts: usize,
+    pub issues: Vec<String>,
+}
+
 #[derive(Resource, Debug, Default)]
 struct VehicleModelDebugState {
     dump_requested: bool,
@@ -348,6 +415,9 @@ struct GroundContact {
     landing_impact_speed_mps: f32,
 }
 
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VehicleInputSet;
+
 #[derive(Resource, Debug, Clone, Copy, Default)]
 pub struct VehicleInputState {
     pub accelerate: bool,
@@ -436,6 +506,13 @@ pub enum VehicleStuntEvent {
     Flip { total_flips: u32 },
 }
 
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct VehicleNitroBoostEvent {
+    pub world_position: Vec2,
+    pub speed_mps: f32,
+    pub duration_s: f32,
+}
+
 #[derive(Message, Debug, Clone, Copy, PartialEq)]
 pub struct VehicleLandingEvent {
     pub world_position: Vec2,
@@ -20,17 +20,30 @@ pub(super) fn dump_loaded_vehicle_model_scene_info(
     _scenes: ResMut<Assets<Scene>>,
     mut state: ResMut<VehicleModelDebugState>,
     _model_scene_query: Query<&PlayerVehicleModelScene>,
+    part_report: Res<VehiclePartAlignmentReport>,
 ) {
     if !state.dump_requested {
         return;
     }
     state.dump_requested = false;
+
+    if part_report.checked_parts > 0 {
+        info!(
+            "Vehicle part alignment: {} part(s) checked, {} issue(s).",
+            part_report.checked_parts,
+            part_report.issues.len()
+        );
+        for issue in &part_report.issues {
+            info!("  {issue}");
+        }
+    }
 }
 
 #[allow(clippy::type_complexity)]
 pub(super) fn configure_player_vehicle_model_visuals(
     mut commands: Commands,
     config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut model_root_query: Query<(
         Entity,
@@ -44,7 +57,7 @@ pub(super) fn configure_player_vehicle_model_visuals(
         Without<PlayerVehicleModelScene>,
     >,
 ) {
-    let Some(vehicle) = config.vehicles_by_id.get(&config.game.app.default_vehicle) else {
+    let Some(vehicle) = config.vehicles_by_id.get(&run_stats.player_vehicle_id) else {
         return;
     };
 
@@ -202,7 +215,9 @@ pub(super) fn configure_player_vehicle_model_visuals(
         let scene_out_of_plane_axis = (model_rotation.inverse() * Vec3::Z).normalize_or_zero();
 
         let source_wheelbase_m = front_center.distance(rear_center).max(0.001);
-        let desired_wheelbase_m = (PLAYER_FRONT_HARDPOINT_X_M - PLAYER_REAR_HARDPOINT_X_M).abs();
+        let front_hardpoint = wheel_hardpoint_local(vehicle, WheelAxle::Front);
+        let rear_hardpoint = wheel_hardpoint_local(vehicle, WheelAxle::Rear);
+        let desired_wheelbase_m = (front_hardpoint.x - rear_hardpoint.x).abs();
         let wheelbase_scale = desired_wheelbase_m / source_wheelbase_m;
 
         let (forward_extent_m, up_extent_m) = chassis_node
@@ -216,15 +231,14 @@ pub(super) fn configure_player_vehicle_model_visuals(
                 )
             })
             .unwrap_or((source_chassis_extent_m, source_chassis_extent_m));
-        let forward_scale = PLAYER_CHASSIS_SIZE.x / forward_extent_m.max(0.001);
-        let up_scale = PLAYER_CHASSIS_SIZE.y / up_extent_m.max(0.001);
+        let forward_scale = vehicle.chassis_length_m / forward_extent_m.max(0.001);
+        let up_scale = vehicle.chassis_height_m / up_extent_m.max(0.001);
         let scale = (((wheelbase_scale * 0.55) + (forward_scale * 0.30) + (up_scale * 0.15))
             * PLAYER_MODEL_SCALE_MULTIPLIER)
             .clamp(0.01, 500.0);
 
-        let desired_wheel_center_x = (PLAYER_FRONT_HARDPOINT_X_M + PLAYER_REAR_HARDPOINT_X_M) * 0.5;
-        let desired_wheel_center_y = ((PLAYER_FRONT_HARDPOINT_Y_M + PLAYER_REAR_HARDPOINT_Y_M)
-            * 0.5)
+        let desired_wheel_center_x = (front_hardpoint.x + rear_hardpoint.x) * 0.5;
+        let desired_wheel_center_y = ((front_hardpoint.y + rear_hardpoint.y) * 0.5)
             - vehicle.suspension_rest_length_m
             + PLAYER_VISUAL_RIDE_HEIGHT_OFFSET_M;
         let scaled_rotated_wheel_center = rotation_matrix * (average_wheel_center * scale);
@@ -268,7 +282,7 @@ pub(super) fn configure_player_vehicle_model_visuals(
             let source_wheel_radius_local =
                 wheel_estimated_radius_from_bounds(wheel.min, wheel.max);
             let source_wheel_radius_after_scene_scale = source_wheel_radius_local * scale;
-            let desired_visual_wheel_radius = PLAYER_WHEEL_RADIUS_M * PLAYER_WHEEL_VISUAL_SCALE;
+            let desired_visual_wheel_radius = vehicle.wheel_radius_m * PLAYER_WHEEL_VISUAL_SCALE;
             let visual_scale_multiplier = if source_wheel_radius_after_scene_scale > f32::EPSILON {
                 (desired_visual_wheel_radius / source_wheel_radius_after_scene_scale)
                     .clamp(0.05, 20.0)
@@ -300,7 +314,11 @@ pub(super) fn configure_player_vehicle_model_visuals(
     }
 }
 
-fn collect_descendants(root: Entity, children_query: &Query<&Children>, out: &mut Vec<Entity>) {
+pub(super) fn collect_descendants(
+    root: Entity,
+    children_query: &Query<&Children>,
+    out: &mut Vec<Entity>,
+) {
     let mut stack = vec![root];
     while let Some(entity) = stack.pop() {
         let Ok(children) = children_query.get(entity) else {
@@ -392,7 +410,7 @@ fn mesh_projected_extent(mesh: &Mesh, axis: Vec3) -> Option<f32> {
     }
 }
 
-fn mesh_local_bounds(mesh: &Mesh) -> Option<(Vec3, Vec3)> {
+pub(super) fn mesh_local_bounds(mesh: &Mesh) -> Option<(Vec3, Vec3)> {
     let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?;
     let mut min = Vec3::splat(f32::INFINITY);
     let mut max = Vec3::splat(f32::NEG_INFINITY);
@@ -425,13 +443,14 @@ fn mesh_local_bounds(mesh: &Mesh) -> Option<(Vec3, Vec3)> {
 pub(super) fn spin_wheel_pairs(
     time: Res<Time>,
     config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     player_query: Query<(&VehicleKinematics, &VehicleSuspensionState), With<PlayerVehicle>>,
     mut wheel_query: Query<(&PlayerWheelPairVisual, &mut Transform)>,
 ) {
     let Ok((kinematics, suspension)) = player_query.single() else {
         return;
     };
-    let Some(vehicle) = config.vehicles_by_id.get(&config.game.app.default_vehicle) else {
+    let Some(vehicle) = config.vehicles_by_id.get(&run_stats.player_vehicle_id) else {
         return;
     };
 
@@ -441,6 +460,7 @@ pub(super) fn spin_wheel_pairs(
     let max_length = rest_length + vehicle.suspension_max_extension_m.max(0.0);
     let visual_min_length = (min_length - 0.08).max(0.02);
     let visual_max_length = max_length + 0.08;
+    let visual_scale = Vec3::splat(vehicle.wheel_radius_m * PLAYER_WHEEL_VISUAL_SCALE);
 
     for (wheel, mut transform) in &mut wheel_query {
         let spring_length_m = match wheel.axle {
@@ -450,18 +470,21 @@ pub(super) fn spin_wheel_pairs(
         let visual_spring_length = (rest_length
             + ((spring_length_m - rest_length) * WHEEL_VISUAL_TRAVEL_EXAGGERATION))
             .clamp(visual_min_length, visual_max_length);
-        transform.translation.x = wheel.hardpoint_local.x;
-        let target_y = wheel.hardpoint_local.y - visual_spring_length;
+        let hardpoint_local = wheel_hardpoint_local(vehicle, wheel.axle);
+        transform.translation.x = hardpoint_local.x;
+        let target_y = hardpoint_local.y - visual_spring_length;
         let spring_lerp = (WHEEL_VISUAL_SPRING_LERP_RATE * dt).clamp(0.0, 1.0);
         transform.translation.y = transform.translation.y.lerp(target_y, spring_lerp);
+        transform.scale = visual_scale;
 
         let axle_scale = match wheel.axle {
             WheelAxle::Front => 0.97,
             WheelAxle::Rear => 1.0,
         };
         let drive_spin_multiplier = if wheel.driven { 1.0 } else { 0.995 };
-        let angular_speed_rad_s =
-            (kinematics.velocity.x / wheel.radius_m.max(0.01)) * axle_scale * drive_spin_multiplier;
+        let angular_speed_rad_s = (kinematics.velocity.x / vehicle.wheel_radius_m.max(0.01))
+            * axle_scale
+            * drive_spin_multiplier;
         transform.rotate_z(-(angular_speed_rad_s * dt));
     }
 }
@@ -0,0 +1,364 @@
+use super::*;
+use bevy::math::Affine3A;
+
+pub(super) fn reset_vehicle_part_alignment_report(mut report: ResMut<VehiclePartAlignmentReport>) {
+    *report = VehiclePartAlignmentReport::default();
+}
+
+// Parts whose model is missing on disk are skipped with a warning; the rest of
+// the vehicle still assembles.
+pub(super) fn resolve_player_vehicle_parts(
+    registry: Option<&AssetRegistry>,
+    vehicle: &VehicleConfig,
+) -> Vec<PlayerVehiclePartSpawn> {
+    let Some(parts) = &vehicle.parts else {
+        return Vec::new();
+    };
+
+    parts
+        .slots()
+        .filter_map(|(slot, part)| {
+            let handle = registry
+                .and_then(|registry| registry.models.get(&part.model_id))
+                .and_then(|entry| entry.handle.clone());
+            let Some(handle) = handle else {
+                warn!(
+                    "Vehicle `{}` part `{}` skipped: model `{}` is not loaded.",
+                    vehicle.id, slot, part.model_id
+                );
+                return None;
+            };
+            let [pitch, yaw, roll] = part.rotation_degrees.map(f32::to_radians);
+            let base_rotation = Quat::from_euler(EulerRot::XYZ, pitch, yaw, roll);
+            let offset = Vec3::from_array(part.offset);
+            let anchor = vehicle_part_anchor_local(slot, vehicle);
+
+            Some(PlayerVehiclePartSpawn {
+                handle,
+                part: PlayerVehiclePart {
+                    slot,
+                    model_id: part.model_id.clone(),
+                    anchor,
+                    offset,
+                    base_rotation,
+                    alignment_checked: false,
+                },
+                transform: Transform::from_translation(anchor + offset)
+                    .with_rotation(base_rotation)
+                    .with_scale(Vec3::splat(part.scale)),
+            })
+        })
+        .collect()
+}
+
+// Anchors are in the player body's local frame: the chassis sits where the
+// single-scene model sits, the turret on its pivot and wheels on their hubs.
+fn vehicle_part_anchor_local(slot: &str, vehicle: &VehicleConfig) -> Vec3 {
+    let wheel_anchor = |axle: WheelAxle| {
+        let hardpoint = wheel_hardpoint_local(vehicle, axle);
+        Vec3::new(
+            hardpoint.x,
+            hardpoint.y - vehicle.suspension_rest_length_m,
+            PLAYER_MODEL_SCENE_Z + PLAYER_MODEL_WHEEL_FOREGROUND_Z_BIAS_M,
+        )
+    };
+    match (slot, part_wheel_axle(slot)) {
+        (_, Some(axle)) => wheel_anchor(axle),
+        ("turret", None) => {
+            PLAYER_TURRET_OFFSET_LOCAL + (Vec3::Y * PLAYER_VISUAL_RIDE_HEIGHT_OFFSET_M)
+        }
+        _ => Vec3::new(
+            0.0,
+            -0.02 + PLAYER_VISUAL_RIDE_HEIGHT_OFFSET_M,
+            PLAYER_MODEL_SCENE_Z,
+        ),
+    }
+}
+
+// Slot keys come from `VehiclePartsConfig::slots`.
+fn part_wheel_axle(slot: &str) -> Option<WheelAxle> {
+    match slot {
+        "front_wheel" => Some(WheelAxle::Front),
+        "rear_wheel" => Some(WheelAxle::Rear),
+        _ => None,
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(super) fn sync_player_vehicle_part_transforms(
+    visual_turret_aim_state: Res<VehicleVisualTurretAimState>,
+    wheel_pair_query: Query<(&PlayerWheelPairVisual, &Transform), Without<PlayerVehiclePart>>,
+    mut part_query: Query<(&PlayerVehiclePart, &mut Transform), Without<PlayerWheelPairVisual>>,
+) {
+    let aim_rotation = Quat::from_rotation_z(visual_turret_aim_state.smoothed_angle_rad);
+
+    for (part, mut transform) in &mut part_query {
+        let (anchor, delta_rotation) = match (part.slot, part_wheel_axle(part.slot)) {
+            (_, Some(axle)) => {
+                let Some((_, wheel_transform)) = wheel_pair_query
+                    .iter()
+                    .find(|(wheel, _)| wheel.axle == axle)
+                else {
+                    continue;
+                };
+                (
+                    wheel_transform
+                        .translation
+                        .truncate()
+                        .extend(PLAYER_MODEL_SCENE_Z + PLAYER_MODEL_WHEEL_FOREGROUND_Z_BIAS_M),
+                    wheel_transform.rotation,
+                )
+            }
+            ("turret", None) => (part.anchor, aim_rotation),
+            _ => continue,
+        };
+        transform.translation = anchor + (delta_rotation * part.offset);
+        transform.rotation = delta_rotation * part.base_rotation;
+    }
+}
+
+// Measures each assembled part in its rest pose (no spin, aim or suspension
+// travel) once its meshes have loaded, and compares it against the physics body.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn report_player_vehicle_part_alignment(
+    config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
+    meshes: Res<Assets<Mesh>>,
+    mut report: ResMut<VehiclePartAlignmentReport>,
+    mut part_query: Query<(Entity, &mut PlayerVehiclePart, &Transform)>,
+    children_query: Query<&Children>,
+    mesh_query: Query<&Mesh3d>,
+    local_transform_query: Query<(&Transform, Option<&ChildOf>)>,
+) {
+    let Some(vehicle) = config.vehicles_by_id.get(&run_stats.player_vehicle_id) else {
+        return;
+    };
+
+    for (part_entity, mut part, transform) in &mut part_query {
+        if part.alignment_checked {
+            continue;
+        }
+
+        let rest_pose = Transform::from_translation(part.anchor + part.offset)
+            .with_rotation(part.base_rotation)
+            .with_scale(transform.scale)
+            .compute_affine();
+        let mut descendants = Vec::new();
+        collect_descendants(part_entity, &children_query, &mut descendants);
+        let mut bounds: Option<(Vec3, Vec3)> = None;
+        let mut waiting_for_meshes = false;
+        for entity in descendants {
+            let Ok(mesh) = mesh_query.get(entity) else {
+                continue;
+            };
+            let Some((mesh_min, mesh_max)) = meshes.get(&mesh.0).and_then(mesh_local_bounds) else {
+                waiting_for_meshes = true;
+                break;
+            };
+            let Some(mesh_to_part) =
+                transform_relative_to_ancestor(entity, part_entity, &local_transform_query)
+            else {
+                continue;
+            };
+            let mesh_to_body = rest_pose * mesh_to_part;
+            for corner in aabb_corners(mesh_min, mesh_max) {
+                let point = mesh_to_body.transform_point3(corner);
+                bounds = Some(match bounds {
+                    Some((min, max)) => (min.min(point), max.max(point)),
+                    None => (point, point),
+                });
+            }
+        }
+        if waiting_for_meshes {
+            continue;
+        }
+        let Some((min, max)) = bounds else {
+            continue;
+        };
+
+        let issues = vehicle_part_alignment_issues(vehicle, part.slot, part.anchor, min, max);
+        part.alignment_checked = true;
+        report.checked_parts += 1;
+        if issues.is_empty() {
+            info!(
+                "Vehicle part `{}` (model `{}`) matches the physics hardpoints.",
+                part.slot, part.model_id
+            );
+        }
+        for issue in issues {
+            let line = format!(
+                "Vehicle part `{}` (model `{}`): {issue}",
+                part.slot, part.model_id
+            );
+            warn!("{line}");
+            report.issues.push(line);
+        }
+    }
+}
+
+fn transform_relative_to_ancestor(
+    entity: Entity,
+    ancestor: Entity,
+    local_transform_query: &Query<(&Transform, Option<&ChildOf>)>,
+) -> Option<Affine3A> {
+    let mut relative = Affine3A::IDENTITY;
+    let mut current = entity;
+    while current != ancestor {
+        let (transform, child_of) = local_transform_query.get(current).ok()?;
+        relative = transform.compute_affine() * relative;
+        current = child_of?.0;
+    }
+    Some(relative)
+}
+
+fn aabb_corners(min: Vec3, max: Vec3) -> [Vec3; 8] {
+    [
+        Vec3::new(min.x, min.y, min.z),
+        Vec3::new(max.x, min.y, min.z),
+        Vec3::new(min.x, max.y, min.z),
+        Vec3::new(max.x, max.y, min.z),
+        Vec3::new(min.x, min.y, max.z),
+        Vec3::new(max.x, min.y, max.z),
+        Vec3::new(min.x, max.y, max.z),
+        Vec3::new(max.x, max.y, max.z),
+    ]
+}
+
+// `min`/`max` are the part's bounds in the player body frame. Expected sizes
+// follow the single-scene fit in `configure_player_vehicle_model_visuals`.
+fn vehicle_part_alignment_issues(
+    vehicle: &VehicleConfig,
+    slot: &str,
+    anchor: Vec3,
+    min: Vec3,
+    max: Vec3,
+) -> Vec<String> {
+    let mut issues = Vec::new();
+    let center = ((min + max) * 0.5).truncate();
+    let size = (max - min).truncate();
+
+    match (slot, part_wheel_axle(slot)) {
+        ("chassis", _) => {
+            if center.x.abs() > PART_ALIGNMENT_TOLERANCE_M {
+                issues.push(format!(
+                    "misaligned: center is {:+.2} m from the physics body center",
+                    center.x
+                ));
+            }
+            let (rear_x, front_x) = (vehicle.rear_hardpoint_x_m, vehicle.front_hardpoint_x_m);
+            if min.x > rear_x || max.x < front_x {
+                issues.push(format!(
+                    "misaligned: spans x {:.2}..{:.2} m but the wheel hardpoints sit at {:.2} and {:.2} m",
+                    min.x, max.x, rear_x, front_x
+                ));
+            }
+            push_scale_issue(
+                &mut issues,
+                "length",
+                size.x,
+                vehicle.chassis_length_m * PLAYER_MODEL_SCALE_MULTIPLIER,
+            );
+        }
+        ("turret", _) => {
+            let pivot = anchor.truncate();
+            let reach = Vec2::splat(PART_ALIGNMENT_TOLERANCE_M);
+            if pivot.cmplt(min.truncate() - reach).any()
+                || pivot.cmpgt(max.truncate() + reach).any()
+            {
+                issues.push(format!(
+                    "misaligned: turret pivot ({:.2}, {:.2}) lies outside the model bounds",
+                    pivot.x, pivot.y
+                ));
+            }
+            push_scale_issue(
+                &mut issues,
+                "length",
+                size.max_element(),
+                PLAYER_TURRET_SIZE.x,
+            );
+        }
+        (_, Some(_)) => {
+            let hub_error = center - anchor.truncate();
+            if hub_error.length() > PART_ALIGNMENT_TOLERANCE_M {
+                issues.push(format!(
+                    "misaligned: wheel center is ({:+.2}, {:+.2}) m off the suspension hub",
+                    hub_error.x, hub_error.y
+                ));
+            }
+            push_scale_issue(
+                &mut issues,
+                "radius",
+                size.max_element() * 0.5,
+                vehicle.wheel_radius_m * PLAYER_WHEEL_VISUAL_SCALE,
+            );
+        }
+        _ => {}
+    }
+
+    issues
+}
+
+fn push_scale_issue(issues: &mut Vec<String>, measure: &str, actual_m: f32, expected_m: f32) {
+    let ratio = actual_m / expected_m.max(0.001);
+    if (ratio - 1.0).abs() > PART_SCALE_TOLERANCE {
+        issues.push(format!(
+            "mis-scaled: {measure} is {actual_m:.2} m, expected about {expected_m:.2} m (x{ratio:.2})"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn part_alignment_flags_offset_chassis_and_mis_scaled_wheels() {
+        let config =
+            GameConfig::load_from_dir(Path::new("config")).expect("repo config should load");
+        let vehicle = &config.vehicles_by_id["starter_car"];
+
+        let half_length = vehicle.chassis_length_m * PLAYER_MODEL_SCALE_MULTIPLIER * 0.5;
+        let chassis_min = Vec3::new(-half_length, -0.4, -0.5);
+        let chassis_max = Vec3::new(half_length, 0.4, 0.5);
+        let anchor = vehicle_part_anchor_local("chassis", vehicle);
+        assert!(vehicle_part_alignment_issues(
+            vehicle,
+            "chassis",
+            anchor,
+            chassis_min,
+            chassis_max
+        )
+        .is_empty());
+        let shifted = Vec3::X * 0.5;
+        let issues = vehicle_part_alignment_issues(
+            vehicle,
+            "chassis",
+            anchor,
+            chassis_min + shifted,
+            chassis_max + shifted,
+        );
+        assert_eq!(issues.len(), 1, "{issues:?}");
+        assert!(issues[0].starts_with("misaligned: center"), "{issues:?}");
+
+        let hub = vehicle_part_anchor_local("front_wheel", vehicle);
+        let radius = Vec3::splat(vehicle.wheel_radius_m * PLAYER_WHEEL_VISUAL_SCALE);
+        assert!(vehicle_part_alignment_issues(
+            vehicle,
+            "front_wheel",
+            hub,
+            hub - radius,
+            hub + radius
+        )
+        .is_empty());
+        let issues = vehicle_part_alignment_issues(
+            vehicle,
+            "front_wheel",
+            hub,
+            hub - radius * 2.0,
+            hub + radius * 2.0,
+        );
+        assert_eq!(issues.len(), 1, "{issues:?}");
+        assert!(issues[0].starts_with("mis-scaled: radius"), "{issues:?}");
+    }
+}
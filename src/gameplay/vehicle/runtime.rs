@@ -33,18 +33,22 @@ pub(super) fn update_ground_spline_segments(
 
 pub(super) fn recover_player_from_ground_embed(
     config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     mut player_query: Query<(&mut Transform, &mut Velocity, &GroundContact), With<PlayerVehicle>>,
 ) {
     let Ok((mut transform, mut velocity, contact)) = player_query.single_mut() else {
         return;
     };
+    let Some(vehicle) = run_stats.player_vehicle() else {
+        return;
+    };
 
     if !contact.grounded {
         return;
     }
 
     let terrain_y = terrain_height_at_x(&config, transform.translation.x);
-    let half_extents = Vec2::new(PLAYER_CHASSIS_SIZE.x * 0.48, PLAYER_CHASSIS_SIZE.y * 0.36);
+    let half_extents = chassis_collider_half_extents(vehicle);
     let (_, _, rotation_rad) = transform.rotation.to_euler(EulerRot::XYZ);
     let right_axis_y = rotation_rad.sin().abs();
     let up_axis_y = rotation_rad.cos().abs();
@@ -169,8 +173,17 @@ pub(super) fn read_vehicle_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     bindings: Res<VehicleInputBindings>,
     virtual_controls: Option<Res<VirtualControlState>>,
+    playback: Option<ResMut<ReplayPlayback>>,
     mut input_state: ResMut<VehicleInputState>,
 ) {
+    if let Some(mut playback) = playback {
+        *input_state = playback
+            .next_frame()
+            .map(|frame| frame.input())
+            .unwrap_or_default();
+        return;
+    }
+
     let accelerate_keyboard = bindings.accelerate.iter().any(|key| keyboard.pressed(*key));
     let brake_keyboard = bindings.brake.iter().any(|key| keyboard.pressed(*key));
     let accelerate_virtual = virtual_controls
@@ -188,6 +201,7 @@ pub(super) fn read_vehicle_input(
 
 pub(super) fn sync_rapier_gravity_from_config(
     config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     mut rapier_config_query: Query<&mut RapierConfiguration, With<DefaultRapierContext>>,
     mut player_gravity_query: Query<&mut GravityScale, With<PlayerVehicle>>,
 ) {
@@ -202,17 +216,44 @@ pub(super) fn sync_rapier_gravity_from_config(
         rapier_config.gravity = Vec2::new(0.0, -environment.gravity.max(0.0));
     }
 
-    if let Some(vehicle) = config.vehicles_by_id.get(&config.game.app.default_vehicle) {
+    if let Some(vehicle) = run_stats.player_vehicle() {
         if let Ok(mut gravity_scale) = player_gravity_query.single_mut() {
             gravity_scale.0 = vehicle.gravity_scale.max(0.01);
         }
     }
 }
 
-#[allow(clippy::type_complexity)]
+// Chassis geometry can change mid-run through the `V` panel or a config reload;
+// the collider is only rebuilt when it actually differs.
+pub(super) fn sync_player_chassis_from_config(
+    run_stats: Res<RunStats>,
+    mut applied_geometry: Local<Option<(Vec2, MassProperties)>>,
+    mut player_query: Query<(&mut Collider, &mut ColliderMassProperties), With<PlayerVehicle>>,
+) {
+    let Some(vehicle) = run_stats.player_vehicle() else {
+        return;
+    };
+    let Ok((mut collider, mut mass_properties)) = player_query.single_mut() else {
+        return;
+    };
+
+    let geometry = (
+        chassis_collider_half_extents(vehicle),
+        chassis_mass_properties(vehicle),
+    );
+    if *applied_geometry == Some(geometry) {
+        return;
+    }
+    *collider = Collider::cuboid(geometry.0.x, geometry.0.y);
+    *mass_properties = ColliderMassProperties::MassProperties(geometry.1);
+    *applied_geometry = Some(geometry);
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub(super) fn apply_vehicle_kinematics(
     time: Res<Time>,
     config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     input_state: Res<VehicleInputState>,
     debug_guards: Option<Res<DebugGameplayGuards>>,
     rapier_context: ReadRapierContext,
@@ -229,6 +270,8 @@ pub(super) fn apply_vehicle_kinematics(
             &mut VehicleSuspensionState,
             &mut GroundContact,
             &mut PlayerHealth,
+            Option<&PlayerFuel>,
+            Option<&PlayerNitro>,
         ),
         With<PlayerVehicle>,
     >,
@@ -247,6 +290,8 @@ pub(super) fn apply_vehicle_kinematics(
         mut suspension,
         mut contact,
         mut health,
+        fuel,
+        nitro,
     )) = player_query.single_mut()
     else {
         return;
@@ -255,7 +300,7 @@ pub(super) fn apply_vehicle_kinematics(
     contact.just_landed = false;
     contact.landing_impact_speed_mps = 0.0;
 
-    let Some(vehicle) = config.vehicles_by_id.get(&config.game.app.default_vehicle) else {
+    let Some(vehicle) = run_stats.player_vehicle() else {
         return;
     };
 
@@ -272,6 +317,12 @@ pub(super) fn apply_vehicle_kinematics(
         .is_some_and(|guards| guards.player_invulnerable);
     let throttle = if input_state.accelerate { 1.0 } else { 0.0 };
     let brake = if input_state.brake { 1.0 } else { 0.0 };
+    // An empty tank cuts drive and reverse; brakes and air control still work.
+    let engine_running = fuel.is_none_or(|fuel| fuel.current > 0.0);
+    let drive_throttle = if engine_running { throttle } else { 0.0 };
+    // Accelerate + brake is the nitro chord, so the wheels do not brake while it fires.
+    let nitro_boosting = engine_running && nitro.is_some_and(PlayerNitro::boosting);
+    let wheel_brake = if nitro_boosting { 0.0 } else { brake };
     let (_, _, z_rot_rad) = transform.rotation.to_euler(EulerRot::XYZ);
     let body_center = transform.translation.truncate();
     *external_force = ExternalForce::default();
@@ -286,12 +337,13 @@ pub(super) fn apply_vehicle_kinematics(
         player_entity,
         body_center,
         z_rot_rad,
-        Vec2::new(PLAYER_FRONT_HARDPOINT_X_M, PLAYER_FRONT_HARDPOINT_Y_M),
+        wheel_hardpoint_local(vehicle, WheelAxle::Front),
         suspension.front_prev_compression_m,
         rest_length,
         min_length,
         max_length,
         max_compression,
+        vehicle.wheel_radius_m,
         vehicle.suspension_stiffness,
         vehicle.suspension_damping,
         dt,
@@ -301,12 +353,13 @@ pub(super) fn apply_vehicle_kinematics(
         player_entity,
         body_center,
         z_rot_rad,
-        Vec2::new(PLAYER_REAR_HARDPOINT_X_M, PLAYER_REAR_HARDPOINT_Y_M),
+        wheel_hardpoint_local(vehicle, WheelAxle::Rear),
         suspension.rear_prev_compression_m,
         rest_length,
         min_length,
         max_length,
         max_compression,
+        vehicle.wheel_radius_m,
         vehicle.suspension_stiffness,
         vehicle.suspension_damping,
         dt,
@@ -365,11 +418,12 @@ pub(super) fn apply_vehicle_kinematics(
     let rear_drive_factor = rear_grip_factor * rear_assist_factor * chassis_drive_alignment;
     let brake_ground_factor = grounded_wheel_ratio.max(WHEEL_FRICTION_MIN_FACTOR);
     let mut front_longitudinal_accel =
-        throttle * drive_accel * front_drive_ratio * front_drive_factor;
-    let mut rear_longitudinal_accel = throttle * drive_accel * rear_drive_ratio * rear_drive_factor;
-    if brake > 0.0 {
+        drive_throttle * drive_accel * front_drive_ratio * front_drive_factor;
+    let mut rear_longitudinal_accel =
+        drive_throttle * drive_accel * rear_drive_ratio * rear_drive_factor;
+    if wheel_brake > 0.0 {
         if velocity.linvel.x > 0.25 {
-            let braking_accel = brake * brake_accel * brake_ground_factor;
+            let braking_accel = wheel_brake * brake_accel * brake_ground_factor;
             let front_brake_weight = if front_wheel_grounded { 0.6 } else { 0.0 };
             let rear_brake_weight = if rear_wheel_grounded { 0.4 } else { 0.0 };
             let brake_weight_sum = front_brake_weight + rear_brake_weight;
@@ -380,10 +434,11 @@ pub(super) fn apply_vehicle_kinematics(
                 front_longitudinal_accel -= braking_accel * 0.5;
                 rear_longitudinal_accel -= braking_accel * 0.5;
             }
-        } else {
+        } else if engine_running {
             front_longitudinal_accel -=
-                brake * brake_accel * front_drive_ratio * front_drive_factor;
-            rear_longitudinal_accel -= brake * brake_accel * rear_drive_ratio * rear_drive_factor;
+                wheel_brake * brake_accel * front_drive_ratio * front_drive_factor;
+            rear_longitudinal_accel -=
+                wheel_brake * brake_accel * rear_drive_ratio * rear_drive_factor;
         }
     }
 
@@ -401,12 +456,12 @@ pub(super) fn apply_vehicle_kinematics(
     let front_hardpoint_world = wheel_hardpoint_world(
         body_center,
         z_rot_rad,
-        Vec2::new(PLAYER_FRONT_HARDPOINT_X_M, PLAYER_FRONT_HARDPOINT_Y_M),
+        wheel_hardpoint_local(vehicle, WheelAxle::Front),
     );
     let rear_hardpoint_world = wheel_hardpoint_world(
         body_center,
         z_rot_rad,
-        Vec2::new(PLAYER_REAR_HARDPOINT_X_M, PLAYER_REAR_HARDPOINT_Y_M),
+        wheel_hardpoint_local(vehicle, WheelAxle::Rear),
     );
 
     let suspension_front_force = front_sample.support_force_n;
@@ -447,6 +502,13 @@ pub(super) fn apply_vehicle_kinematics(
             body_center,
         );
     }
+    if nitro_boosting {
+        let thrust_accel = vehicle
+            .nitro
+            .as_ref()
+            .map_or(0.0, |nitro_cfg| nitro_cfg.thrust_acceleration);
+        external_force.force += forward_direction * (thrust_accel * body_mass);
+    }
 
     let front_grounded_after = front_wheel_grounded;
     let rear_grounded_after = rear_wheel_grounded;
@@ -485,10 +547,10 @@ pub(super) fn apply_vehicle_kinematics(
         contact.grounded = false;
     }
 
-    velocity.linvel.x = velocity
-        .linvel
-        .x
-        .clamp(-vehicle.max_reverse_speed, vehicle.max_forward_speed);
+    velocity.linvel.x = velocity.linvel.x.clamp(
+        -vehicle.max_reverse_speed,
+        forward_speed_limit(vehicle, nitro),
+    );
     velocity.linvel.y = velocity
         .linvel
         .y
@@ -503,6 +565,98 @@ pub(super) fn apply_vehicle_kinematics(
     kinematics.angular_velocity = velocity.angvel;
 }
 
+fn forward_speed_limit(vehicle: &VehicleConfig, nitro: Option<&PlayerNitro>) -> f32 {
+    vehicle.max_forward_speed + nitro.map_or(0.0, |nitro| nitro.overspeed_mps)
+}
+
+// Runs before `apply_vehicle_kinematics`, so stunts and kills from the previous
+// step charge the meter and a boost fired here pushes on this step.
+#[allow(clippy::type_complexity)]
+pub(super) fn update_player_nitro(
+    time: Res<Time>,
+    run_stats: Res<RunStats>,
+    input_state: Res<VehicleInputState>,
+    mut stunt_events: MessageReader<VehicleStuntEvent>,
+    mut kill_events: MessageReader<EnemyKilledEvent>,
+    mut boost_events: MessageWriter<VehicleNitroBoostEvent>,
+    mut player_query: Query<
+        (&Transform, &Velocity, &mut PlayerNitro, Option<&PlayerFuel>),
+        With<PlayerVehicle>,
+    >,
+) {
+    let stunt_count = stunt_events.read().count() as f32;
+    let kill_count = kill_events.read().count() as f32;
+    let Ok((transform, velocity, mut nitro, fuel)) = player_query.single_mut() else {
+        return;
+    };
+    let Some(nitro_cfg) = run_stats
+        .player_vehicle()
+        .and_then(|vehicle| vehicle.nitro.as_ref())
+    else {
+        return;
+    };
+
+    let dt = time.delta_secs();
+    nitro.max = nitro_cfg.meter_capacity;
+    nitro.charge = (nitro.charge
+        + (stunt_count * nitro_cfg.stunt_charge)
+        + (kill_count * nitro_cfg.kill_charge))
+        .clamp(0.0, nitro.max);
+
+    if nitro.boosting() {
+        nitro.boost_remaining_s = (nitro.boost_remaining_s - dt).max(0.0);
+        nitro.overspeed_mps = nitro_cfg.max_speed_bonus_mps;
+        return;
+    }
+    nitro.overspeed_mps = move_towards(
+        nitro.overspeed_mps,
+        0.0,
+        (nitro_cfg.max_speed_bonus_mps / NITRO_OVERSPEED_FADE_S) * dt,
+    );
+
+    let engine_running = fuel.is_none_or(|fuel| fuel.current > 0.0);
+    if nitro.ready() && engine_running && input_state.accelerate && input_state.brake {
+        nitro.charge = 0.0;
+        nitro.boost_remaining_s = nitro_cfg.boost_duration_s;
+        nitro.overspeed_mps = nitro_cfg.max_speed_bonus_mps;
+        boost_events.write(VehicleNitroBoostEvent {
+            world_position: transform.translation.truncate(),
+            speed_mps: velocity.linvel.x,
+            duration_s: nitro_cfg.boost_duration_s,
+        });
+    }
+}
+
+pub(super) fn burn_player_fuel(
+    time: Res<Time>,
+    run_stats: Res<RunStats>,
+    input_state: Res<VehicleInputState>,
+    mut player_query: Query<(&mut PlayerFuel, &Velocity), With<PlayerVehicle>>,
+) {
+    let Ok((mut fuel, velocity)) = player_query.single_mut() else {
+        return;
+    };
+    let Some(fuel_cfg) = run_stats
+        .player_vehicle()
+        .and_then(|vehicle| vehicle.fuel.as_ref())
+    else {
+        return;
+    };
+
+    let dt = time.delta_secs();
+    fuel.max = fuel_cfg.tank_capacity;
+    let throttle = if input_state.accelerate { 1.0 } else { 0.0 };
+    let burn_per_second =
+        fuel_cfg.idle_burn_per_second + (fuel_cfg.throttle_burn_per_second * throttle);
+    fuel.current = (fuel.current - (burn_per_second * dt)).clamp(0.0, fuel.max);
+
+    if fuel.current <= 0.0 && velocity.linvel.length() < FUEL_STALL_SPEED_MPS {
+        fuel.stalled_s += dt;
+    } else {
+        fuel.stalled_s = 0.0;
+    }
+}
+
 pub(super) fn update_player_health_bar(
     player_query: Query<&PlayerHealth, With<PlayerVehicle>>,
     mut hp_fill_query: Query<(&PlayerHpBarFill, &mut Transform, &mut Sprite)>,
@@ -539,10 +693,12 @@ pub(super) fn reset_camera_follow_state(mut state: ResMut<CameraFollowState>) {
     *state = CameraFollowState::default();
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn camera_follow_vehicle(
     time: Res<Time>,
     telemetry: Res<VehicleTelemetry>,
     config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     debug_camera_pan: Option<Res<DebugCameraPanState>>,
     mut follow_state: ResMut<CameraFollowState>,
     player_query: Query<&Transform, With<PlayerVehicle>>,
@@ -552,7 +708,7 @@ pub(super) fn camera_follow_vehicle(
         (With<Camera2d>, Without<PlayerVehicle>),
     >,
 ) {
-    let Some(vehicle) = config.vehicles_by_id.get(&config.game.app.default_vehicle) else {
+    let Some(vehicle) = config.vehicles_by_id.get(&run_stats.player_vehicle_id) else {
         return;
     };
 
@@ -873,6 +1029,7 @@ fn sample_wheel_suspension(
     min_length_m: f32,
     max_length_m: f32,
     max_compression_m: f32,
+    wheel_radius_m: f32,
     stiffness: f32,
     damping: f32,
     dt: f32,
@@ -893,7 +1050,7 @@ fn sample_wheel_suspension(
         );
     }
 
-    let ray_length = max_length_m + PLAYER_WHEEL_RADIUS_M + GROUND_RAYCAST_MAX_DISTANCE_M;
+    let ray_length = max_length_m + wheel_radius_m + GROUND_RAYCAST_MAX_DISTANCE_M;
     let ray_filter = QueryFilter::only_fixed()
         .exclude_sensors()
         .exclude_rigid_body(player_entity);
@@ -910,7 +1067,7 @@ fn sample_wheel_suspension(
         .unwrap_or(Vec2::Y);
 
     let contact_length = hit_toi
-        .map(|toi| (toi - PLAYER_WHEEL_RADIUS_M).max(0.0))
+        .map(|toi| (toi - wheel_radius_m).max(0.0))
         .unwrap_or(max_length_m + GROUND_RAYCAST_MAX_DISTANCE_M);
     let grounded = contact_length <= (max_length_m + PLAYER_REAR_WHEEL_GROUND_EPSILON_M)
         && hit_normal.y >= MIN_DRIVEABLE_GROUND_NORMAL_Y
@@ -960,6 +1117,7 @@ fn sample_wheel_suspension(
 
 pub(super) fn rear_wheel_root_contact_y(
     config: &GameConfig,
+    vehicle: &VehicleConfig,
     root_x: f32,
     root_z_rotation: f32,
     rear_spring_length_m: f32,
@@ -967,10 +1125,36 @@ pub(super) fn rear_wheel_root_contact_y(
     let rear_hardpoint_world = wheel_hardpoint_world(
         Vec2::new(root_x, 0.0),
         root_z_rotation,
-        Vec2::new(PLAYER_REAR_HARDPOINT_X_M, PLAYER_REAR_HARDPOINT_Y_M),
+        wheel_hardpoint_local(vehicle, WheelAxle::Rear),
     );
     let rear_ground_y = terrain_height_at_x(config, rear_hardpoint_world.x);
-    rear_ground_y + PLAYER_WHEEL_RADIUS_M - (rear_hardpoint_world.y - rear_spring_length_m)
+    rear_ground_y + vehicle.wheel_radius_m - (rear_hardpoint_world.y - rear_spring_length_m)
+}
+
+pub(super) fn wheel_hardpoint_local(vehicle: &VehicleConfig, axle: WheelAxle) -> Vec2 {
+    match axle {
+        WheelAxle::Front => Vec2::new(vehicle.front_hardpoint_x_m, vehicle.front_hardpoint_y_m),
+        WheelAxle::Rear => Vec2::new(vehicle.rear_hardpoint_x_m, vehicle.rear_hardpoint_y_m),
+    }
+}
+
+pub(super) fn chassis_size(vehicle: &VehicleConfig) -> Vec2 {
+    Vec2::new(vehicle.chassis_length_m, vehicle.chassis_height_m)
+}
+
+// The collider is inset from the drawn chassis box so its corners do not snag.
+pub(super) fn chassis_collider_half_extents(vehicle: &VehicleConfig) -> Vec2 {
+    chassis_size(vehicle) * Vec2::new(0.48, 0.36)
+}
+
+pub(super) fn chassis_mass_properties(vehicle: &VehicleConfig) -> MassProperties {
+    let collider_size = chassis_collider_half_extents(vehicle) * 2.0;
+    let base_principal_inertia = (vehicle.chassis_mass_kg * collider_size.length_squared()) / 12.0;
+    MassProperties {
+        local_center_of_mass: Vec2::new(0.0, vehicle.chassis_center_of_mass_y_m),
+        mass: vehicle.chassis_mass_kg,
+        principal_inertia: base_principal_inertia * vehicle.rotational_inertia.max(0.05),
+    }
 }
 
 fn wheel_hardpoint_world(root_position: Vec2, root_z_rotation: f32, hardpoint_local: Vec2) -> Vec2 {
@@ -988,3 +1172,120 @@ fn move_towards(current: f32, target: f32, max_delta: f32) -> f32 {
         current + (target - current).signum() * max_delta
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const STEP_S: f32 = 0.1;
+
+    fn nitro_world() -> (World, Entity) {
+        let config =
+            GameConfig::load_from_dir(Path::new("config")).expect("repo config should load");
+        let run_stats = RunStats::for_vehicle(&config, "starter_car");
+        let meter_capacity = run_stats
+            .player_vehicle()
+            .and_then(|vehicle| vehicle.nitro.as_ref())
+            .expect("starter car should have nitro")
+            .meter_capacity;
+
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f32(STEP_S));
+        world.insert_resource(time);
+        world.insert_resource(run_stats);
+        world.insert_resource(VehicleInputState::default());
+        world.init_resource::<Messages<VehicleStuntEvent>>();
+        world.init_resource::<Messages<EnemyKilledEvent>>();
+        world.init_resource::<Messages<VehicleNitroBoostEvent>>();
+        let player = world
+            .spawn((
+                PlayerVehicle,
+                Transform::default(),
+                Velocity::default(),
+                PlayerNitro {
+                    charge: 0.0,
+                    max: meter_capacity,
+                    boost_remaining_s: 0.0,
+                    overspeed_mps: 0.0,
+                },
+            ))
+            .id();
+        (world, player)
+    }
+
+    #[test]
+    fn nitro_charges_from_stunts_and_kills_and_fires_only_when_full() {
+        let (mut world, player) = nitro_world();
+        let update_nitro = world.register_system(update_player_nitro);
+        let vehicle = world
+            .resource::<RunStats>()
+            .player_vehicle()
+            .unwrap()
+            .clone();
+        let nitro_cfg = vehicle.nitro.clone().unwrap();
+        let nitro = |world: &World| *world.get::<PlayerNitro>(player).unwrap();
+        let boost_count =
+            |world: &World| world.resource::<Messages<VehicleNitroBoostEvent>>().len();
+
+        *world.resource_mut::<VehicleInputState>() = VehicleInputState {
+            accelerate: true,
+            brake: true,
+        };
+        world
+            .resource_mut::<Messages<VehicleStuntEvent>>()
+            .write(VehicleStuntEvent::Flip { total_flips: 1 });
+        world
+            .resource_mut::<Messages<EnemyKilledEvent>>()
+            .write(EnemyKilledEvent {
+                enemy_type_id: "grunt".to_string(),
+                world_position: Vec2::ZERO,
+            });
+        world.run_system(update_nitro).unwrap();
+        assert_eq!(
+            nitro(&world).charge,
+            nitro_cfg.stunt_charge + nitro_cfg.kill_charge
+        );
+        assert!(!nitro(&world).boosting());
+        assert_eq!(boost_count(&world), 0);
+
+        world.resource_mut::<VehicleInputState>().brake = false;
+        let stunts_to_fill = (nitro_cfg.meter_capacity / nitro_cfg.stunt_charge).ceil() as usize;
+        for _ in 0..stunts_to_fill {
+            world
+                .resource_mut::<Messages<VehicleStuntEvent>>()
+                .write(VehicleStuntEvent::AirtimeBig { duration_s: 1.0 });
+        }
+        world.run_system(update_nitro).unwrap();
+        assert!(nitro(&world).ready());
+        assert_eq!(boost_count(&world), 0);
+
+        world.resource_mut::<VehicleInputState>().brake = true;
+        world.run_system(update_nitro).unwrap();
+        assert!(nitro(&world).boosting());
+        assert_eq!(nitro(&world).charge, 0.0);
+        assert_eq!(nitro(&world).overspeed_mps, nitro_cfg.max_speed_bonus_mps);
+        assert_eq!(boost_count(&world), 1);
+        assert!(forward_speed_limit(&vehicle, Some(&nitro(&world))) > vehicle.max_forward_speed);
+
+        *world.resource_mut::<VehicleInputState>() = VehicleInputState::default();
+        while nitro(&world).boosting() {
+            world.run_system(update_nitro).unwrap();
+        }
+        world.run_system(update_nitro).unwrap();
+        let fading = nitro(&world).overspeed_mps;
+        assert!(fading > 0.0 && fading < nitro_cfg.max_speed_bonus_mps);
+
+        let fade_steps = (NITRO_OVERSPEED_FADE_S / STEP_S).ceil() as usize;
+        for _ in 0..fade_steps {
+            world.run_system(update_nitro).unwrap();
+        }
+        assert_eq!(nitro(&world).overspeed_mps, 0.0);
+        assert_eq!(
+            forward_speed_limit(&vehicle, Some(&nitro(&world))),
+            vehicle.max_forward_speed
+        );
+        assert_eq!(boost_count(&world), 1);
+    }
+}
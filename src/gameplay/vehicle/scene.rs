@@ -6,6 +6,7 @@ pub(super) fn spawn_vehicle_scene(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
     asset_registry: Option<Res<AssetRegistry>>,
     asset_server: Res<AssetServer>,
     existing_player: Query<Entity, With<PlayerVehicle>>,
@@ -36,14 +37,15 @@ pub(super) fn spawn_vehicle_scene(
     }
 
     if existing_player.is_empty() {
-        let Some(vehicle) = config.vehicles_by_id.get(&config.game.app.default_vehicle) else {
+        let Some(vehicle) = run_stats.player_vehicle() else {
             return;
         };
+        // A `parts` table replaces the single-scene model entirely.
+        let part_spawns = resolve_player_vehicle_parts(asset_registry.as_deref(), vehicle);
         let model_scene = asset_registry
             .as_ref()
-            .and_then(|registry| {
-                resolve_vehicle_model_entry(registry, &config.game.app.default_vehicle)
-            })
+            .filter(|_| vehicle.parts.is_none())
+            .and_then(|registry| resolve_vehicle_model_entry(registry, vehicle))
             .and_then(|(_model_id, model_entry)| {
                 model_entry
                     .handle
@@ -57,14 +59,10 @@ pub(super) fn spawn_vehicle_scene(
                         },
                     })
             });
-        let chassis_half_extents =
-            Vec2::new(PLAYER_CHASSIS_SIZE.x * 0.48, PLAYER_CHASSIS_SIZE.y * 0.36);
-        let chassis_size = chassis_half_extents * 2.0;
-        let chassis_base_principal_inertia = (PLAYER_CHASSIS_MASS_KG
-            * ((chassis_size.x * chassis_size.x) + (chassis_size.y * chassis_size.y)))
-            / 12.0;
-        let chassis_principal_inertia =
-            chassis_base_principal_inertia * vehicle.rotational_inertia.max(0.05);
+        let chassis_half_extents = chassis_collider_half_extents(vehicle);
+        let front_hardpoint = wheel_hardpoint_local(vehicle, WheelAxle::Front);
+        let rear_hardpoint = wheel_hardpoint_local(vehicle, WheelAxle::Rear);
+        let wheel_visual_scale = Vec3::splat(vehicle.wheel_radius_m * PLAYER_WHEEL_VISUAL_SCALE);
 
         let player_entity = commands
             .spawn((
@@ -93,8 +91,13 @@ pub(super) fn spawn_vehicle_scene(
                 },
                 Transform::from_xyz(
                     0.0,
-                    rear_wheel_root_contact_y(&config, 0.0, 0.0, vehicle.suspension_rest_length_m)
-                        + START_HEIGHT_OFFSET,
+                    rear_wheel_root_contact_y(
+                        &config,
+                        vehicle,
+                        0.0,
+                        0.0,
+                        vehicle.suspension_rest_length_m,
+                    ) + START_HEIGHT_OFFSET,
                     10.0,
                 ),
                 GlobalTransform::default(),
@@ -105,11 +108,7 @@ pub(super) fn spawn_vehicle_scene(
             .insert((
                 RigidBody::Dynamic,
                 Collider::cuboid(chassis_half_extents.x, chassis_half_extents.y),
-                ColliderMassProperties::MassProperties(MassProperties {
-                    local_center_of_mass: Vec2::new(0.0, PLAYER_CHASSIS_CENTER_OF_MASS_Y_M),
-                    mass: PLAYER_CHASSIS_MASS_KG,
-                    principal_inertia: chassis_principal_inertia,
-                }),
+                ColliderMassProperties::MassProperties(chassis_mass_properties(vehicle)),
                 Friction::coefficient(1.20),
                 Restitution::coefficient(0.02),
                 GravityScale(vehicle.gravity_scale),
@@ -123,8 +122,24 @@ pub(super) fn spawn_vehicle_scene(
                 Sleeping::disabled(),
             ))
             .id();
+        if let Some(fuel) = &vehicle.fuel {
+            commands.entity(player_entity).insert(PlayerFuel {
+                current: fuel.tank_capacity,
+                max: fuel.tank_capacity,
+                stalled_s: 0.0,
+            });
+        }
+        if let Some(nitro) = &vehicle.nitro {
+            commands.entity(player_entity).insert(PlayerNitro {
+                charge: 0.0,
+                max: nitro.meter_capacity,
+                boost_remaining_s: 0.0,
+                overspeed_mps: 0.0,
+            });
+        }
 
-        let wheel_mesh = meshes.add(RegularPolygon::new(PLAYER_WHEEL_RADIUS_M, 6));
+        // Unit-radius mesh; `spin_wheel_pairs` scales it to the configured wheel radius.
+        let wheel_mesh = meshes.add(RegularPolygon::new(1.0, 6));
         let front_wheel_material =
             materials.add(ColorMaterial::from(Color::srgb(0.70, 0.80, 0.90)));
         let rear_wheel_material = materials.add(ColorMaterial::from(Color::srgb(0.62, 0.73, 0.84)));
@@ -144,11 +159,20 @@ pub(super) fn spawn_vehicle_scene(
                 ));
             }
 
+            for part_spawn in part_spawns {
+                parent.spawn((
+                    Name::new(format!("PlayerVehiclePart_{}", part_spawn.part.slot)),
+                    part_spawn.part,
+                    SceneRoot(part_spawn.handle),
+                    part_spawn.transform,
+                ));
+            }
+
             parent.spawn((
                 Name::new("PlayerChassis"),
                 PlayerChassisVisual,
                 PlayerVehiclePlaceholderVisual,
-                Sprite::from_color(Color::srgb(0.93, 0.34, 0.24), PLAYER_CHASSIS_SIZE),
+                Sprite::from_color(Color::srgb(0.93, 0.34, 0.24), chassis_size(vehicle)),
                 Transform::from_xyz(0.0, -0.02 + PLAYER_VISUAL_RIDE_HEIGHT_OFFSET_M, 0.00),
                 if DRAW_PLAYER_GAMEPLAY_BOX_VISUALS {
                     Visibility::Inherited
@@ -177,42 +201,32 @@ pub(super) fn spawn_vehicle_scene(
                 Name::new("PlayerWheelPairFront"),
                 PlayerWheelPairVisual {
                     axle: WheelAxle::Front,
-                    radius_m: PLAYER_WHEEL_RADIUS_M,
                     driven: false,
-                    hardpoint_local: Vec2::new(
-                        PLAYER_FRONT_HARDPOINT_X_M,
-                        PLAYER_FRONT_HARDPOINT_Y_M,
-                    ),
                 },
                 Mesh2d(wheel_mesh.clone()),
                 MeshMaterial2d(front_wheel_material.clone()),
                 Transform::from_xyz(
-                    PLAYER_FRONT_HARDPOINT_X_M,
-                    PLAYER_FRONT_HARDPOINT_Y_M - vehicle.suspension_rest_length_m,
+                    front_hardpoint.x,
+                    front_hardpoint.y - vehicle.suspension_rest_length_m,
                     0.80,
                 )
-                .with_scale(Vec3::splat(PLAYER_WHEEL_VISUAL_SCALE)),
+                .with_scale(wheel_visual_scale),
             ));
 
             parent.spawn((
                 Name::new("PlayerWheelPairRear"),
                 PlayerWheelPairVisual {
                     axle: WheelAxle::Rear,
-                    radius_m: PLAYER_WHEEL_RADIUS_M,
                     driven: true,
-                    hardpoint_local: Vec2::new(
-                        PLAYER_REAR_HARDPOINT_X_M,
-                        PLAYER_REAR_HARDPOINT_Y_M,
-                    ),
                 },
                 Mesh2d(wheel_mesh.clone()),
                 MeshMaterial2d(rear_wheel_material.clone()),
                 Transform::from_xyz(
-                    PLAYER_REAR_HARDPOINT_X_M,
-                    PLAYER_REAR_HARDPOINT_Y_M - vehicle.suspension_rest_length_m,
+                    rear_hardpoint.x,
+                    rear_hardpoint.y - vehicle.suspension_rest_length_m,
                     0.80,
                 )
-                .with_scale(Vec3::splat(PLAYER_WHEEL_VISUAL_SCALE)),
+                .with_scale(wheel_visual_scale),
             ));
 
             parent.spawn((
@@ -389,10 +403,20 @@ pub(super) fn cleanup_vehicle_scene(
     }
 }
 
+// An explicit `model_id` wins; otherwise fall back to the `vehicle_<id>` naming
+// convention in assets.toml::models.
 fn resolve_vehicle_model_entry<'a>(
     registry: &'a AssetRegistry,
-    vehicle_id: &str,
+    vehicle: &VehicleConfig,
 ) -> Option<(String, &'a ModelAssetEntry)> {
+    if let Some(model_id) = vehicle.model_id.as_deref() {
+        return registry
+            .models
+            .get(model_id)
+            .map(|entry| (model_id.to_string(), entry));
+    }
+
+    let vehicle_id = vehicle.id.as_str();
     let preferred_id = format!("vehicle_{vehicle_id}");
     if let Some(entry) = registry.models.get(&preferred_id) {
         return Some((preferred_id, entry));
@@ -0,0 +1,137 @@
+use crate::config::{GameConfig, RunStats};
+use crate::gameplay::autopilot::Autopilot;
+use crate::gameplay::vehicle::VehicleInputState;
+use crate::gameplay::GameplaySimulationPlugin;
+use crate::replay::{ReplayFile, ReplayFrame, ReplayPlayback, ReplayPlugin, ReplayUpgradeDecision};
+use crate::rng::RunRngPlugin;
+use crate::states::{ActiveRun, GameState, GameStatePlugin, RunSummary};
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::scene::ScenePlugin;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use bevy::transform::TransformPlugin;
+use bevy_rapier2d::prelude::*;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[cfg(feature = "gaussian_splats")]
+use bevy_gaussian_splatting::PlanarGaussian3d;
+
+// Upgrade confirmations wait on real time, so a run needs a few updates beyond its ticks.
+const HEADLESS_EXTRA_UPDATES: usize = 600;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeadlessInputSpan {
+    pub duration_s: f32,
+    pub input: VehicleInputState,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HeadlessRun {
+    pub seed: u64,
+    // `None` drives `game.toml::app.default_vehicle`.
+    pub vehicle_id: Option<String>,
+    pub duration_s: f32,
+    pub inputs: Vec<HeadlessInputSpan>,
+    pub upgrade_decisions: Vec<ReplayUpgradeDecision>,
+    pub autopilot: bool,
+}
+
+impl HeadlessRun {
+    pub fn to_replay_file(&self, config: &GameConfig) -> ReplayFile {
+        let fixed_timestep_hz = config.game.app.fixed_timestep_hz;
+        let tick_count = ticks_for_duration(self.duration_s, fixed_timestep_hz);
+        let mut frames = Vec::with_capacity(tick_count);
+        for span in &self.inputs {
+            let frame = ReplayFrame {
+                accelerate: span.input.accelerate,
+                brake: span.input.brake,
+            };
+            frames.extend(std::iter::repeat_n(
+                frame,
+                ticks_for_duration(span.duration_s, fixed_timestep_hz),
+            ));
+        }
+        frames.resize(tick_count, ReplayFrame::default());
+
+        ReplayFile {
+            seed: self.seed,
+            vehicle_id: self
+                .vehicle_id
+                .clone()
+                .unwrap_or_else(|| config.game.app.default_vehicle.clone()),
+            frames,
+            upgrade_decisions: self.upgrade_decisions.clone(),
+        }
+    }
+}
+
+pub fn build_headless_app(config: GameConfig, run: &HeadlessRun) -> App {
+    let fixed_timestep_hz = config.game.app.fixed_timestep_hz;
+    let replay = run.to_replay_file(&config);
+    let run_stats = RunStats::for_vehicle(&config, &replay.vehicle_id);
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        StatesPlugin,
+        InputPlugin,
+        TransformPlugin,
+        AssetPlugin::default(),
+        ScenePlugin,
+    ))
+    .init_asset::<Image>()
+    .init_asset::<Mesh>()
+    .init_asset::<ColorMaterial>()
+    .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(1.0).in_fixed_schedule())
+    // One update per fixed tick, independent of how fast the host machine is.
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+        1.0 / fixed_timestep_hz as f64,
+    )))
+    .insert_resource(run_stats)
+    .insert_resource(config)
+    .insert_resource(ReplayPlayback::new(PathBuf::from("<headless>"), replay))
+    .add_plugins(RunRngPlugin)
+    .add_plugins(ReplayPlugin)
+    .add_plugins(GameplaySimulationPlugin)
+    .insert_state(GameState::Replay)
+    .add_plugins(GameStatePlugin);
+
+    if run.autopilot {
+        app.insert_resource(Autopilot);
+    }
+
+    #[cfg(feature = "gaussian_splats")]
+    app.init_asset::<PlanarGaussian3d>();
+
+    app.finish();
+    app.cleanup();
+    app
+}
+
+pub fn run_headless(config: GameConfig, run: &HeadlessRun) -> RunSummary {
+    let tick_count = ticks_for_duration(run.duration_s, config.game.app.fixed_timestep_hz);
+    let mut app = build_headless_app(config, run);
+
+    let mut finished = false;
+    for _ in 0..tick_count + HEADLESS_EXTRA_UPDATES {
+        app.update();
+        if !app.world().contains_resource::<State<ActiveRun>>() {
+            finished = true;
+            break;
+        }
+    }
+    if !finished {
+        warn!(
+            "Headless run (seed {}) ran out of updates; is an upgrade offer missing a decision?",
+            run.seed
+        );
+    }
+
+    app.world().resource::<RunSummary>().clone()
+}
+
+fn ticks_for_duration(duration_s: f32, fixed_timestep_hz: f32) -> usize {
+    (duration_s.max(0.0) * fixed_timestep_hz).round() as usize
+}
@@ -0,0 +1,26 @@
+mod assets;
+mod cli;
+mod commentary_stub;
+mod config;
+mod debug;
+mod gameplay;
+mod headless;
+mod replay;
+mod rng;
+mod states;
+mod ui;
+mod web;
+
+pub use assets::AssetRegistryPlugin;
+pub use cli::{CliArgs, USAGE};
+pub use commentary_stub::CommentaryStubPlugin;
+pub use config::{config_file_schemas, requested_config_profiles, ConfigPlugin, GameConfig};
+pub use debug::DebugOverlayPlugin;
+pub use gameplay::vehicle::VehicleInputState;
+pub use gameplay::GameplayPlugin;
+pub use headless::{build_headless_app, run_headless, HeadlessInputSpan, HeadlessRun};
+pub use replay::{ReplayPlugin, ReplayUpgradeDecision};
+pub use rng::RunRngPlugin;
+pub use states::{GameState, GameStatePlugin, RunEndReason, RunSummary};
+pub use ui::GameHudPlugin;
+pub use web::WebSupportPlugin;
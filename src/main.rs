@@ -1,13 +1,3 @@
-mod assets;
-mod commentary_stub;
-mod config;
-mod debug;
-mod gameplay;
-mod states;
-mod ui;
-mod web;
-
-use assets::AssetRegistryPlugin;
 use bevy::asset::{AssetMetaCheck, AssetPlugin};
 use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
 use bevy::prelude::*;
@@ -17,15 +7,18 @@ use bevy_egui::EguiPlugin;
 #[cfg(feature = "gaussian_splats")]
 use bevy_gaussian_splatting::GaussianSplattingPlugin;
 use bevy_rapier2d::prelude::*;
-use commentary_stub::CommentaryStubPlugin;
-use config::ConfigPlugin;
-use debug::DebugOverlayPlugin;
-use gameplay::GameplayPlugin;
-use states::{GameState, GameStatePlugin};
-use ui::GameHudPlugin;
-use web::WebSupportPlugin;
+use mr_autoauto::{
+    AssetRegistryPlugin, CliArgs, CommentaryStubPlugin, ConfigPlugin, DebugOverlayPlugin,
+    GameHudPlugin, GameState, GameStatePlugin, GameplayPlugin, ReplayPlugin, RunRngPlugin,
+    WebSupportPlugin, USAGE,
+};
 
 fn main() {
+    let cli = CliArgs::from_env().unwrap_or_else(|error| {
+        eprintln!("{error}\n{USAGE}");
+        std::process::exit(2);
+    });
+
     let mut primary_window = Window {
         title: "Mr. Autoauto".to_string(),
         resolution: (1280, 720).into(),
@@ -63,9 +56,12 @@ fn main() {
             }),
     )
     .add_plugins(EguiPlugin::default())
-    .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(1.0))
+    .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(1.0).in_fixed_schedule())
     .add_plugins(FrameTimeDiagnosticsPlugin::default())
+    .insert_resource(cli)
     .add_plugins(ConfigPlugin)
+    .add_plugins(RunRngPlugin)
+    .add_plugins(ReplayPlugin)
     .add_plugins(AssetRegistryPlugin)
     .add_plugins(WebSupportPlugin)
     .add_plugins(DebugOverlayPlugin)
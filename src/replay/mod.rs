@@ -0,0 +1,444 @@
+use crate::cli::CliArgs;
+use crate::config::RunStats;
+use crate::gameplay::fixed_step::GameplaySimSet;
+use crate::gameplay::vehicle::{VehicleInputSet, VehicleInputState};
+use crate::rng::{RunRng, RunSeedSet};
+use crate::states::GameState;
+use bevy::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const REPLAY_MAGIC: &[u8; 4] = b"MRAR";
+const REPLAY_FORMAT_VERSION: u8 = 3;
+const REPLAY_DIR: &str = "replays";
+const REPLAY_FILE_EXTENSION: &str = "mrreplay";
+const INPUT_ACCELERATE_BIT: u8 = 1 << 0;
+const INPUT_BRAKE_BIT: u8 = 1 << 1;
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplayRecorder>()
+            .add_systems(Startup, load_replay_from_cli)
+            .add_systems(
+                OnEnter(GameState::InRun),
+                start_replay_recording.after(RunSeedSet),
+            )
+            .add_systems(OnEnter(GameState::Results), save_recorded_replay)
+            .add_systems(OnEnter(GameState::Replay), enter_replay)
+            .add_systems(OnExit(GameState::Replay), finish_replay_playback)
+            .add_systems(
+                FixedUpdate,
+                record_replay_frame
+                    .in_set(GameplaySimSet::Input)
+                    .after(VehicleInputSet)
+                    .run_if(in_state(GameState::InRun)),
+            )
+            .add_systems(Update, replay_controls.run_if(in_state(GameState::Replay)));
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayUpgradeDecision {
+    Pick(String),
+    Reroll,
+    Skip,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReplayFrame {
+    pub accelerate: bool,
+    pub brake: bool,
+}
+
+impl ReplayFrame {
+    pub fn input(self) -> VehicleInputState {
+        VehicleInputState {
+            accelerate: self.accelerate,
+            brake: self.brake,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplayFile {
+    pub seed: u64,
+    pub vehicle_id: String,
+    pub frames: Vec<ReplayFrame>,
+    pub upgrade_decisions: Vec<ReplayUpgradeDecision>,
+}
+
+impl ReplayFile {
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let bytes = fs::read(path)
+            .map_err(|error| format!("Failed reading `{}`: {error}", path.display()))?;
+        Self::from_bytes(&bytes)
+            .map_err(|error| format!("Invalid replay `{}`: {error}", path.display()))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| format!("Failed creating `{}`: {error}", parent.display()))?;
+        }
+        fs::write(path, self.to_bytes())
+            .map_err(|error| format!("Failed writing `{}`: {error}", path.display()))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(23 + self.vehicle_id.len() + self.frames.len());
+        bytes.extend_from_slice(REPLAY_MAGIC);
+        bytes.push(REPLAY_FORMAT_VERSION);
+        bytes.extend_from_slice(&self.seed.to_le_bytes());
+        bytes.extend_from_slice(&(self.vehicle_id.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(self.vehicle_id.as_bytes());
+
+        bytes.extend_from_slice(&(self.frames.len() as u32).to_le_bytes());
+        for frame in &self.frames {
+            let mut input_bits = 0_u8;
+            if frame.accelerate {
+                input_bits |= INPUT_ACCELERATE_BIT;
+            }
+            if frame.brake {
+                input_bits |= INPUT_BRAKE_BIT;
+            }
+            bytes.push(input_bits);
+        }
+
+        bytes.extend_from_slice(&(self.upgrade_decisions.len() as u32).to_le_bytes());
+        for decision in &self.upgrade_decisions {
+            match decision {
+                ReplayUpgradeDecision::Pick(upgrade_id) => {
+                    bytes.push(0);
+                    bytes.extend_from_slice(&(upgrade_id.len() as u16).to_le_bytes());
+                    bytes.extend_from_slice(upgrade_id.as_bytes());
+                }
+                ReplayUpgradeDecision::Reroll => bytes.push(1),
+                ReplayUpgradeDecision::Skip => bytes.push(2),
+            }
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let mut reader = ReplayByteReader { bytes, offset: 0 };
+        if reader.take(4)? != REPLAY_MAGIC {
+            return Err("missing replay header".to_string());
+        }
+        let version = reader.u8()?;
+        if version != REPLAY_FORMAT_VERSION {
+            return Err(format!(
+                "unsupported replay version {version} (expected {REPLAY_FORMAT_VERSION})"
+            ));
+        }
+        let seed = u64::from_le_bytes(reader.array()?);
+        let vehicle_id_len = u16::from_le_bytes(reader.array()?) as usize;
+        let vehicle_id = std::str::from_utf8(reader.take(vehicle_id_len)?)
+            .map_err(|_| "vehicle id is not UTF-8".to_string())?
+            .to_string();
+
+        let frame_count = u32::from_le_bytes(reader.array()?) as usize;
+        let mut frames = Vec::with_capacity(frame_count.min(bytes.len()));
+        for _ in 0..frame_count {
+            let input_bits = reader.u8()?;
+            frames.push(ReplayFrame {
+                accelerate: input_bits & INPUT_ACCELERATE_BIT != 0,
+                brake: input_bits & INPUT_BRAKE_BIT != 0,
+            });
+        }
+
+        let decision_count = u32::from_le_bytes(reader.array()?) as usize;
+        let mut upgrade_decisions = Vec::with_capacity(decision_count.min(bytes.len()));
+        for index in 0..decision_count {
+            let decision = match reader.u8()? {
+                0 => {
+                    let len = u16::from_le_bytes(reader.array()?) as usize;
+                    let upgrade_id = std::str::from_utf8(reader.take(len)?)
+                        .map_err(|_| format!("upgrade decision {index} has a non-UTF-8 id"))?;
+                    ReplayUpgradeDecision::Pick(upgrade_id.to_string())
+                }
+                1 => ReplayUpgradeDecision::Reroll,
+                2 => ReplayUpgradeDecision::Skip,
+                tag => return Err(format!("upgrade decision {index} has unknown tag {tag}")),
+            };
+            upgrade_decisions.push(decision);
+        }
+
+        if reader.offset != bytes.len() {
+            return Err(format!(
+                "{} trailing bytes after replay data",
+                bytes.len() - reader.offset
+            ));
+        }
+        Ok(Self {
+            seed,
+            vehicle_id,
+            frames,
+            upgrade_decisions,
+        })
+    }
+}
+
+struct ReplayByteReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> ReplayByteReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| format!("replay truncated at byte {}", self.offset))?;
+        let slice = &self.bytes[self.offset..end];
+        self.offset = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let mut array = [0_u8; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct ReplayRecorder {
+    recording: Option<ReplayFile>,
+}
+
+impl ReplayRecorder {
+    pub fn record_upgrade_decision(&mut self, decision: ReplayUpgradeDecision) {
+        if let Some(recording) = self.recording.as_mut() {
+            recording.upgrade_decisions.push(decision);
+        }
+    }
+}
+
+#[derive(Resource, Debug)]
+pub struct ReplayPlayback {
+    source: PathBuf,
+    file: ReplayFile,
+    next_frame: usize,
+    next_upgrade_decision: usize,
+    desynced_upgrade_decision: Option<usize>,
+}
+
+impl ReplayPlayback {
+    pub fn new(source: PathBuf, file: ReplayFile) -> Self {
+        Self {
+            source,
+            file,
+            next_frame: 0,
+            next_upgrade_decision: 0,
+            desynced_upgrade_decision: None,
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.file.seed
+    }
+
+    pub fn vehicle_id(&self) -> &str {
+        &self.file.vehicle_id
+    }
+
+    pub fn next_frame(&mut self) -> Option<ReplayFrame> {
+        let frame = self.file.frames.get(self.next_frame).copied()?;
+        self.next_frame += 1;
+        Some(frame)
+    }
+
+    pub fn peek_upgrade_decision(&self) -> Option<&ReplayUpgradeDecision> {
+        self.file.upgrade_decisions.get(self.next_upgrade_decision)
+    }
+
+    pub fn next_upgrade_decision(&mut self) -> Option<ReplayUpgradeDecision> {
+        let decision = self
+            .file
+            .upgrade_decisions
+            .get(self.next_upgrade_decision)
+            .cloned()?;
+        self.next_upgrade_decision += 1;
+        Some(decision)
+    }
+
+    // A stuck decision is retried every frame, so it is only reported once.
+    pub fn report_upgrade_desync(&mut self, reason: &str) {
+        if self.desynced_upgrade_decision == Some(self.next_upgrade_decision) {
+            return;
+        }
+        self.desynced_upgrade_decision = Some(self.next_upgrade_decision);
+        warn!(
+            "Replay `{}` is out of sync at upgrade decision {}: {reason}",
+            self.source.display(),
+            self.next_upgrade_decision
+        );
+    }
+
+    fn is_finished(&self) -> bool {
+        self.next_frame >= self.file.frames.len()
+    }
+}
+
+fn load_replay_from_cli(mut commands: Commands, cli: Option<Res<CliArgs>>) {
+    let Some(path) = cli.and_then(|cli| cli.replay.clone()) else {
+        return;
+    };
+
+    match ReplayFile::read(&path) {
+        Ok(file) => {
+            info!(
+                "Loaded replay `{}`: seed {}, vehicle `{}`, {} frames, {} upgrade decisions.",
+                path.display(),
+                file.seed,
+                file.vehicle_id,
+                file.frames.len(),
+                file.upgrade_decisions.len()
+            );
+            commands.insert_resource(ReplayPlayback::new(path, file));
+        }
+        Err(error) => error!("{error}. Starting a normal run instead."),
+    }
+}
+
+fn start_replay_recording(
+    rng: Res<RunRng>,
+    run_stats: Res<RunStats>,
+    mut recorder: ResMut<ReplayRecorder>,
+) {
+    recorder.recording = Some(ReplayFile {
+        seed: rng.seed(),
+        vehicle_id: run_stats.player_vehicle_id.clone(),
+        ..default()
+    });
+}
+
+fn record_replay_frame(input_state: Res<VehicleInputState>, mut recorder: ResMut<ReplayRecorder>) {
+    let Some(recording) = recorder.recording.as_mut() else {
+        return;
+    };
+
+    recording.frames.push(ReplayFrame {
+        accelerate: input_state.accelerate,
+        brake: input_state.brake,
+    });
+}
+
+fn save_recorded_replay(mut recorder: ResMut<ReplayRecorder>) {
+    let Some(recording) = recorder.recording.take() else {
+        return;
+    };
+    if recording.frames.is_empty() {
+        return;
+    }
+
+    let path = Path::new(REPLAY_DIR).join(format!(
+        "run_{:016x}.{REPLAY_FILE_EXTENSION}",
+        recording.seed
+    ));
+    match recording.write(&path) {
+        Ok(()) => info!(
+            "Saved replay to `{}` ({} frames, {} upgrade decisions).",
+            path.display(),
+            recording.frames.len(),
+            recording.upgrade_decisions.len()
+        ),
+        Err(error) => warn!("Replay not saved: {error}"),
+    }
+}
+
+fn enter_replay(playback: Option<Res<ReplayPlayback>>) {
+    if let Some(playback) = playback {
+        info!(
+            "Entered state: Replay (`{}`, seed {}).",
+            playback.source.display(),
+            playback.seed()
+        );
+    }
+}
+
+fn replay_controls(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    playback: Option<Res<ReplayPlayback>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let finished = playback.is_none_or(|playback| playback.is_finished());
+    if finished {
+        info!("Replay finished.");
+        next_state.set(GameState::Results);
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::Escape) {
+        info!("Replay stopped.");
+        next_state.set(GameState::Results);
+    }
+}
+
+fn finish_replay_playback(mut commands: Commands) {
+    commands.remove_resource::<ReplayPlayback>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replay_file_round_trips_through_bytes() {
+        let file = ReplayFile {
+            seed: 0xDEAD_BEEF_0123_4567,
+            vehicle_id: "monster_truck".to_string(),
+            frames: vec![
+                ReplayFrame {
+                    accelerate: true,
+                    brake: false,
+                },
+                ReplayFrame {
+                    accelerate: false,
+                    brake: true,
+                },
+            ],
+            upgrade_decisions: vec![
+                ReplayUpgradeDecision::Reroll,
+                ReplayUpgradeDecision::Pick("armor_plating".to_string()),
+                ReplayUpgradeDecision::Skip,
+            ],
+        };
+
+        let bytes = file.to_bytes();
+        assert_eq!(ReplayFile::from_bytes(&bytes), Ok(file));
+        assert!(ReplayFile::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn peeking_an_upgrade_decision_leaves_it_for_the_next_offer() {
+        let mut playback = ReplayPlayback::new(
+            PathBuf::from("<test>"),
+            ReplayFile {
+                upgrade_decisions: vec![
+                    ReplayUpgradeDecision::Pick("armor_plating".to_string()),
+                    ReplayUpgradeDecision::Skip,
+                ],
+                ..default()
+            },
+        );
+
+        let pick = ReplayUpgradeDecision::Pick("armor_plating".to_string());
+        assert_eq!(playback.peek_upgrade_decision(), Some(&pick));
+        playback.report_upgrade_desync("not offered");
+        assert_eq!(playback.peek_upgrade_decision(), Some(&pick));
+        assert_eq!(playback.next_upgrade_decision(), Some(pick));
+        assert_eq!(
+            playback.next_upgrade_decision(),
+            Some(ReplayUpgradeDecision::Skip)
+        );
+        assert_eq!(playback.peek_upgrade_decision(), None);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::cli::CliArgs;
+use crate::replay::ReplayPlayback;
+use crate::states::ActiveRun;
+use bevy::prelude::*;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const LCG_MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+const LCG_INCREMENT: u64 = 1_442_695_040_888_963_407;
+
+pub struct RunRngPlugin;
+
+impl Plugin for RunRngPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(RunRng::new(fresh_run_seed()))
+            .add_systems(OnEnter(ActiveRun), reseed_run_rng.in_set(RunSeedSet));
+    }
+}
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RunSeedSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RngStream {
+    Spawns,
+    Drops,
+    Upgrades,
+    WeaponSpread,
+    CosmeticFx,
+}
+
+impl RngStream {
+    const ALL: [Self; 5] = [
+        Self::Spawns,
+        Self::Drops,
+        Self::Upgrades,
+        Self::WeaponSpread,
+        Self::CosmeticFx,
+    ];
+
+    fn index(self) -> usize {
+        match self {
+            Self::Spawns => 0,
+            Self::Drops => 1,
+            Self::Upgrades => 2,
+            Self::WeaponSpread => 3,
+            Self::CosmeticFx => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamRng {
+    state: u64,
+}
+
+impl StreamRng {
+    pub fn next_u32(&mut self) -> u32 {
+        self.state = self
+            .state
+            .wrapping_mul(LCG_MULTIPLIER)
+            .wrapping_add(LCG_INCREMENT);
+        (self.state >> 32) as u32
+    }
+
+    pub fn unit(&mut self) -> f32 {
+        self.next_u32() as f32 / u32::MAX as f32
+    }
+
+    pub fn signed_unit(&mut self) -> f32 {
+        (self.unit() * 2.0) - 1.0
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        if len <= 1 {
+            return 0;
+        }
+        self.next_u32() as usize % len
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct RunRng {
+    seed: u64,
+    streams: [StreamRng; RngStream::ALL.len()],
+}
+
+impl RunRng {
+    pub fn new(seed: u64) -> Self {
+        let streams = RngStream::ALL.map(|stream| StreamRng {
+            state: split_mix64(seed ^ (stream.index() as u64 + 1).wrapping_mul(0x9E37_79B9)),
+        });
+        Self { seed, streams }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn stream(&mut self, stream: RngStream) -> &mut StreamRng {
+        &mut self.streams[stream.index()]
+    }
+}
+
+fn reseed_run_rng(
+    cli: Option<Res<CliArgs>>,
+    replay: Option<Res<ReplayPlayback>>,
+    mut rng: ResMut<RunRng>,
+) {
+    let seed = replay
+        .map(|replay| replay.seed())
+        .or_else(|| cli.and_then(|cli| cli.seed))
+        .unwrap_or_else(fresh_run_seed);
+    *rng = RunRng::new(seed);
+    info!("Run seed: {seed}");
+}
+
+fn split_mix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn fresh_run_seed() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    split_mix64(nanos as u64 ^ (nanos >> 64) as u64)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn fresh_run_seed() -> u64 {
+    let mut bytes = [0_u8; 8];
+    if getrandom::getrandom(&mut bytes).is_err() {
+        return split_mix64(0x4D52_4155_544F_4155);
+    }
+    u64::from_le_bytes(bytes)
+}
@@ -0,0 +1,206 @@
+use super::GameState;
+use crate::config::{GameConfig, RunStats, VehicleConfig};
+use crate::web::VirtualControlState;
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub(super) struct GarageScreenRoot;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct GarageVehicleCard {
+    index: usize,
+}
+
+#[derive(Resource, Debug, Clone)]
+pub(super) struct GarageSelection {
+    vehicle_ids: Vec<String>,
+    cursor: usize,
+}
+
+pub(super) fn enter_garage(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
+) {
+    let vehicles = &config.vehicles.vehicles;
+    let cursor = vehicles
+        .iter()
+        .position(|vehicle| vehicle.id == run_stats.player_vehicle_id)
+        .unwrap_or(0);
+    commands.insert_resource(GarageSelection {
+        vehicle_ids: vehicles.iter().map(|vehicle| vehicle.id.clone()).collect(),
+        cursor,
+    });
+
+    let card_width_percent = (96.0 / vehicles.len().max(1) as f32).min(32.0);
+    commands
+        .spawn((
+            Name::new("GarageOverlay"),
+            GarageScreenRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.01, 0.02, 0.03, 0.94)),
+            ZIndex(300),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(94.0),
+                        max_width: Val::Px(1080.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(14.0),
+                        padding: UiRect::all(Val::Px(18.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.10, 0.13, 0.96)),
+                    BorderColor::all(Color::srgba(0.56, 0.62, 0.68, 0.92)),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("GARAGE"),
+                        TextFont {
+                            font_size: 52.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.94, 0.97, 1.00)),
+                    ));
+                    panel
+                        .spawn((
+                            Name::new("GarageCardsRow"),
+                            Node {
+                                width: Val::Percent(100.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Stretch,
+                                column_gap: Val::Px(12.0),
+                                ..default()
+                            },
+                        ))
+                        .with_children(|cards_row| {
+                            for (index, vehicle) in vehicles.iter().enumerate() {
+                                cards_row
+                                    .spawn((
+                                        Name::new("GarageVehicleCard"),
+                                        GarageVehicleCard { index },
+                                        Node {
+                                            width: Val::Percent(card_width_percent),
+                                            min_height: Val::Px(188.0),
+                                            padding: UiRect::all(Val::Px(12.0)),
+                                            border: UiRect::all(Val::Px(1.0)),
+                                            ..default()
+                                        },
+                                        BackgroundColor(Color::srgba(0.13, 0.17, 0.22, 0.98)),
+                                        BorderColor::all(Color::srgba(0.56, 0.66, 0.74, 0.95)),
+                                    ))
+                                    .with_children(|card| {
+                                        card.spawn((
+                                            Text::new(build_vehicle_card_text(vehicle)),
+                                            TextFont {
+                                                font_size: 18.0,
+                                                ..default()
+                                            },
+                                            TextColor(Color::srgb(0.93, 0.96, 1.00)),
+                                        ));
+                                    });
+                            }
+                        });
+                    panel.spawn((
+                        Text::new("A/Left: next vehicle    D/Right: start run"),
+                        TextFont {
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.90, 0.94, 0.98)),
+                    ));
+                });
+        });
+
+    info!("Entered state: Garage");
+}
+
+fn build_vehicle_card_text(vehicle: &VehicleConfig) -> String {
+    let secondary = vehicle.secondary_weapon_id.as_deref().unwrap_or("none");
+    let fuel = vehicle.fuel.as_ref().map_or_else(
+        || "unlimited".to_string(),
+        |fuel| format!("{:.0}", fuel.tank_capacity),
+    );
+    let nitro = vehicle.nitro.as_ref().map_or_else(
+        || "none".to_string(),
+        |nitro| format!("+{:.1} m/s", nitro.max_speed_bonus_mps),
+    );
+    format!(
+        "{}\n\nHealth: {:.0}\nAcceleration: {:.0}\nTop Speed: {:.1} m/s\nGravity: {:.2}\nFuel: {fuel}\nNitro: {nitro}\nGun: {}\nSecondary: {secondary}",
+        vehicle.display_label(),
+        vehicle.health,
+        vehicle.acceleration,
+        vehicle.max_forward_speed,
+        vehicle.gravity_scale,
+        vehicle.default_weapon_id,
+    )
+}
+
+pub(super) fn cleanup_garage_screen(
+    mut commands: Commands,
+    garage_screen_query: Query<Entity, With<GarageScreenRoot>>,
+) {
+    for entity in &garage_screen_query {
+        commands.entity(entity).try_despawn();
+    }
+    commands.remove_resource::<GarageSelection>();
+}
+
+pub(super) fn garage_controls(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    virtual_controls: Option<Res<VirtualControlState>>,
+    config: Res<GameConfig>,
+    mut selection: ResMut<GarageSelection>,
+    mut run_stats: ResMut<RunStats>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let virtual_controls = virtual_controls.as_deref();
+    if keyboard.just_pressed(KeyCode::KeyA)
+        || keyboard.just_pressed(KeyCode::ArrowLeft)
+        || virtual_controls.is_some_and(|controls| controls.brake_just_pressed)
+    {
+        selection.cursor = (selection.cursor + 1) % selection.vehicle_ids.len().max(1);
+        return;
+    }
+
+    if !(keyboard.just_pressed(KeyCode::KeyD)
+        || keyboard.just_pressed(KeyCode::ArrowRight)
+        || virtual_controls.is_some_and(|controls| controls.accelerate_just_pressed))
+    {
+        return;
+    }
+    let Some(vehicle_id) = selection.vehicle_ids.get(selection.cursor) else {
+        return;
+    };
+    *run_stats = RunStats::for_vehicle(&config, vehicle_id);
+    info!("Selected vehicle `{}`.", run_stats.player_vehicle_id);
+    next_state.set(GameState::InRun);
+}
+
+pub(super) fn update_garage_cards(
+    selection: Res<GarageSelection>,
+    mut card_query: Query<(&GarageVehicleCard, &mut BackgroundColor, &mut BorderColor)>,
+) {
+    if !selection.is_changed() {
+        return;
+    }
+
+    for (card, mut bg, mut border) in &mut card_query {
+        if card.index == selection.cursor {
+            *bg = BackgroundColor(Color::srgba(0.18, 0.24, 0.31, 0.98));
+            *border = BorderColor::all(Color::srgba(0.96, 0.86, 0.45, 0.98));
+        } else {
+            *bg = BackgroundColor(Color::srgba(0.13, 0.17, 0.22, 0.98));
+            *border = BorderColor::all(Color::srgba(0.56, 0.66, 0.74, 0.95));
+        }
+    }
+}
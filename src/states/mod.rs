@@ -1,14 +1,22 @@
+mod garage;
+
 use crate::assets::AssetRegistry;
-use crate::config::GameConfig;
+use crate::config::{GameConfig, RunStats, VehicleConfig};
 use crate::gameplay::combat::EnemyKilledEvent;
+use crate::gameplay::enemies::{PlayerDamageEvent, PlayerDamageSource};
 use crate::gameplay::pickups::{PickupCollectedEvent, PickupKind};
+use crate::gameplay::upgrades::UpgradeAppliedEvent;
 use crate::gameplay::vehicle::{
-    PlayerHealth, PlayerVehicle, VehicleStuntMetrics, VehicleTelemetry,
+    PlayerFuel, PlayerHealth, PlayerVehicle, VehicleLandingEvent, VehicleStuntMetrics,
+    VehicleTelemetry,
 };
+use crate::replay::ReplayPlayback;
+use crate::rng::{RunRng, RunSeedSet};
 use bevy::app::AppExit;
 use bevy::asset::LoadState;
 use bevy::prelude::*;
-use std::collections::HashSet;
+use garage::{cleanup_garage_screen, enter_garage, garage_controls, update_garage_cards};
+use std::collections::{BTreeMap, HashSet};
 
 #[cfg(feature = "gaussian_splats")]
 use bevy_gaussian_splatting::PlanarGaussian3d;
@@ -21,16 +29,30 @@ pub enum GameState {
     #[default]
     Boot,
     Loading,
+    Garage,
     InRun,
+    Replay,
     Pause,
     Results,
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ActiveRun;
+
+impl ComputedStates for ActiveRun {
+    type SourceStates = GameState;
+
+    fn compute(state: GameState) -> Option<Self> {
+        matches!(state, GameState::InRun | GameState::Replay).then_some(Self)
+    }
+}
+
 pub struct GameStatePlugin;
 
 impl Plugin for GameStatePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<RunSummary>()
+        app.add_computed_state::<ActiveRun>()
+            .init_resource::<RunSummary>()
             .add_systems(Startup, setup_camera)
             .add_systems(OnEnter(GameState::Boot), enter_boot)
             .add_systems(Update, boot_to_loading.run_if(in_state(GameState::Boot)))
@@ -40,21 +62,31 @@ impl Plugin for GameStatePlugin {
                 Update,
                 loading_to_in_run.run_if(in_state(GameState::Loading)),
             )
-            .add_systems(OnEnter(GameState::InRun), enter_in_run)
+            .add_systems(OnEnter(GameState::Garage), enter_garage)
+            .add_systems(OnExit(GameState::Garage), cleanup_garage_screen)
+            .add_systems(
+                Update,
+                (garage_controls, update_garage_cards)
+                    .chain()
+                    .run_if(in_state(GameState::Garage)),
+            )
+            .add_systems(OnEnter(ActiveRun), enter_in_run.after(RunSeedSet))
             .add_systems(
                 Update,
                 (
                     update_run_summary_progress,
                     apply_kill_score_events,
                     apply_pickup_score_events,
+                    track_run_damage_and_upgrades,
                     apply_stunt_score_sources,
                     finalize_run_summary_score,
                     trigger_results_on_player_death,
-                    in_run_controls,
+                    trigger_results_on_out_of_fuel,
                 )
                     .chain()
-                    .run_if(in_state(GameState::InRun)),
+                    .run_if(in_state(ActiveRun)),
             )
+            .add_systems(Update, in_run_controls.run_if(in_state(GameState::InRun)))
             .add_systems(OnEnter(GameState::Pause), enter_pause)
             .add_systems(Update, pause_controls.run_if(in_state(GameState::Pause)))
             .add_systems(OnEnter(GameState::Results), enter_results)
@@ -80,8 +112,10 @@ struct LoadingScreenState {
     splat_cloud_handles: Vec<Handle<PlanarGaussian3d>>,
 }
 
-#[derive(Resource, Debug, Clone, Default)]
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
 pub struct RunSummary {
+    pub seed: u64,
+    pub vehicle_id: String,
     pub score: u32,
     pub distance_m: f32,
     pub distance_score: u32,
@@ -96,6 +130,8 @@ pub struct RunSummary {
     pub coin_pickup_count: u32,
     pub health_pickup_count: u32,
     pub total_health_restored: f32,
+    pub fuel_pickup_count: u32,
+    pub total_fuel_added: f32,
     pub total_airtime_s: f32,
     pub total_wheelie_s: f32,
     pub flip_count: u32,
@@ -104,6 +140,43 @@ pub struct RunSummary {
     pub long_wheelie_count: u32,
     pub took_damage: bool,
     pub was_game_over: bool,
+    pub end_reason: Option<RunEndReason>,
+    pub last_damage_cause: Option<RunDamageCause>,
+    pub kills_by_enemy_type: BTreeMap<String, u32>,
+    pub upgrades_picked: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunEndReason {
+    Destroyed,
+    OutOfFuel,
+}
+
+impl RunEndReason {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Destroyed => "destroyed",
+            Self::OutOfFuel => "out_of_fuel",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunDamageCause {
+    Enemy(PlayerDamageSource),
+    CrashLanding,
+}
+
+impl RunDamageCause {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Enemy(PlayerDamageSource::ProjectileBullet) => "bullet",
+            Self::Enemy(PlayerDamageSource::ProjectileMissile) => "missile",
+            Self::Enemy(PlayerDamageSource::ProjectileBomb) => "bomb",
+            Self::Enemy(PlayerDamageSource::Contact) => "contact",
+            Self::CrashLanding => "crash_landing",
+        }
+    }
 }
 
 fn setup_camera(mut commands: Commands) {
@@ -161,12 +234,15 @@ fn cleanup_loading_screen(
     commands.remove_resource::<LoadingScreenState>();
 }
 
+#[allow(clippy::too_many_arguments)]
 fn loading_to_in_run(
     time: Res<Time>,
     asset_server: Res<AssetServer>,
     config: Res<GameConfig>,
     registry: Option<Res<AssetRegistry>>,
     loading_state: Option<Res<LoadingScreenState>>,
+    replay: Option<Res<ReplayPlayback>>,
+    mut run_stats: ResMut<RunStats>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     let Some(loading_state) = loading_state else {
@@ -231,7 +307,21 @@ fn loading_to_in_run(
         warn!("Loading logo failed to load, continuing to run state.");
     }
 
-    next_state.set(GameState::InRun);
+    if let Some(replay) = replay {
+        if !config.vehicles_by_id.contains_key(replay.vehicle_id()) {
+            warn!(
+                "Replay vehicle `{}` is not in the config; replaying with `{}`.",
+                replay.vehicle_id(),
+                config.game.app.default_vehicle
+            );
+        }
+        *run_stats = RunStats::for_vehicle(&config, replay.vehicle_id());
+        next_state.set(GameState::Replay);
+    } else if config.vehicles.vehicles.len() > 1 {
+        next_state.set(GameState::Garage);
+    } else {
+        next_state.set(GameState::InRun);
+    }
 }
 
 fn asset_registry_ready(asset_server: &AssetServer, registry: &AssetRegistry) -> bool {
@@ -293,7 +383,9 @@ fn preload_splat_cloud_handles(
     handles
 }
 
-fn enter_in_run(mut run_summary: ResMut<RunSummary>) {
+fn enter_in_run(rng: Res<RunRng>, run_stats: Res<RunStats>, mut run_summary: ResMut<RunSummary>) {
+    run_summary.seed = rng.seed();
+    run_summary.vehicle_id = run_stats.player_vehicle_id.clone();
     run_summary.score = 0;
     run_summary.distance_m = 0.0;
     run_summary.distance_score = 0;
@@ -308,6 +400,8 @@ fn enter_in_run(mut run_summary: ResMut<RunSummary>) {
     run_summary.coin_pickup_count = 0;
     run_summary.health_pickup_count = 0;
     run_summary.total_health_restored = 0.0;
+    run_summary.fuel_pickup_count = 0;
+    run_summary.total_fuel_added = 0.0;
     run_summary.total_airtime_s = 0.0;
     run_summary.total_wheelie_s = 0.0;
     run_summary.flip_count = 0;
@@ -316,6 +410,10 @@ fn enter_in_run(mut run_summary: ResMut<RunSummary>) {
     run_summary.long_wheelie_count = 0;
     run_summary.took_damage = false;
     run_summary.was_game_over = false;
+    run_summary.end_reason = None;
+    run_summary.last_damage_cause = None;
+    run_summary.kills_by_enemy_type.clear();
+    run_summary.upgrades_picked.clear();
     info!("Entered state: InRun");
 }
 
@@ -422,6 +520,10 @@ fn apply_kill_score_events(
 
         total_added = total_added.saturating_add(kill_points);
         run_summary.kill_count = run_summary.kill_count.saturating_add(1);
+        *run_summary
+            .kills_by_enemy_type
+            .entry(event.enemy_type_id.clone())
+            .or_default() += 1;
     }
 
     if total_added > 0 {
@@ -443,10 +545,31 @@ fn apply_pickup_score_events(
                 run_summary.health_pickup_count = run_summary.health_pickup_count.saturating_add(1);
                 run_summary.total_health_restored += event.health_restored.max(0.0);
             }
+            PickupKind::Fuel => {
+                run_summary.fuel_pickup_count = run_summary.fuel_pickup_count.saturating_add(1);
+                run_summary.total_fuel_added += event.fuel_added.max(0.0);
+            }
         }
     }
 }
 
+fn track_run_damage_and_upgrades(
+    mut damage_events: MessageReader<PlayerDamageEvent>,
+    mut landing_events: MessageReader<VehicleLandingEvent>,
+    mut upgrade_events: MessageReader<UpgradeAppliedEvent>,
+    mut run_summary: ResMut<RunSummary>,
+) {
+    for event in damage_events.read() {
+        run_summary.last_damage_cause = Some(RunDamageCause::Enemy(event.source));
+    }
+    if landing_events.read().any(|event| event.was_crash) {
+        run_summary.last_damage_cause = Some(RunDamageCause::CrashLanding);
+    }
+    for event in upgrade_events.read() {
+        run_summary.upgrades_picked.push(event.upgrade_id.clone());
+    }
+}
+
 fn trigger_results_on_player_death(
     player_query: Query<&PlayerHealth, With<PlayerVehicle>>,
     mut run_summary: ResMut<RunSummary>,
@@ -465,6 +588,39 @@ fn trigger_results_on_player_death(
         }
         run_summary.took_damage = true;
         run_summary.was_game_over = true;
+        run_summary.end_reason = Some(RunEndReason::Destroyed);
+        next_state.set(GameState::Results);
+    }
+}
+
+fn trigger_results_on_out_of_fuel(
+    run_stats: Res<RunStats>,
+    player_query: Query<&PlayerFuel, With<PlayerVehicle>>,
+    mut run_summary: ResMut<RunSummary>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if run_summary.end_reason == Some(RunEndReason::Destroyed) {
+        return;
+    }
+    let Ok(fuel) = player_query.single() else {
+        return;
+    };
+    let Some(fuel_cfg) = run_stats
+        .player_vehicle()
+        .and_then(|vehicle| vehicle.fuel.as_ref())
+    else {
+        return;
+    };
+
+    if fuel.current <= 0.0 && fuel.stalled_s >= fuel_cfg.out_of_fuel_stall_seconds {
+        if !run_summary.was_game_over {
+            info!(
+                "Player ran out of fuel; entering results with score {}.",
+                run_summary.score
+            );
+        }
+        run_summary.was_game_over = true;
+        run_summary.end_reason = Some(RunEndReason::OutOfFuel);
         next_state.set(GameState::Results);
     }
 }
@@ -501,26 +657,31 @@ fn pause_controls(
     }
 }
 
-fn enter_results(mut commands: Commands, run_summary: Res<RunSummary>) {
-    let title = if run_summary.was_game_over {
-        "GAME OVER"
-    } else {
-        "RESULTS"
+fn enter_results(mut commands: Commands, config: Res<GameConfig>, run_summary: Res<RunSummary>) {
+    let title = match run_summary.end_reason {
+        Some(RunEndReason::OutOfFuel) => "OUT OF FUEL",
+        _ if run_summary.was_game_over => "GAME OVER",
+        _ => "RESULTS",
     };
     let no_damage_line = if run_summary.no_damage_bonus_score > 0 {
         format!("No Damage Bonus: +{}", run_summary.no_damage_bonus_score)
     } else {
         "No Damage Bonus: +0".to_string()
     };
+    let vehicle = config.vehicles_by_id.get(&run_summary.vehicle_id).map_or(
+        run_summary.vehicle_id.as_str(),
+        VehicleConfig::display_label,
+    );
     let summary_text = format!(
         "Score: {score}\n\
 Distance: {distance:.1} m (+{distance_score})\n\
 Kills: {kill_count} (+{kill_score})\n\
-Pickups: {coin_pickups} coins (+{pickup_score}) | Health Crates: {health_pickups} (+{health_restored:.1} hp)\n\
+Pickups: {coin_pickups} coins (+{pickup_score}) | Health Crates: {health_pickups} (+{health_restored:.1} hp) | Fuel Cans: {fuel_pickups}\n\
 Stunts: +{stunt_score} (airtime +{airtime_score}, wheelie +{wheelie_score}, flips +{flip_score})\n\
 Airtime Total: {airtime_total:.2}s | Wheelie Total: {wheelie_total:.2}s | Flips: {flip_count}\n\
 Big/Huge Jumps: {big_jumps}/{huge_jumps} | Long Wheelies: {long_wheelies}\n\
-{no_damage_line}\n\n\
+{no_damage_line}\n\
+Vehicle: {vehicle} | Seed: {seed}\n\n\
 Space - New Run\n\
 Q - Quit",
         score = run_summary.score,
@@ -532,6 +693,7 @@ Q - Quit",
         pickup_score = run_summary.pickup_score,
         health_pickups = run_summary.health_pickup_count,
         health_restored = run_summary.total_health_restored,
+        fuel_pickups = run_summary.fuel_pickup_count,
         stunt_score = run_summary.stunt_score,
         airtime_score = run_summary.airtime_score,
         wheelie_score = run_summary.wheelie_score,
@@ -543,6 +705,7 @@ Q - Quit",
         huge_jumps = run_summary.huge_jump_count,
         long_wheelies = run_summary.long_wheelie_count,
         no_damage_line = no_damage_line,
+        seed = run_summary.seed,
     );
 
     commands
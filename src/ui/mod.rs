@@ -1,9 +1,9 @@
-use crate::config::GameConfig;
-use crate::gameplay::upgrades::UpgradeAppliedEvent;
+use crate::config::{GameConfig, RunStats};
+use crate::gameplay::upgrades::{UpgradeAppliedEvent, UpgradeRevertedEvent};
 use crate::gameplay::vehicle::{
-    PlayerHealth, PlayerVehicle, VehicleStuntMetrics, VehicleTelemetry,
+    PlayerFuel, PlayerHealth, PlayerNitro, PlayerVehicle, VehicleStuntMetrics, VehicleTelemetry,
 };
-use crate::states::{GameState, RunSummary};
+use crate::states::{ActiveRun, RunSummary};
 use bevy::prelude::*;
 use std::collections::HashMap;
 
@@ -13,6 +13,7 @@ const HUD_PANEL_BORDER: Color = Color::srgba(0.58, 0.68, 0.76, 0.92);
 const HUD_TEXT_PRIMARY: Color = Color::srgb(0.94, 0.97, 1.0);
 const HUD_TEXT_MUTED: Color = Color::srgb(0.76, 0.83, 0.9);
 const HUD_HEALTH_BAR_WIDTH_PX: f32 = 260.0;
+const HUD_FUEL_LOW_FRACTION: f32 = 0.25;
 
 pub struct GameHudPlugin;
 
@@ -20,15 +21,15 @@ impl Plugin for GameHudPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<HudUpgradeState>()
             .add_systems(
-                OnEnter(GameState::InRun),
+                OnEnter(ActiveRun),
                 (reset_hud_upgrade_state, spawn_game_hud),
             )
-            .add_systems(OnExit(GameState::InRun), cleanup_game_hud)
+            .add_systems(OnExit(ActiveRun), cleanup_game_hud)
             .add_systems(
                 Update,
                 (track_upgrade_applies_for_hud, update_game_hud)
                     .chain()
-                    .run_if(in_state(GameState::InRun)),
+                    .run_if(in_state(ActiveRun)),
             );
     }
 }
@@ -40,6 +41,8 @@ struct GameHudRoot;
 enum HudTextKind {
     Score,
     Health,
+    Fuel,
+    Nitro,
     CoreStats,
     StuntStats,
     Segment,
@@ -49,6 +52,12 @@ enum HudTextKind {
 #[derive(Component)]
 struct HudHealthFill;
 
+#[derive(Component)]
+struct HudFuelFill;
+
+#[derive(Component)]
+struct HudNitroFill;
+
 #[derive(Resource, Debug, Clone, Default)]
 struct HudUpgradeState {
     by_id: HashMap<String, HudUpgradeEntry>,
@@ -65,10 +74,20 @@ fn reset_hud_upgrade_state(mut state: ResMut<HudUpgradeState>) {
     state.by_id.clear();
 }
 
-fn spawn_game_hud(mut commands: Commands, existing_hud: Query<Entity, With<GameHudRoot>>) {
+fn spawn_game_hud(
+    mut commands: Commands,
+    run_stats: Res<RunStats>,
+    existing_hud: Query<Entity, With<GameHudRoot>>,
+) {
     if !existing_hud.is_empty() {
         return;
     }
+    let has_fuel = run_stats
+        .player_vehicle()
+        .is_some_and(|vehicle| vehicle.fuel.is_some());
+    let has_nitro = run_stats
+        .player_vehicle()
+        .is_some_and(|vehicle| vehicle.nitro.is_some());
 
     commands
         .spawn((
@@ -142,6 +161,74 @@ fn spawn_game_hud(mut commands: Commands, existing_hud: Query<Entity, With<GameH
                             BackgroundColor(Color::srgb(0.38, 0.90, 0.34)),
                         ));
                     });
+                if has_fuel {
+                    panel.spawn((
+                        HudTextKind::Fuel,
+                        Text::new("FUEL 0 / 0"),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(HUD_TEXT_PRIMARY),
+                    ));
+                    panel
+                        .spawn((
+                            Name::new("HudFuelBar"),
+                            Node {
+                                width: Val::Px(HUD_HEALTH_BAR_WIDTH_PX),
+                                height: Val::Px(10.0),
+                                border: UiRect::all(Val::Px(1.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(0.02, 0.03, 0.04, 0.84)),
+                            BorderColor::all(Color::srgba(0.56, 0.64, 0.70, 0.9)),
+                        ))
+                        .with_children(|bar| {
+                            bar.spawn((
+                                HudFuelFill,
+                                Node {
+                                    width: Val::Px(HUD_HEALTH_BAR_WIDTH_PX),
+                                    height: Val::Percent(100.0),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.96, 0.66, 0.18)),
+                            ));
+                        });
+                }
+                if has_nitro {
+                    panel.spawn((
+                        HudTextKind::Nitro,
+                        Text::new("NITRO 0%"),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(HUD_TEXT_PRIMARY),
+                    ));
+                    panel
+                        .spawn((
+                            Name::new("HudNitroBar"),
+                            Node {
+                                width: Val::Px(HUD_HEALTH_BAR_WIDTH_PX),
+                                height: Val::Px(10.0),
+                                border: UiRect::all(Val::Px(1.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(0.02, 0.03, 0.04, 0.84)),
+                            BorderColor::all(Color::srgba(0.56, 0.64, 0.70, 0.9)),
+                        ))
+                        .with_children(|bar| {
+                            bar.spawn((
+                                HudNitroFill,
+                                Node {
+                                    width: Val::Px(0.0),
+                                    height: Val::Percent(100.0),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.30, 0.62, 0.96)),
+                            ));
+                        });
+                }
                 panel.spawn((
                     HudTextKind::CoreStats,
                     Text::new("Distance 0.0m | Speed 0.0 m/s | Kills 0 | Coins 0"),
@@ -215,6 +302,7 @@ fn cleanup_game_hud(mut commands: Commands, hud_query: Query<Entity, With<GameHu
 
 fn track_upgrade_applies_for_hud(
     mut events: MessageReader<UpgradeAppliedEvent>,
+    mut reverted_events: MessageReader<UpgradeRevertedEvent>,
     mut state: ResMut<HudUpgradeState>,
 ) {
     for event in events.read() {
@@ -227,18 +315,40 @@ fn track_upgrade_applies_for_hud(
             },
         );
     }
+    for event in reverted_events.read() {
+        if event.stack == 0 {
+            state.by_id.remove(&event.upgrade_id);
+        } else if let Some(entry) = state.by_id.get_mut(&event.upgrade_id) {
+            entry.stack = event.stack;
+        }
+    }
 }
 
-#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn update_game_hud(
     config: Option<Res<GameConfig>>,
     telemetry: Option<Res<VehicleTelemetry>>,
     stunts: Option<Res<VehicleStuntMetrics>>,
     run_summary: Option<Res<RunSummary>>,
-    player_query: Query<&PlayerHealth, With<PlayerVehicle>>,
+    player_query: Query<
+        (&PlayerHealth, Option<&PlayerFuel>, Option<&PlayerNitro>),
+        With<PlayerVehicle>,
+    >,
     upgrades: Res<HudUpgradeState>,
     mut text_query: Query<(&HudTextKind, &mut Text)>,
     mut health_fill_query: Query<(&mut Node, &mut BackgroundColor), With<HudHealthFill>>,
+    mut fuel_fill_query: Query<
+        (&mut Node, &mut BackgroundColor),
+        (With<HudFuelFill>, Without<HudHealthFill>),
+    >,
+    mut nitro_fill_query: Query<
+        (&mut Node, &mut BackgroundColor),
+        (
+            With<HudNitroFill>,
+            Without<HudHealthFill>,
+            Without<HudFuelFill>,
+        ),
+    >,
 ) {
     let (distance_m, speed_mps) = telemetry
         .map(|telemetry| (telemetry.distance_m.max(0.0), telemetry.speed_mps))
@@ -262,7 +372,7 @@ fn update_game_hud(
 
     let (hp_current, hp_max) = player_query
         .single()
-        .map(|health| (health.current.max(0.0), health.max.max(1.0)))
+        .map(|(health, _, _)| (health.current.max(0.0), health.max.max(1.0)))
         .unwrap_or((0.0, 1.0));
     let hp_fraction = (hp_current / hp_max).clamp(0.0, 1.0);
 
@@ -273,10 +383,56 @@ fn update_game_hud(
         *bar_color = BackgroundColor(Color::srgb(red, green, 0.2));
     }
 
+    let (fuel_current, fuel_max) = player_query
+        .single()
+        .ok()
+        .and_then(|(_, fuel, _)| fuel)
+        .map(|fuel| (fuel.current.max(0.0), fuel.max.max(1.0)))
+        .unwrap_or((0.0, 1.0));
+    let fuel_fraction = (fuel_current / fuel_max).clamp(0.0, 1.0);
+
+    if let Ok((mut bar_node, mut bar_color)) = fuel_fill_query.single_mut() {
+        bar_node.width = Val::Px(HUD_HEALTH_BAR_WIDTH_PX * fuel_fraction);
+        *bar_color = if fuel_fraction <= HUD_FUEL_LOW_FRACTION {
+            BackgroundColor(Color::srgb(0.96, 0.26, 0.18))
+        } else {
+            BackgroundColor(Color::srgb(0.96, 0.66, 0.18))
+        };
+    }
+
+    let nitro = player_query.single().ok().and_then(|(_, _, nitro)| nitro);
+    let nitro_fraction = nitro
+        .map(|nitro| {
+            if nitro.boosting() {
+                1.0
+            } else {
+                (nitro.charge / nitro.max.max(1.0)).clamp(0.0, 1.0)
+            }
+        })
+        .unwrap_or(0.0);
+    let nitro_label = match nitro {
+        Some(nitro) if nitro.boosting() => "NITRO BOOSTING".to_string(),
+        Some(nitro) if nitro.ready() => "NITRO READY (hold accelerate + brake)".to_string(),
+        _ => format!("NITRO {:.0}%", nitro_fraction * 100.0),
+    };
+
+    if let Ok((mut bar_node, mut bar_color)) = nitro_fill_query.single_mut() {
+        bar_node.width = Val::Px(HUD_HEALTH_BAR_WIDTH_PX * nitro_fraction);
+        *bar_color = if nitro.is_some_and(|nitro| nitro.boosting() || nitro.ready()) {
+            BackgroundColor(Color::srgb(0.56, 0.90, 1.0))
+        } else {
+            BackgroundColor(Color::srgb(0.30, 0.62, 0.96))
+        };
+    }
+
     let active_segment = config
         .as_ref()
         .map(|cfg| resolve_active_segment_id(distance_m, cfg))
         .unwrap_or_else(|| "n/a".to_string());
+    let difficulty_scalar = config
+        .as_ref()
+        .map(|cfg| cfg.difficulty_scalar_at_distance(distance_m))
+        .unwrap_or(1.0);
     let next_upgrade_remaining = config
         .as_ref()
         .map(|cfg| {
@@ -296,6 +452,12 @@ fn update_game_hud(
             HudTextKind::Health => {
                 *text = Text::new(format!("HP {hp_current:.0} / {hp_max:.0}"));
             }
+            HudTextKind::Fuel => {
+                *text = Text::new(format!("FUEL {fuel_current:.0} / {fuel_max:.0}"));
+            }
+            HudTextKind::Nitro => {
+                *text = Text::new(nitro_label.clone());
+            }
             HudTextKind::CoreStats => {
                 *text = Text::new(format!(
                     "Distance {distance_m:.1} m | Speed {speed_mps:.1} m/s | Kills {kills} | Coins {coins} | Next upgrade in {next_upgrade_remaining}"
@@ -307,7 +469,9 @@ fn update_game_hud(
                 ));
             }
             HudTextKind::Segment => {
-                *text = Text::new(format!("Segment: {active_segment}"));
+                *text = Text::new(format!(
+                    "Segment: {active_segment} | Difficulty x{difficulty_scalar:.2}"
+                ));
             }
             HudTextKind::Upgrades => {
                 *text = Text::new(upgrades_text.clone());
@@ -1,5 +1,5 @@
 use crate::config::GameConfig;
-use crate::states::GameState;
+use crate::states::{ActiveRun, GameState};
 use bevy::prelude::*;
 use bevy::window::{CursorGrabMode, CursorOptions, PrimaryWindow};
 
@@ -19,10 +19,10 @@ impl Plugin for WebSupportPlugin {
                 sync_web_runtime_state.run_if(resource_exists::<GameConfig>),
             )
             .add_systems(
-                OnEnter(GameState::InRun),
+                OnEnter(ActiveRun),
                 (spawn_touch_controls_ui, spawn_audio_unlock_ui),
             )
-            .add_systems(OnExit(GameState::InRun), cleanup_web_ui)
+            .add_systems(OnExit(ActiveRun), cleanup_web_ui)
             .add_systems(
                 Update,
                 (
@@ -32,8 +32,13 @@ impl Plugin for WebSupportPlugin {
                     update_audio_unlock_ui,
                 )
                     .chain()
-                    .run_if(in_state(GameState::InRun))
+                    .run_if(in_state(ActiveRun))
                     .run_if(resource_exists::<GameConfig>),
+            )
+            // The garage uses the same left/right screen halves as brake/accelerate.
+            .add_systems(
+                Update,
+                update_virtual_controls_from_pointer_and_touch.run_if(in_state(GameState::Garage)),
             );
     }
 }
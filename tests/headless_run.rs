@@ -0,0 +1,178 @@
+use mr_autoauto::{
+    run_headless, GameConfig, HeadlessInputSpan, HeadlessRun, RunEndReason, VehicleInputState,
+};
+use std::path::Path;
+
+fn full_throttle_run(seed: u64, duration_s: f32) -> HeadlessRun {
+    HeadlessRun {
+        seed,
+        vehicle_id: None,
+        duration_s,
+        inputs: vec![HeadlessInputSpan {
+            duration_s,
+            input: VehicleInputState {
+                accelerate: true,
+                brake: false,
+            },
+        }],
+        upgrade_decisions: Vec::new(),
+        autopilot: false,
+    }
+}
+
+fn load_config() -> GameConfig {
+    GameConfig::load_from_dir(Path::new("config")).expect("repo config should load")
+}
+
+// The headless run reads the vehicle from `vehicles_by_id`, so both copies are edited.
+fn with_starter_fuel_tank(tank_capacity: f32, fuel_track_spacing_m: f32) -> GameConfig {
+    let mut config = load_config();
+    for vehicle in config
+        .vehicles
+        .vehicles
+        .iter_mut()
+        .chain(config.vehicles_by_id.values_mut())
+        .filter(|vehicle| vehicle.id == "starter_car")
+    {
+        let fuel = vehicle
+            .fuel
+            .as_mut()
+            .expect("starter car should have a fuel tank");
+        fuel.tank_capacity = tank_capacity;
+        fuel.out_of_fuel_stall_seconds = 0.5;
+    }
+    config.game.pickups.fuel_track_spacing_m = fuel_track_spacing_m;
+    config
+}
+
+#[test]
+fn headless_run_drives_forward_and_reports_its_seed() {
+    let summary = run_headless(load_config(), &full_throttle_run(42, 8.0));
+
+    assert_eq!(summary.seed, 42);
+    assert!(summary.distance_m > 0.0);
+}
+
+#[test]
+fn headless_runs_with_the_same_seed_and_inputs_match() {
+    let run = full_throttle_run(7, 8.0);
+
+    assert_eq!(
+        run_headless(load_config(), &run),
+        run_headless(load_config(), &run)
+    );
+}
+
+#[test]
+fn headless_run_drives_the_selected_vehicle() {
+    let run = HeadlessRun {
+        vehicle_id: Some("monster_truck".to_string()),
+        ..full_throttle_run(7, 4.0)
+    };
+    let summary = run_headless(load_config(), &run);
+
+    assert_eq!(summary.vehicle_id, "monster_truck");
+    assert!(summary.distance_m > 0.0);
+    assert_eq!(
+        run_headless(load_config(), &full_throttle_run(7, 4.0)).vehicle_id,
+        "starter_car"
+    );
+}
+
+#[test]
+fn headless_run_with_an_empty_tank_ends_out_of_fuel() {
+    let summary = run_headless(with_starter_fuel_tank(1.0, 0.0), &full_throttle_run(7, 8.0));
+
+    assert!(summary.was_game_over);
+    assert_eq!(summary.end_reason, Some(RunEndReason::OutOfFuel));
+    assert_eq!(summary.fuel_pickup_count, 0);
+}
+
+#[test]
+fn headless_run_refills_from_track_fuel_cans() {
+    let summary = run_headless(
+        with_starter_fuel_tank(20.0, 30.0),
+        &full_throttle_run(7, 20.0),
+    );
+
+    assert!(summary.fuel_pickup_count >= 1);
+    assert!(summary.total_fuel_added > 0.0);
+    assert_ne!(summary.end_reason, Some(RunEndReason::OutOfFuel));
+}
+
+#[test]
+fn headless_run_score_adds_up_its_parts() {
+    let config = load_config();
+    let points_per_meter = config.game.scoring.points_per_meter;
+    let no_damage_bonus = config.game.scoring.no_damage_bonus;
+    let summary = run_headless(config, &full_throttle_run(11, 8.0));
+
+    assert!(summary.distance_score > 0);
+    assert_eq!(
+        summary.distance_score,
+        (summary.distance_m * points_per_meter).floor() as u32
+    );
+    let expected_bonus = if summary.took_damage {
+        0
+    } else {
+        no_damage_bonus
+    };
+    assert_eq!(summary.no_damage_bonus_score, expected_bonus);
+    assert_eq!(
+        summary.score,
+        summary.distance_score
+            + summary.kill_score
+            + summary.pickup_score
+            + summary.stunt_score
+            + summary.no_damage_bonus_score
+    );
+}
+
+#[test]
+fn headless_run_is_held_at_the_segment_boss_gate() {
+    let open = run_headless(load_config(), &full_throttle_run(7, 12.0));
+
+    // A 60 m first segment puts the boss trigger 20 m before its end; the boss is
+    // made unkillable so the gate stays closed for the whole run.
+    let mut config = load_config();
+    config.segments.segment_sequence[0].length = 60.0;
+    config
+        .enemy_types_by_id
+        .get_mut("segment_boss_drone")
+        .expect("repo config should define the segment boss")
+        .health = 1.0e6;
+    let boss_trigger_x = 40.0;
+    let gated = run_headless(config, &full_throttle_run(7, 12.0));
+
+    assert!(open.distance_m > boss_trigger_x, "{}", open.distance_m);
+    assert!(
+        gated.distance_m > boss_trigger_x - 5.0,
+        "{}",
+        gated.distance_m
+    );
+    assert!(gated.distance_m <= boss_trigger_x, "{}", gated.distance_m);
+    assert!(!gated.kills_by_enemy_type.contains_key("segment_boss_drone"));
+}
+
+#[test]
+fn headless_run_applies_upgrades_from_offers() {
+    let mut config = load_config();
+    config.game.run_upgrades.coins_per_offer = 1;
+    let option_ids: Vec<String> = config
+        .game
+        .run_upgrades
+        .options
+        .iter()
+        .map(|option| option.id.clone())
+        .collect();
+    let run = HeadlessRun {
+        autopilot: true,
+        ..full_throttle_run(7, 30.0)
+    };
+    let summary = run_headless(config, &run);
+
+    assert!(!summary.upgrades_picked.is_empty());
+    for upgrade_id in &summary.upgrades_picked {
+        assert!(option_ids.contains(upgrade_id), "{upgrade_id}");
+    }
+}